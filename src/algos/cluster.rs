@@ -0,0 +1,131 @@
+/// Maximum number of iterations in `kmeans`.
+const MAX_ITER: usize = 300;
+
+/// SplitMix64 pseudo random generator, enough for reproducible seeding.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in [0, 1).
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+fn squared_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+/// Location of the nearest centroid and its squared distance.
+fn nearest(point: &[f64], centroids: &[Vec<f64>]) -> (usize, f64) {
+    centroids
+        .iter()
+        .map(|c| squared_distance(point, c))
+        .enumerate()
+        .fold((0, f64::INFINITY), |m, x| if x.1 < m.1 { x } else { m })
+}
+
+/// Initial centroids chosen by k-means++, each next centroid is drawn with
+/// probability proportional to the squared distance to the nearest chosen.
+fn init_centroids(points: &[Vec<f64>], k: usize, rng: &mut SplitMix64) -> Vec<Vec<f64>> {
+    let first = (rng.next_u64() % points.len() as u64) as usize;
+    let mut centroids = vec![points[first].clone()];
+    while centroids.len() < k {
+        let distances: Vec<f64> = points.iter().map(|x| nearest(x, &centroids).1).collect();
+        let total: f64 = distances.iter().sum();
+        if total == 0. {
+            // all points coincide with centroids, duplicate the first one
+            centroids.push(centroids[0].clone());
+            continue;
+        }
+        let mut target = rng.next_f64() * total;
+        let mut chosen = points.len() - 1;
+        for (i, d) in distances.iter().enumerate() {
+            if target < *d {
+                chosen = i;
+                break;
+            }
+            target -= d;
+        }
+        centroids.push(points[chosen].clone());
+    }
+    centroids
+}
+
+/// Cluster `points` into `k` clusters by Lloyd's algorithm, initialized by
+/// k-means++ with `seed`.
+///
+/// Returns the cluster of each point and centroids of clusters. A cluster
+/// which loses all of its points keeps its previous centroid.
+pub fn kmeans(points: &[Vec<f64>], k: usize, seed: u64) -> (Vec<usize>, Vec<Vec<f64>>) {
+    assert!(k > 0, "k must be positive");
+    assert!(k <= points.len(), "k must not exceed the number of points");
+    let dim = points[0].len();
+    let mut rng = SplitMix64 { state: seed };
+    let mut centroids = init_centroids(points, k, &mut rng);
+
+    let mut labels: Vec<usize> = points.iter().map(|x| nearest(x, &centroids).0).collect();
+    for _ in 0..MAX_ITER {
+        let mut sums = vec![vec![0.; dim]; k];
+        let mut counts = vec![0usize; k];
+        for (point, label) in points.iter().zip(&labels) {
+            counts[*label] += 1;
+            for (s, x) in sums[*label].iter_mut().zip(point) {
+                *s += x;
+            }
+        }
+        for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+            if count > 0 {
+                *centroid = sum.into_iter().map(|x| x / count as f64).collect();
+            }
+        }
+
+        let new_labels: Vec<usize> = points.iter().map(|x| nearest(x, &centroids).0).collect();
+        if new_labels == labels {
+            break;
+        }
+        labels = new_labels;
+    }
+    (labels, centroids)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::kmeans;
+
+    #[test]
+    fn test_kmeans() {
+        let points = vec![
+            vec![0., 0.],
+            vec![10., 10.],
+            vec![0., 1.],
+            vec![10., 11.],
+            vec![1., 0.],
+            vec![11., 10.],
+        ];
+        let (labels, centroids) = kmeans(&points, 2, 0);
+        assert_eq!(labels[0], labels[2]);
+        assert_eq!(labels[0], labels[4]);
+        assert_eq!(labels[1], labels[3]);
+        assert_eq!(labels[1], labels[5]);
+        assert!(labels[0] != labels[1]);
+
+        let c = &centroids[labels[0]];
+        assert!((c[0] - 1. / 3.).abs() < 1e-12 && (c[1] - 1. / 3.).abs() < 1e-12);
+        let c = &centroids[labels[1]];
+        assert!((c[0] - 31. / 3.).abs() < 1e-12 && (c[1] - 31. / 3.).abs() < 1e-12);
+
+        // same seed gives the same result
+        assert_eq!(kmeans(&points, 2, 0), kmeans(&points, 2, 0));
+    }
+}
@@ -4,6 +4,8 @@ use std::ops::{Add, Sub, Div};
 
 use num::{Zero, Float, ToPrimitive};
 
+use traits::Summation;
+
 //**********************************************
 //*Min, Max
 //**********************************************
@@ -43,11 +45,13 @@ macro_rules! define_float_stats {
             fn nanmax(&self, n: $t) -> $t {
                 self.max(n)
             }
+            // NaN is ignored by min / max, so that the result of all NaN
+            // (or empty) values is NaN
             fn nanmin_value() -> $t {
-                $t::min_value()
+                $t::NAN
             }
             fn nanmax_value() -> $t {
-                $t::max_value()
+                $t::NAN
             }
         }
     }
@@ -137,6 +141,40 @@ impl Aggregation {
         Aggregation::vec_unbiased_var(values).sqrt()
     }
 
+    /// Sum of `values` by `method`.
+    pub fn float_sum(values: &[f64], method: Summation) -> f64 {
+        match method {
+            Summation::Naive => values.iter().sum(),
+            Summation::Kahan => Aggregation::kahan_sum(values),
+            Summation::Pairwise => Aggregation::pairwise_sum(values),
+        }
+    }
+
+    fn kahan_sum(values: &[f64]) -> f64 {
+        let mut sum = 0.;
+        // lost low-order bits
+        let mut compensation = 0.;
+        for &x in values {
+            let t = sum + x;
+            if Float::abs(sum) >= Float::abs(x) {
+                compensation += (sum - t) + x;
+            } else {
+                compensation += (x - t) + sum;
+            }
+            sum = t;
+        }
+        sum + compensation
+    }
+
+    fn pairwise_sum(values: &[f64]) -> f64 {
+        if values.len() <= 128 {
+            values.iter().sum()
+        } else {
+            let (left, right) = values.split_at(values.len() / 2);
+            Aggregation::pairwise_sum(left) + Aggregation::pairwise_sum(right)
+        }
+    }
+
     pub fn vec_min<T>(values: &Vec<T>) -> T
     where
         T: Clone + NanMinMax<T>,
@@ -179,6 +217,21 @@ mod tests {
         assert_eq!(Aggregation::vec_mean(&values), 2.5);
     }
 
+    #[test]
+    fn test_float_sum() {
+        use traits::Summation;
+
+        let values: Vec<f64> = vec![1., 1e100, 1., -1e100];
+        assert_eq!(Aggregation::float_sum(&values, Summation::Naive), 0.);
+        assert_eq!(Aggregation::float_sum(&values, Summation::Kahan), 2.);
+
+        let values: Vec<f64> = vec![0.1; 10000];
+        assert_eq!(Aggregation::float_sum(&values, Summation::Kahan), 1000.);
+        let naive = Aggregation::float_sum(&values, Summation::Naive);
+        let pairwise = Aggregation::float_sum(&values, Summation::Pairwise);
+        assert!((pairwise - 1000.).abs() < (naive - 1000.).abs());
+    }
+
     #[test]
     fn test_vec_sum_i64() {
         let values: Vec<i64> = vec![1, 2, 3, 5];
@@ -244,5 +297,13 @@ mod tests {
         let values: Vec<f64> = vec![3., 2., 1., 5., 2., 6., 3.];
         assert_eq!(Aggregation::vec_min(&values), 1.);
         assert_eq!(Aggregation::vec_max(&values), 6.);
+
+        let values: Vec<f64> = vec![f64::NAN, -2., f64::NAN, -1.];
+        assert_eq!(Aggregation::vec_min(&values), -2.);
+        assert_eq!(Aggregation::vec_max(&values), -1.);
+
+        let values: Vec<f64> = vec![f64::NAN, f64::NAN];
+        assert!(Aggregation::vec_min(&values).is_nan());
+        assert!(Aggregation::vec_max(&values).is_nan());
     }
 }
@@ -0,0 +1,163 @@
+use std::cmp::Ordering;
+use std::f64;
+
+/// Pearson correlation coefficient, NaN if either has no variance.
+pub fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    assert!(x.len() == y.len(), "Length mismatch!");
+    let n = x.len() as f64;
+    let (mx, my) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+    let mut sxy = 0.;
+    let mut sxx = 0.;
+    let mut syy = 0.;
+    for (a, b) in x.iter().zip(y) {
+        sxy += (a - mx) * (b - my);
+        sxx += (a - mx) * (a - mx);
+        syy += (b - my) * (b - my);
+    }
+    sxy / (sxx * syy).sqrt()
+}
+
+/// Ranks starting from 1, ties have the average of their ranks.
+pub fn rank(values: &[f64]) -> Vec<f64> {
+    let mut indexer: Vec<usize> = (0..values.len()).collect();
+    indexer.sort_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap_or(Ordering::Equal));
+
+    let mut ranks = vec![0.; values.len()];
+    let mut start = 0;
+    while start < indexer.len() {
+        let mut end = start + 1;
+        while end < indexer.len() && values[indexer[end]] == values[indexer[start]] {
+            end += 1;
+        }
+        // average of 1-based ranks start + 1 ..= end
+        let rank = (start + end + 1) as f64 / 2.;
+        for loc in &indexer[start..end] {
+            ranks[*loc] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
+
+/// Spearman rank correlation coefficient.
+pub fn spearman(x: &[f64], y: &[f64]) -> f64 {
+    pearson(&rank(x), &rank(y))
+}
+
+/// Kendall rank correlation coefficient, tau-b which accounts for ties.
+pub fn kendall(x: &[f64], y: &[f64]) -> f64 {
+    assert!(x.len() == y.len(), "Length mismatch!");
+    let mut concordant = 0i64;
+    let mut discordant = 0i64;
+    let mut x_ties = 0i64;
+    let mut y_ties = 0i64;
+    for i in 0..x.len() {
+        for j in i + 1..x.len() {
+            let dx = x[i] - x[j];
+            let dy = y[i] - y[j];
+            if dx == 0. && dy == 0. {
+                continue;
+            } else if dx == 0. {
+                x_ties += 1;
+            } else if dy == 0. {
+                y_ties += 1;
+            } else if (dx > 0.) == (dy > 0.) {
+                concordant += 1;
+            } else {
+                discordant += 1;
+            }
+        }
+    }
+    let pairs = concordant + discordant;
+    (concordant - discordant) as f64 / (((pairs + x_ties) * (pairs + y_ties)) as f64).sqrt()
+}
+
+/// Bin of each value splitting the range of `values` into `bins` of equal
+/// width.
+fn equal_width_bins(values: &[f64], bins: usize) -> Vec<usize> {
+    let min = values.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let width = (max - min) / bins as f64;
+    values
+        .iter()
+        .map(|x| if width > 0. {
+            (((x - min) / width) as usize).min(bins - 1)
+        } else {
+            0
+        })
+        .collect()
+}
+
+/// Mutual information in nats, estimated from the joint histogram.
+///
+/// Values are binned into equal width bins, whose number is determined by
+/// Sturges' rule.
+pub fn mutual_information(x: &[f64], y: &[f64]) -> f64 {
+    assert!(x.len() == y.len(), "Length mismatch!");
+    let n = x.len();
+    if n == 0 {
+        return f64::NAN;
+    }
+    let bins = (n as f64).log2().ceil() as usize + 1;
+    let (bx, by) = (equal_width_bins(x, bins), equal_width_bins(y, bins));
+
+    let mut joint = vec![vec![0usize; bins]; bins];
+    let mut px = vec![0usize; bins];
+    let mut py = vec![0usize; bins];
+    for (a, b) in bx.iter().zip(&by) {
+        joint[*a][*b] += 1;
+        px[*a] += 1;
+        py[*b] += 1;
+    }
+    let n = n as f64;
+    let mut result = 0.;
+    for (a, row) in joint.iter().enumerate() {
+        for (b, count) in row.iter().enumerate() {
+            if *count > 0 {
+                let pxy = *count as f64 / n;
+                result += pxy * (pxy * n * n / (px[a] * py[b]) as f64).ln();
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{pearson, rank, spearman, kendall, mutual_information};
+
+    #[test]
+    fn test_pearson() {
+        assert_eq!(pearson(&[1., 2., 3.], &[2., 4., 6.]), 1.);
+        assert_eq!(pearson(&[1., 2., 3.], &[3., 2., 1.]), -1.);
+        assert!(pearson(&[1., 1., 1.], &[1., 2., 3.]).is_nan());
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(rank(&[3., 1., 4., 1., 5.]), vec![3., 1.5, 4., 1.5, 5.]);
+    }
+
+    #[test]
+    fn test_spearman_kendall() {
+        // monotonic but not linear
+        let x = [1., 2., 3., 4., 5.];
+        let y = [1., 4., 9., 16., 100.];
+        assert_eq!(spearman(&x, &y), 1.);
+        assert_eq!(kendall(&x, &y), 1.);
+        assert_eq!(kendall(&x, &[5., 4., 3., 2., 1.]), -1.);
+        // 4 concordant, 1 discordant, 1 tie in y
+        let res = kendall(&[1., 2., 3., 4.], &[1., 3., 2., 3.]);
+        assert!((res - 3. / 30f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_mutual_information() {
+        let x = [1., 2., 3., 4., 1., 2., 3., 4.];
+        // identical variables share all of their entropy, ln(4)
+        assert!((mutual_information(&x, &x) - 4f64.ln()).abs() < 1e-12);
+        let y = [1., 1., 1., 1., 2., 2., 2., 2.];
+        assert!(mutual_information(&x, &y).abs() < 1e-12);
+    }
+}
@@ -65,8 +65,8 @@ mod tests {
         assert_eq!(c.len(), 0);
 
         let (keys, counts) = c.get_results();
-        assert_eq!(keys, vec![]);
-        assert_eq!(counts, vec![]);
+        assert_eq!(keys, Vec::<i64>::new());
+        assert_eq!(counts, Vec::<usize>::new());
     }
 
     #[test]
@@ -0,0 +1,57 @@
+/// Levenshtein distance between strings, the number of inserted, deleted or
+/// substituted characters to transform one to the other.
+pub fn levenshtein(left: &str, right: &str) -> usize {
+    let right: Vec<char> = right.chars().collect();
+    // distances from the prefix of left to each prefix of right
+    let mut prev: Vec<usize> = (0..right.len() + 1).collect();
+    for (i, l) in left.chars().enumerate() {
+        let mut current: Vec<usize> = Vec::with_capacity(right.len() + 1);
+        current.push(i + 1);
+        for (j, r) in right.iter().enumerate() {
+            let cost = if l == *r { 0 } else { 1 };
+            let value = (prev[j] + cost).min(prev[j + 1] + 1).min(current[j] + 1);
+            current.push(value);
+        }
+        prev = current;
+    }
+    prev[right.len()]
+}
+
+/// The most similar candidate to `label` ignoring case, if any is similar
+/// enough.
+///
+/// Candidates containing `label` are preferred, otherwise candidates within
+/// the edit distance of half of the longer length are considered.
+pub fn suggest<'a>(label: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let label = label.to_lowercase();
+    if let Some(c) = candidates.iter().find(|x| x.to_lowercase().contains(&label)) {
+        return Some(c);
+    }
+    candidates
+        .iter()
+        .map(|x| (levenshtein(&label, &x.to_lowercase()), *x))
+        .filter(|&(d, x)| d <= label.chars().count().max(x.chars().count()) / 2)
+        .min_by_key(|x| x.0)
+        .map(|x| x.1)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{levenshtein, suggest};
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("abc", "abc"), 0);
+    }
+
+    #[test]
+    fn test_suggest() {
+        let candidates = vec!["revenue_total", "cost", "region"];
+        assert_eq!(suggest("Revenue", &candidates), Some("revenue_total"));
+        assert_eq!(suggest("cots", &candidates), Some("cost"));
+        assert_eq!(suggest("xyz", &candidates), None);
+    }
+}
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Encode values as codes, which are locations in uniques.
+///
+/// Uniques are in order of first appearance.
+pub fn factorize<T>(values: &[T]) -> (Vec<usize>, Vec<T>)
+where
+    T: Clone + Hash + Eq,
+{
+    let mut map: HashMap<&T, usize> = HashMap::new();
+    let mut uniques: Vec<T> = vec![];
+    let codes: Vec<usize> = values
+        .iter()
+        .map(|v| {
+            *map.entry(v).or_insert_with(|| {
+                uniques.push(v.clone());
+                uniques.len() - 1
+            })
+        })
+        .collect();
+    (codes, uniques)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::factorize;
+
+    #[test]
+    fn test_factorize() {
+        let (codes, uniques) = factorize(&["b", "a", "b", "c", "a"]);
+        assert_eq!(codes, vec![0, 1, 0, 2, 1]);
+        assert_eq!(uniques, vec!["b", "a", "c"]);
+
+        let (codes, uniques) = factorize::<i64>(&[]);
+        assert!(codes.is_empty());
+        assert!(uniques.is_empty());
+    }
+}
@@ -0,0 +1,49 @@
+use rustfft::FftPlanner;
+use rustfft::num_complex::Complex;
+
+/// Discrete Fourier transform of real `values` as (real, imaginary) pairs.
+///
+/// Any length is computed in O(n log n) by rustfft, which plans mixed-radix,
+/// Rader's or Bluestein's algorithm depending on the factors of the length.
+pub fn fft(values: &[f64]) -> Vec<(f64, f64)> {
+    if values.is_empty() {
+        return vec![];
+    }
+    let mut buffer: Vec<Complex<f64>> = values.iter().map(|&x| Complex::new(x, 0.)).collect();
+    FftPlanner::new().plan_fft_forward(buffer.len()).process(&mut buffer);
+    buffer.into_iter().map(|x| (x.re, x.im)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::fft;
+
+    #[test]
+    fn test_fft() {
+        let res = fft(&[1., 2., 3., 4.]);
+        let exp = vec![(10., 0.), (-2., 2.), (-2., 0.), (-2., -2.)];
+        for (r, e) in res.iter().zip(&exp) {
+            assert!((r.0 - e.0).abs() < 1e-12 && (r.1 - e.1).abs() < 1e-12);
+        }
+
+        let res = fft(&[1., 1., 1.]);
+        assert!((res[0].0 - 3.).abs() < 1e-12);
+        assert!(res[1].0.abs() < 1e-12 && res[2].1.abs() < 1e-12);
+        assert!(fft(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_fft_long() {
+        // prime length, a single tone at bin 1000
+        let n = 100_003;
+        let values: Vec<f64> = (0..n)
+            .map(|t| (2. * ::std::f64::consts::PI * 1000. * t as f64 / n as f64).cos())
+            .collect();
+        let res = fft(&values);
+        assert_eq!(res.len(), n);
+        assert!((res[1000].0 - n as f64 / 2.).abs() < 1e-6);
+        assert!((res[n - 1000].0 - n as f64 / 2.).abs() < 1e-6);
+        assert!(res[999].0.abs() < 1e-6 && res[0].0.abs() < 1e-6);
+    }
+}
@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 
+use super::factorize::factorize;
+
 // ToDo: use Cow?
 
+#[derive(Clone)]
 pub struct HashGrouper<T> {
     pub groups: HashMap<T, Vec<usize>>,
 }
@@ -15,19 +18,27 @@ pub trait Grouper<T> {
     fn len(&self) -> usize;
 }
 
+impl<T> HashGrouper<T>
+where
+    T: Hash + Eq,
+{
+    /// Create groups from factorized keys, `codes` are locations in `uniques`.
+    pub fn from_codes(codes: &[usize], uniques: Vec<T>) -> HashGrouper<T> {
+        let mut locs: Vec<Vec<usize>> = vec![vec![]; uniques.len()];
+        for (i, code) in codes.iter().enumerate() {
+            locs[*code].push(i);
+        }
+        HashGrouper { groups: uniques.into_iter().zip(locs).collect() }
+    }
+}
+
 impl<T> Grouper<T> for HashGrouper<T>
 where
     T: Clone + Hash + Eq,
 {
     fn groupby(key: &[T]) -> HashGrouper<T> {
-
-        let mut map: HashMap<T, Vec<usize>> = HashMap::new();
-
-        for (i, k) in key.iter().enumerate() {
-            let e = map.entry(k.clone()).or_insert_with(Vec::<usize>::new);
-            e.push(i);
-        }
-        HashGrouper { groups: map }
+        let (codes, uniques) = factorize(key);
+        HashGrouper::from_codes(&codes, uniques)
     }
 
     fn get(&self, key: &T) -> Option<&Vec<usize>> {
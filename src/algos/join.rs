@@ -1,15 +1,23 @@
 use std::borrow::Cow;
-use std::cmp;
+use std::cmp::{self, Ordering};
+use std::collections::HashMap;
 use std::hash::Hash;
+use std::ops::Sub;
 use std::usize;
 
 use super::set::{CowCollections, union};
+use traits::{How, Direction};
 
 const USIZE_MISSING: usize = usize::MAX;
 
 
 pub struct HashJoin;
 
+/// Join by merging sorted keys, results are ordered by keys.
+///
+/// Unsorted keys are sorted internally.
+pub struct SortMergeJoin;
+
 pub trait JoinOp<T> {
     fn inner(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>);
     fn left(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>);
@@ -118,10 +126,274 @@ where
     }
 }
 
+impl<T> JoinOp<T> for SortMergeJoin
+where
+    T: Clone + Ord,
+{
+    fn inner(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
+        sort_merge(left, right, false, false)
+    }
+
+    fn left(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
+        SortMergeJoin::keep_first(left, right)
+    }
+
+    fn right(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
+        let res = SortMergeJoin::keep_first(right, left);
+        (res.0, res.2, res.1)
+    }
+
+    fn outer(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
+        sort_merge(left, right, true, true)
+    }
+
+    fn keep_first(keep: &[T], other: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
+        sort_merge(keep, other, true, false)
+    }
+}
+
+/// Locations which sort values, values are not sorted if already sorted.
+fn sorted_order<T: Ord>(values: &[T]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    if !values.is_sorted() {
+        order.sort_by(|&a, &b| values[a].cmp(&values[b]));
+    }
+    order
+}
+
+fn sort_merge<T>(
+    left: &[T],
+    right: &[T],
+    keep_left: bool,
+    keep_right: bool,
+) -> (Vec<T>, Vec<usize>, Vec<usize>)
+where
+    T: Clone + Ord,
+{
+    let lorder = sorted_order(left);
+    let rorder = sorted_order(right);
+
+    let exp_capacity = cmp::max(left.len(), right.len());
+    let mut indexer: Vec<T> = Vec::with_capacity(exp_capacity);
+    let mut lindexer: Vec<usize> = Vec::with_capacity(exp_capacity);
+    let mut rindexer: Vec<usize> = Vec::with_capacity(exp_capacity);
+
+    let (mut i, mut j) = (0, 0);
+    loop {
+        let ord = match (lorder.get(i), rorder.get(j)) {
+            (Some(&l), Some(&r)) => left[l].cmp(&right[r]),
+            (Some(_), None) if keep_left => Ordering::Less,
+            (None, Some(_)) if keep_right => Ordering::Greater,
+            _ => break,
+        };
+        match ord {
+            Ordering::Equal => {
+                indexer.push(left[lorder[i]].clone());
+                lindexer.push(lorder[i]);
+                rindexer.push(rorder[j]);
+                i += 1;
+                j += 1;
+            }
+            Ordering::Less => {
+                if keep_left {
+                    indexer.push(left[lorder[i]].clone());
+                    lindexer.push(lorder[i]);
+                    rindexer.push(USIZE_MISSING);
+                }
+                i += 1;
+            }
+            Ordering::Greater => {
+                if keep_right {
+                    indexer.push(right[rorder[j]].clone());
+                    lindexer.push(USIZE_MISSING);
+                    rindexer.push(rorder[j]);
+                }
+                j += 1;
+            }
+        }
+    }
+    (indexer, lindexer, rindexer)
+}
+
+/// Locations of right keys matching each left key by `direction`.
+///
+/// Keys farther than `tolerance` are not matched, and `usize::MAX` is
+/// returned for left keys without match.
+pub fn asof_locs<T>(
+    left: &[T],
+    right: &[T],
+    tolerance: Option<T>,
+    direction: Direction,
+) -> Vec<usize>
+where
+    T: Clone + Ord + Sub<Output = T>,
+{
+    let rorder = sorted_order(right);
+    let within = |distance: T| tolerance.as_ref().is_none_or(|t| distance <= *t);
+
+    left.iter()
+        .map(|key| {
+            // candidate locations in rorder
+            let backward = match rorder.partition_point(|&r| right[r] <= *key) {
+                0 => None,
+                n => Some(rorder[n - 1]),
+            };
+            let forward = rorder
+                .get(rorder.partition_point(|&r| right[r] < *key))
+                .cloned();
+            let backward = backward.filter(|&r| within(key.clone() - right[r].clone()));
+            let forward = forward.filter(|&r| within(right[r].clone() - key.clone()));
+            let matched = match direction {
+                Direction::Backward => backward,
+                Direction::Forward => forward,
+                Direction::Nearest => {
+                    match (backward, forward) {
+                        (Some(b), Some(f)) => {
+                            if right[f].clone() - key.clone() < key.clone() - right[b].clone() {
+                                Some(f)
+                            } else {
+                                Some(b)
+                            }
+                        }
+                        (b, f) => b.or(f),
+                    }
+                }
+            };
+            matched.unwrap_or(USIZE_MISSING)
+        })
+        .collect()
+}
+
+/// Join locations of keys which may be duplicated, all combinations of matched
+/// rows are included. Locations without match are `usize::MAX`.
+///
+/// Rows are ordered by left keys, except `How::Right` which is ordered by right keys.
+/// `How::Outer` appends rows only in right after left ones.
+pub fn hash_merge<T>(left: &[T], right: &[T], how: How) -> (Vec<usize>, Vec<usize>)
+where
+    T: Hash + Eq,
+{
+    if how == How::Right {
+        let (rindexer, lindexer) = hash_merge(right, left, How::Left);
+        return (lindexer, rindexer);
+    }
+
+    let mut map: HashMap<&T, Vec<usize>> = HashMap::new();
+    for (i, key) in right.iter().enumerate() {
+        map.entry(key).or_default().push(i);
+    }
+
+    let mut lindexer: Vec<usize> = Vec::with_capacity(left.len());
+    let mut rindexer: Vec<usize> = Vec::with_capacity(left.len());
+    let mut matched: Vec<bool> = vec![false; right.len()];
+    for (i, key) in left.iter().enumerate() {
+        match map.get(key) {
+            Some(locs) => {
+                for loc in locs {
+                    lindexer.push(i);
+                    rindexer.push(*loc);
+                    matched[*loc] = true;
+                }
+            }
+            None => {
+                if how != How::Inner {
+                    lindexer.push(i);
+                    rindexer.push(USIZE_MISSING);
+                }
+            }
+        }
+    }
+    if how == How::Outer {
+        for (i, _) in matched.iter().enumerate().filter(|&(_, m)| !m) {
+            lindexer.push(USIZE_MISSING);
+            rindexer.push(i);
+        }
+    }
+    (lindexer, rindexer)
+}
+
 #[cfg(test)]
 mod tests {
 
-    use super::{JoinOp, HashJoin, USIZE_MISSING};
+    use super::{JoinOp, HashJoin, SortMergeJoin, USIZE_MISSING, asof_locs, hash_merge};
+    use traits::{How, Direction};
+
+    #[test]
+    fn test_asof_locs() {
+        let left = vec![1, 5, 10, 12];
+        let right = vec![8, 2, 5, 20];
+        const M: usize = USIZE_MISSING;
+
+        let res = asof_locs(&left, &right, None, Direction::Backward);
+        assert_eq!(res, vec![M, 2, 0, 0]);
+        let res = asof_locs(&left, &right, None, Direction::Forward);
+        assert_eq!(res, vec![1, 2, 3, 3]);
+        let res = asof_locs(&left, &right, None, Direction::Nearest);
+        assert_eq!(res, vec![1, 2, 0, 0]);
+
+        let res = asof_locs(&left, &right, Some(2), Direction::Backward);
+        assert_eq!(res, vec![M, 2, 0, M]);
+        let res = asof_locs(&left, &right, Some(1), Direction::Nearest);
+        assert_eq!(res, vec![1, 2, M, M]);
+    }
+
+    #[test]
+    fn test_sort_merge_join() {
+        let v1 = vec![1, 3, 5, 7];
+        let v2 = vec![2, 3, 4, 7, 8];
+
+        let res = SortMergeJoin::inner(&v1, &v2);
+        assert_eq!(res, (vec![3, 7], vec![1, 3], vec![1, 3]));
+
+        let res = SortMergeJoin::left(&v1, &v2);
+        assert_eq!(res.0, v1);
+        assert_eq!(res.1, vec![0, 1, 2, 3]);
+        assert_eq!(res.2, vec![USIZE_MISSING, 1, USIZE_MISSING, 3]);
+
+        let res = SortMergeJoin::right(&v1, &v2);
+        assert_eq!(res.0, v2);
+        assert_eq!(res.1, vec![USIZE_MISSING, 1, USIZE_MISSING, 3, USIZE_MISSING]);
+        assert_eq!(res.2, vec![0, 1, 2, 3, 4]);
+
+        let res = SortMergeJoin::outer(&v1, &v2);
+        assert_eq!(res.0, vec![1, 2, 3, 4, 5, 7, 8]);
+        assert_eq!(res.1, vec![0, USIZE_MISSING, 1, USIZE_MISSING, 2, 3, USIZE_MISSING]);
+        assert_eq!(res.2, vec![USIZE_MISSING, 0, 1, 2, USIZE_MISSING, 3, 4]);
+
+        // unsorted keys are ordered in the result
+        let res = SortMergeJoin::inner(&[7, 1, 3], &[3, 7]);
+        assert_eq!(res, (vec![3, 7], vec![2, 0], vec![0, 1]));
+    }
+
+    #[test]
+    fn test_hash_merge() {
+        let v1 = vec![1, 2, 2, 3];
+        let v2 = vec![2, 4, 2, 1];
+
+        let res = hash_merge(&v1, &v2, How::Inner);
+        assert_eq!(res, (vec![0, 1, 1, 2, 2], vec![3, 0, 2, 0, 2]));
+
+        let res = hash_merge(&v1, &v2, How::Left);
+        assert_eq!(
+            res,
+            (vec![0, 1, 1, 2, 2, 3], vec![3, 0, 2, 0, 2, USIZE_MISSING])
+        );
+
+        let res = hash_merge(&v1, &v2, How::Right);
+        assert_eq!(
+            res,
+            (vec![1, 2, USIZE_MISSING, 1, 2, 0], vec![0, 0, 1, 2, 2, 3])
+        );
+
+        let res = hash_merge(&v1, &v2, How::Outer);
+        assert_eq!(
+            res,
+            (
+                vec![0, 1, 1, 2, 2, 3, USIZE_MISSING],
+                vec![3, 0, 2, 0, 2, USIZE_MISSING, 1],
+            )
+        );
+    }
 
     #[test]
     fn test_vec_inner_join() {
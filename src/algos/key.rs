@@ -0,0 +1,314 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use nullvec::prelude::{Array, Scalar};
+use traits::{Slicer, NullEquality};
+
+/// Hashable representation of `Scalar`, used to group or join on `Array` values.
+///
+/// Integers of any dtype are equal if their values are equal. Floats are
+/// compared by bits, `-0.0` is regarded as `0.0` and all NaN are equal.
+/// `Null` is equal to `Null`.
+///
+/// Keys are ordered as `Null`, bools, numbers and strings. Integers and floats
+/// are compared by their values, integers come first if the values are equal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalarKey {
+    Null,
+    Bool(bool),
+    Int(i128),
+    Float(u64),
+    Str(String),
+}
+
+impl<'a> From<&'a Scalar> for ScalarKey {
+    fn from(value: &'a Scalar) -> ScalarKey {
+        match *value {
+            Scalar::Null => ScalarKey::Null,
+            Scalar::bool(v) => ScalarKey::Bool(v),
+            Scalar::i64(v) => ScalarKey::Int(i128::from(v)),
+            Scalar::i32(v) => ScalarKey::Int(i128::from(v)),
+            Scalar::i16(v) => ScalarKey::Int(i128::from(v)),
+            Scalar::i8(v) => ScalarKey::Int(i128::from(v)),
+            Scalar::isize(v) => ScalarKey::Int(v as i128),
+            Scalar::u64(v) => ScalarKey::Int(i128::from(v)),
+            Scalar::u32(v) => ScalarKey::Int(i128::from(v)),
+            Scalar::u16(v) => ScalarKey::Int(i128::from(v)),
+            Scalar::u8(v) => ScalarKey::Int(i128::from(v)),
+            Scalar::usize(v) => ScalarKey::Int(v as i128),
+            Scalar::f64(v) => float_key(v),
+            Scalar::f32(v) => float_key(f64::from(v)),
+            Scalar::String(ref v) => ScalarKey::Str(v.clone()),
+        }
+    }
+}
+
+impl ScalarKey {
+    /// Convert to `Scalar`, integers are converted to `i64`.
+    pub fn to_scalar(&self) -> Scalar {
+        match *self {
+            ScalarKey::Null => Scalar::Null,
+            ScalarKey::Bool(v) => Scalar::bool(v),
+            ScalarKey::Int(v) => Scalar::i64(v as i64),
+            ScalarKey::Float(v) => Scalar::f64(f64::from_bits(v)),
+            ScalarKey::Str(ref v) => Scalar::String(v.clone()),
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match *self {
+            ScalarKey::Null => 0,
+            ScalarKey::Bool(_) => 1,
+            ScalarKey::Int(_) | ScalarKey::Float(_) => 2,
+            ScalarKey::Str(_) => 3,
+        }
+    }
+}
+
+impl PartialOrd for ScalarKey {
+    fn partial_cmp(&self, other: &ScalarKey) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScalarKey {
+    fn cmp(&self, other: &ScalarKey) -> Ordering {
+        match (self, other) {
+            (ScalarKey::Bool(l), ScalarKey::Bool(r)) => l.cmp(r),
+            (ScalarKey::Int(l), ScalarKey::Int(r)) => l.cmp(r),
+            (ScalarKey::Float(l), ScalarKey::Float(r)) => {
+                f64::from_bits(*l).total_cmp(&f64::from_bits(*r))
+            }
+            (ScalarKey::Int(l), ScalarKey::Float(r)) => {
+                (*l as f64).total_cmp(&f64::from_bits(*r)).then(Ordering::Less)
+            }
+            (ScalarKey::Float(l), ScalarKey::Int(r)) => {
+                f64::from_bits(*l).total_cmp(&(*r as f64)).then(Ordering::Greater)
+            }
+            (ScalarKey::Str(l), ScalarKey::Str(r)) => l.cmp(r),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl fmt::Display for ScalarKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_scalar())
+    }
+}
+
+fn float_key(value: f64) -> ScalarKey {
+    ScalarKey::Float(float_bits(value))
+}
+
+fn float_bits(value: f64) -> u64 {
+    if value.is_nan() {
+        f64::NAN.to_bits()
+    } else if value == 0. {
+        0f64.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
+
+/// Key of the row at `loc`, composed of values in `arrays`.
+pub fn row_key(arrays: &[&Array], loc: usize) -> Vec<ScalarKey> {
+    arrays.iter().map(|x| ScalarKey::from(&x.iloc(&loc))).collect()
+}
+
+/// Keys of each row, composed of values in `arrays`.
+pub fn row_keys(arrays: &[&Array]) -> Vec<Vec<ScalarKey>> {
+    let len = arrays.first().map_or(0, |x| x.len());
+    (0..len).map(|i| row_key(arrays, i)).collect()
+}
+
+/// Keys of each row tagged by `nulls` semantics.
+///
+/// Rows are tagged by 0 unless `nulls` is `NullEquality::Distinct` and the
+/// row contains `Null`, in which case the tag is unique to the row. `side`
+/// (0 or 1) keeps tags of rows in different frames distinct.
+pub fn tagged_row_keys(
+    arrays: &[&Array],
+    nulls: NullEquality,
+    side: usize,
+) -> Vec<(Vec<ScalarKey>, usize)> {
+    row_keys(arrays)
+        .into_iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let tag = match nulls {
+                NullEquality::Distinct if key.contains(&ScalarKey::Null) => i * 2 + side + 1,
+                _ => 0,
+            };
+            (key, tag)
+        })
+        .collect()
+}
+
+/// Value of an `Array` element borrowed in place, equal as `ScalarKey` is.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Cell<'a> {
+    Null,
+    Bool(bool),
+    Int(i128),
+    Float(u64),
+    Str(&'a str),
+}
+
+macro_rules! cells_of {
+    ($values:expr, $cell:expr) => {
+        $values
+            .iter_raw()
+            .map(|(null, x)| if null { Cell::Null } else { $cell(x) })
+            .collect()
+    };
+}
+
+fn cells<'a>(array: &'a Array) -> Vec<Cell<'a>> {
+    match *array {
+        Array::Int64Array(ref v) => cells_of!(v, |&x: &i64| Cell::Int(i128::from(x))),
+        Array::Int32Array(ref v) => cells_of!(v, |&x: &i32| Cell::Int(i128::from(x))),
+        Array::Int16Array(ref v) => cells_of!(v, |&x: &i16| Cell::Int(i128::from(x))),
+        Array::Int8Array(ref v) => cells_of!(v, |&x: &i8| Cell::Int(i128::from(x))),
+        Array::IsizeArray(ref v) => cells_of!(v, |&x: &isize| Cell::Int(x as i128)),
+        Array::UInt64Array(ref v) => cells_of!(v, |&x: &u64| Cell::Int(i128::from(x))),
+        Array::UInt32Array(ref v) => cells_of!(v, |&x: &u32| Cell::Int(i128::from(x))),
+        Array::UInt16Array(ref v) => cells_of!(v, |&x: &u16| Cell::Int(i128::from(x))),
+        Array::UInt8Array(ref v) => cells_of!(v, |&x: &u8| Cell::Int(i128::from(x))),
+        Array::UsizeArray(ref v) => cells_of!(v, |&x: &usize| Cell::Int(x as i128)),
+        Array::Float64Array(ref v) => cells_of!(v, |&x: &f64| Cell::Float(float_bits(x))),
+        Array::Float32Array(ref v) => {
+            cells_of!(v, |&x: &f32| Cell::Float(float_bits(f64::from(x))))
+        }
+        Array::BoolArray(ref v) => cells_of!(v, |&x: &bool| Cell::Bool(x)),
+        Array::StringArray(ref v) => cells_of!(v, |x: &'a String| Cell::Str(x)),
+    }
+}
+
+fn row_hash(columns: &[Vec<Cell>], loc: usize) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for column in columns {
+        column[loc].hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn row_equals(columns: &[Vec<Cell>], left: usize, right: usize) -> bool {
+    columns.iter().all(|x| x[left] == x[right])
+}
+
+/// Encode rows of `arrays` as codes, without materializing keys of each row.
+///
+/// Rows are looked up by the hash of their values, and rows having the same
+/// hash are compared value by value. Returns codes of each row and the first
+/// location of each unique row, in order of first appearance.
+pub fn factorize_rows(arrays: &[&Array]) -> (Vec<usize>, Vec<usize>) {
+    factorize_rows_by(arrays, row_hash)
+}
+
+fn factorize_rows_by<F>(arrays: &[&Array], hash: F) -> (Vec<usize>, Vec<usize>)
+where
+    F: Fn(&[Vec<Cell>], usize) -> u64,
+{
+    let columns: Vec<Vec<Cell>> = arrays.iter().map(|x| cells(x)).collect();
+    let len = arrays.first().map_or(0, |x| x.len());
+    // hash to codes of unique rows having the hash
+    let mut table: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut firsts: Vec<usize> = vec![];
+    let mut codes: Vec<usize> = Vec::with_capacity(len);
+    for i in 0..len {
+        let candidates = table.entry(hash(&columns, i)).or_default();
+        let code = match candidates
+            .iter()
+            .find(|&&c| row_equals(&columns, firsts[c], i)) {
+            Some(&c) => c,
+            None => {
+                firsts.push(i);
+                candidates.push(firsts.len() - 1);
+                firsts.len() - 1
+            }
+        };
+        codes.push(code);
+    }
+    (codes, firsts)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec, Scalar};
+
+    use super::{ScalarKey, row_keys, factorize_rows, factorize_rows_by};
+
+    #[test]
+    fn test_scalar_key() {
+        assert_eq!(
+            ScalarKey::from(&Scalar::i32(1)),
+            ScalarKey::from(&Scalar::u64(1))
+        );
+        assert_eq!(
+            ScalarKey::from(&Scalar::f64(-0.)),
+            ScalarKey::from(&Scalar::f64(0.))
+        );
+        assert!(ScalarKey::from(&Scalar::f64(1.)) != ScalarKey::from(&Scalar::i64(1)));
+
+        let mut keys = vec![
+            ScalarKey::Str("a".to_string()),
+            ScalarKey::from(&Scalar::f64(1.5)),
+            ScalarKey::Int(2),
+            ScalarKey::Null,
+            ScalarKey::from(&Scalar::f64(1.)),
+            ScalarKey::Int(1),
+        ];
+        keys.sort();
+        let res: Vec<String> = keys.iter().map(|x| x.to_string()).collect();
+        assert_eq!(res, vec!["Null", "1", "1", "1.5", "2", "a"]);
+        assert_eq!(keys[1], ScalarKey::Int(1));
+
+        let a = Array::Int64Array(vec![1, 2].into());
+        let b = Array::StringArray(vec!["x".to_string(), "y".to_string()].into());
+        let res = row_keys(&[&a, &b]);
+        assert_eq!(
+            res,
+            vec![
+                vec![ScalarKey::Int(1), ScalarKey::Str("x".to_string())],
+                vec![ScalarKey::Int(2), ScalarKey::Str("y".to_string())],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_factorize_rows() {
+        let a = Array::Int64Array(vec![1, 2, 1, 2, 1].into());
+        let b = Array::StringArray(
+            vec!["x", "y", "x", "x", "x"].iter().map(|x| x.to_string()).collect::<Vec<_>>().into(),
+        );
+        let c = Array::Float64Array(vec![0., 1., -0., 1., 2.].into());
+        let (codes, firsts) = factorize_rows(&[&a, &b, &c]);
+        assert_eq!(codes, vec![0, 1, 0, 2, 3]);
+        assert_eq!(firsts, vec![0, 1, 3, 4]);
+
+        let (codes, firsts) = factorize_rows(&[]);
+        assert!(codes.is_empty());
+        assert!(firsts.is_empty());
+    }
+
+    #[test]
+    fn test_factorize_rows_hash_collision() {
+        let a = Array::StringArray(
+            vec!["x", "y", "x", "z"].iter().map(|x| x.to_string()).collect::<Vec<_>>().into(),
+        );
+        let b = Array::Int32Array(NullVec::with_mask(
+            vec![1, 0, 1, 1],
+            Some(vec![false, true, false, false]),
+        ));
+        // every row has the same hash, rows are told apart by their values
+        let (codes, firsts) = factorize_rows_by(&[&a, &b], |_, _| 0);
+        assert_eq!(codes, vec![0, 1, 0, 2]);
+        assert_eq!(firsts, vec![0, 1, 3]);
+        assert_eq!(factorize_rows(&[&a, &b]), (codes, firsts));
+    }
+}
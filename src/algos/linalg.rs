@@ -0,0 +1,103 @@
+/// Maximum number of sweeps in `symmetric_eigen`.
+const MAX_SWEEPS: usize = 100;
+
+/// Eigenvalues and eigenvectors of symmetric `matrix` by cyclic Jacobi
+/// rotations, sorted by descending eigenvalues.
+///
+/// Each eigenvector is normalized and its largest element in absolute value
+/// is positive, so that results are deterministic.
+pub fn symmetric_eigen(matrix: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let n = matrix.len();
+    let mut a: Vec<Vec<f64>> = matrix.to_vec();
+    // columns of v are eigenvectors
+    let mut v: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1. } else { 0. }).collect())
+        .collect();
+
+    for _ in 0..MAX_SWEEPS {
+        let off: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum();
+        if off < 1e-22 {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q] == 0. {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+                let c = 1. / (t * t + 1.).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+                let (row_p, row_q) = (a[p].clone(), a[q].clone());
+                for k in 0..n {
+                    a[p][k] = c * row_p[k] - s * row_q[k];
+                    a[q][k] = s * row_p[k] + c * row_q[k];
+                }
+                for row in v.iter_mut() {
+                    let (x, y) = (row[p], row[q]);
+                    row[p] = c * x - s * y;
+                    row[q] = s * x + c * y;
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&x, &y| a[y][y].partial_cmp(&a[x][x]).unwrap());
+    let values: Vec<f64> = order.iter().map(|&i| a[i][i]).collect();
+    let vectors: Vec<Vec<f64>> = order
+        .iter()
+        .map(|&j| {
+            let vector: Vec<f64> = v.iter().map(|row| row[j]).collect();
+            let largest = vector.iter().cloned().fold(0., |m: f64, x| {
+                if x.abs() > m.abs() { x } else { m }
+            });
+            if largest < 0. {
+                vector.into_iter().map(|x| -x).collect()
+            } else {
+                vector
+            }
+        })
+        .collect();
+    (values, vectors)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::symmetric_eigen;
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let matrix = vec![vec![2., 1.], vec![1., 2.]];
+        let (values, vectors) = symmetric_eigen(&matrix);
+        assert!((values[0] - 3.).abs() < 1e-12);
+        assert!((values[1] - 1.).abs() < 1e-12);
+        let h = 0.5f64.sqrt();
+        assert!((vectors[0][0] - h).abs() < 1e-12 && (vectors[0][1] - h).abs() < 1e-12);
+        assert!((vectors[1][0].abs() - h).abs() < 1e-12);
+        assert!((vectors[1][0] + vectors[1][1]).abs() < 1e-12);
+
+        let matrix = vec![
+            vec![4., 1., 2.],
+            vec![1., 3., 0.],
+            vec![2., 0., 5.],
+        ];
+        let (values, vectors) = symmetric_eigen(&matrix);
+        for (value, vector) in values.iter().zip(&vectors) {
+            for i in 0..3 {
+                let av: f64 = (0..3).map(|j| matrix[i][j] * vector[j]).sum();
+                assert!((av - value * vector[i]).abs() < 1e-10);
+            }
+        }
+        assert!(values[0] >= values[1] && values[1] >= values[2]);
+    }
+}
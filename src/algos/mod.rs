@@ -1,6 +1,15 @@
+pub mod cluster;
 pub mod computation;
+pub mod correlation;
 pub mod counter;
-mod duplicates;
+pub mod distance;
+pub mod duplicates;
+pub mod factorize;
+#[cfg(feature = "rustfft")]
+pub mod fft;
 pub mod grouper;
 pub mod join;
+pub mod key;
+pub mod linalg;
+pub mod parallel;
 mod set;
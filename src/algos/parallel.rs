@@ -0,0 +1,133 @@
+use std::cmp::Ordering;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+use super::computation::Aggregation;
+use traits::SortKind;
+#[cfg(feature = "rayon")]
+use traits::{Summation, Reduction};
+
+/// Number of values summed by a task in `par_float_sum`.
+#[cfg(feature = "rayon")]
+const SUM_BLOCK: usize = 1 << 16;
+
+/// Sum of `values` by `method` using rayon.
+///
+/// Values are split into blocks of fixed size regardless of the number of
+/// threads. With `Reduction::Deterministic`, partial sums are added in the
+/// order of blocks, thus the result is the same across runs and machines.
+/// With `Reduction::Unordered`, partial sums of `Summation::Kahan` carry their
+/// compensation until all of them are added.
+#[cfg(feature = "rayon")]
+pub fn par_float_sum(values: &[f64], method: Summation, reduction: Reduction) -> f64 {
+    let partials = values.par_chunks(SUM_BLOCK).map(|x| Aggregation::float_sum(x, method));
+    match (reduction, method) {
+        (Reduction::Deterministic, _) => {
+            let partials: Vec<f64> = partials.collect();
+            Aggregation::float_sum(&partials, method)
+        }
+        (Reduction::Unordered, Summation::Kahan) => {
+            let (sum, compensation) = partials.map(|x| (x, 0.)).reduce(|| (0., 0.), kahan_add);
+            sum + compensation
+        }
+        (Reduction::Unordered, _) => partials.reduce(|| 0., |x, y| x + y),
+    }
+}
+
+/// Add pairs of sum and its compensation, keeping bits lost by the addition.
+#[cfg(feature = "rayon")]
+fn kahan_add(left: (f64, f64), right: (f64, f64)) -> (f64, f64) {
+    let sum = left.0 + right.0;
+    let lost = if left.0.abs() >= right.0.abs() {
+        (left.0 - sum) + right.0
+    } else {
+        (right.0 - sum) + left.0
+    };
+    (sum, left.1 + right.1 + lost)
+}
+
+/// Sort `values` by `cmp` using the algorithm of `kind`.
+pub fn sort_by_kind<T, F>(mut values: Vec<T>, kind: SortKind, cmp: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    match kind {
+        SortKind::Stable => values.sort_by(cmp),
+        SortKind::Unstable => values.sort_unstable_by(cmp),
+        #[cfg(feature = "rayon")]
+        SortKind::Parallel => values.par_sort_unstable_by(cmp),
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::sort_by_kind;
+    use traits::SortKind;
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_float_sum() {
+        use super::{par_float_sum, SUM_BLOCK};
+        use algos::computation::Aggregation;
+        use traits::{Summation, Reduction};
+
+        let values: Vec<f64> = (0..SUM_BLOCK * 3 + 7).map(|x| 1. / (x as f64 + 1.)).collect();
+        let res = par_float_sum(&values, Summation::Naive, Reduction::Deterministic);
+        for _ in 0..5 {
+            assert_eq!(par_float_sum(&values, Summation::Naive, Reduction::Deterministic), res);
+        }
+        // same as summing blocks sequentially
+        let partials: Vec<f64> = values
+            .chunks(SUM_BLOCK)
+            .map(|x| Aggregation::float_sum(x, Summation::Naive))
+            .collect();
+        assert_eq!(res, Aggregation::float_sum(&partials, Summation::Naive));
+        let unordered = par_float_sum(&values, Summation::Naive, Reduction::Unordered);
+        assert!((unordered - res).abs() < 1e-9);
+
+        let exp = Aggregation::float_sum(&values, Summation::Kahan);
+        assert_eq!(par_float_sum(&values, Summation::Kahan, Reduction::Deterministic), exp);
+        assert_eq!(par_float_sum(&[], Summation::Kahan, Reduction::Deterministic), 0.);
+        assert_eq!(par_float_sum(&[], Summation::Kahan, Reduction::Unordered), 0.);
+
+        // partial sums of blocks are 1e16, 1 and -1e16
+        let mut values = vec![0.; SUM_BLOCK * 3];
+        values[0] = 1e16;
+        values[SUM_BLOCK] = 1.;
+        values[SUM_BLOCK * 2] = -1e16;
+        for _ in 0..5 {
+            assert_eq!(par_float_sum(&values, Summation::Kahan, Reduction::Unordered), 1.);
+        }
+    }
+
+    #[test]
+    fn test_sort_by_kind() {
+        // (key, original location), keys have many ties
+        let values: Vec<(usize, usize)> = (0..100_003).map(|i| ((i * 7919) % 101, i)).collect();
+        let mut exp = values.clone();
+        exp.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let res = sort_by_kind(values.clone(), SortKind::Stable, |a, b| a.0.cmp(&b.0));
+        assert_eq!(res, exp);
+        let res = sort_by_kind(values.clone(), SortKind::Unstable, |a, b| a.0.cmp(&b.0));
+        assert!(res.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert!(sort_by_kind(Vec::<i64>::new(), SortKind::Unstable, |a, b| a.cmp(b)).is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_sort_by_kind_parallel() {
+        let values: Vec<(usize, usize)> = (0..100_003).map(|i| ((i * 7919) % 101, i)).collect();
+        let res = sort_by_kind(values.clone(), SortKind::Parallel, |a, b| b.0.cmp(&a.0));
+        assert!(res.windows(2).all(|w| w[0].0 >= w[1].0));
+        let mut res: Vec<usize> = res.into_iter().map(|x| x.1).collect();
+        res.sort();
+        assert_eq!(res, (0..100_003).collect::<Vec<usize>>());
+        assert!(sort_by_kind(Vec::<i64>::new(), SortKind::Parallel, |a, b| a.cmp(b)).is_empty());
+    }
+}
@@ -43,7 +43,7 @@ impl<'v, 'i, 'c, V, I, C> RowIndex<'c> for Block<'v, 'i, 'c, V, I, C>
     type Key = I;
     type Row = V;
 
-    fn len(&'c self) -> usize {
+    fn len(&self) -> usize {
         self.index.len()
     }
 
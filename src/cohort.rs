@@ -0,0 +1,91 @@
+//! Cohort retention of event data, grouped by calendar `Period`.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use nullvec::prelude::{Nullable, NullVec};
+
+use frame::DataFrame;
+use period::Period;
+
+/// Retention matrix of cohorts from events.
+///
+/// Each event is given by its user, the period of the user's first event
+/// (cohort) and the period of the event. Index of the result is cohorts in
+/// chronological order, and columns are the number of periods since the
+/// cohort starting from 0. Each cell is the fraction of users in the cohort
+/// active in that period, or null if the period is after the last event.
+pub fn cohort_retention<'n, U>(
+    users: &[U],
+    cohorts: &[Period],
+    activity: &[Period],
+) -> DataFrame<'n, 'n, 'n, Period, i64>
+where
+    U: Eq + Hash,
+{
+    assert!(users.len() == cohorts.len(), "Length mismatch!");
+    assert!(users.len() == activity.len(), "Length mismatch!");
+
+    let mut members: HashMap<Period, HashSet<&U>> = HashMap::new();
+    let mut active: HashMap<(Period, i64), HashSet<&U>> = HashMap::new();
+    for ((user, &cohort), &period) in users.iter().zip(cohorts).zip(activity) {
+        let offset = period - cohort;
+        assert!(offset >= 0, "activity must not precede the cohort");
+        members.entry(cohort).or_default().insert(user);
+        active.entry((cohort, offset)).or_default().insert(user);
+    }
+
+    let mut new_index: Vec<Period> = members.keys().cloned().collect();
+    new_index.sort();
+    let last = activity.iter().max();
+    let max_offset = last.map_or(-1, |&x| new_index.iter().map(|&c| x - c).max().unwrap());
+
+    let new_columns: Vec<i64> = (0..max_offset + 1).collect();
+    let new_values = new_columns
+        .iter()
+        .map(|&offset| {
+            let rates: NullVec<f64> = new_index
+                .iter()
+                .map(|cohort| if *last.unwrap() - *cohort < offset {
+                    Nullable::Null
+                } else {
+                    let count = active.get(&(*cohort, offset)).map_or(0, |x| x.len());
+                    Nullable::Value(count as f64 / members[cohort].len() as f64)
+                })
+                .collect();
+            rates.into()
+        })
+        .collect();
+    DataFrame::from_vec(new_values, new_index, new_columns)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, Nullable, NullVec};
+
+    use super::cohort_retention;
+    use period::Period;
+    use traits::{ColIndex, RowIndex};
+
+    #[test]
+    fn test_cohort_retention() {
+        let jan = Period::Month { year: 2018, month: 1 };
+        let (feb, mar) = (jan + 1, jan + 2);
+        let users = vec!["a", "a", "a", "b", "b", "c", "c", "d"];
+        let cohorts = vec![jan, jan, jan, jan, jan, feb, feb, feb];
+        let activity = vec![jan, jan, mar, jan, feb, feb, mar, feb];
+        let res = cohort_retention(&users, &cohorts, &activity);
+
+        assert_eq!(res.index.values, vec![jan, feb]);
+        assert_eq!(res.columns.values, vec![0, 1, 2]);
+        assert_eq!(res.len(), 2);
+        assert_eq!(res.get(&0), Array::new(vec![1., 1.]));
+        assert_eq!(res.get(&1), Array::new(vec![0.5, 0.5]));
+        let exp: NullVec<f64> = vec![Nullable::Value(0.5), Nullable::Null].into();
+        assert_eq!(res.get(&2), exp.into());
+
+        let res = cohort_retention::<&str>(&[], &[], &[]);
+        assert_eq!(res.len(), 0);
+    }
+}
@@ -0,0 +1,142 @@
+//! Vectorized conditional expression, `when(mask).then(value).otherwise(value)`.
+
+enum Branch<'a, V: 'a> {
+    Scalar(V),
+    Values(&'a [V]),
+}
+
+impl<'a, V: Clone> Branch<'a, V> {
+    fn get(&self, i: usize) -> V {
+        match *self {
+            Branch::Scalar(ref v) => v.clone(),
+            Branch::Values(values) => values[i].clone(),
+        }
+    }
+}
+
+/// Start a conditional expression, rows where `mask` is `true` take the value
+/// of the following `then`.
+///
+/// Conditions are evaluated in order, and the first matched branch is taken
+/// for each row. Rows matching no condition take the value of `otherwise`.
+pub fn when<'a, V>(mask: &'a [bool]) -> When<'a, V> {
+    When {
+        branches: vec![],
+        mask,
+    }
+}
+
+/// Conditional expression waiting for the value of the last condition.
+pub struct When<'a, V: 'a> {
+    branches: Vec<(&'a [bool], Branch<'a, V>)>,
+    mask: &'a [bool],
+}
+
+/// Conditional expression which can be followed by another condition or the
+/// default value.
+pub struct Then<'a, V: 'a> {
+    branches: Vec<(&'a [bool], Branch<'a, V>)>,
+}
+
+impl<'a, V: Clone> When<'a, V> {
+    fn push(mut self, branch: Branch<'a, V>) -> Then<'a, V> {
+        self.branches.push((self.mask, branch));
+        Then { branches: self.branches }
+    }
+
+    /// Value for rows matching the condition.
+    pub fn then(self, value: V) -> Then<'a, V> {
+        self.push(Branch::Scalar(value))
+    }
+
+    /// Values for rows matching the condition, taken from the same location.
+    pub fn then_values(self, values: &'a [V]) -> Then<'a, V> {
+        self.push(Branch::Values(values))
+    }
+}
+
+impl<'a, V: Clone> Then<'a, V> {
+    /// Add a condition, which is evaluated only for rows not matching
+    /// previous conditions.
+    pub fn when(self, mask: &'a [bool]) -> When<'a, V> {
+        When {
+            branches: self.branches,
+            mask,
+        }
+    }
+
+    fn evaluate(&self, default: &Branch<V>) -> Vec<V> {
+        let len = self.branches[0].0.len();
+        for &(mask, ref branch) in &self.branches {
+            assert!(mask.len() == len, "Length of masks are different");
+            if let Branch::Values(values) = *branch {
+                assert!(values.len() == len, "Length of values and mask are different");
+            }
+        }
+        (0..len)
+            .map(|i| {
+                self.branches
+                    .iter()
+                    .find(|x| x.0[i])
+                    .map_or_else(|| default.get(i), |x| x.1.get(i))
+            })
+            .collect()
+    }
+
+    /// Value for rows matching no condition, and evaluate the expression.
+    pub fn otherwise(self, value: V) -> Vec<V> {
+        self.evaluate(&Branch::Scalar(value))
+    }
+
+    /// Values for rows matching no condition, and evaluate the expression.
+    pub fn otherwise_values(self, values: &[V]) -> Vec<V> {
+        self.evaluate(&Branch::Values(values))
+    }
+}
+
+/// Take the value of the first matched mask for each row, or `default` if no
+/// mask matches. Same as chaining `when` for each pair of mask and value.
+///
+/// `branches` must not be empty.
+pub fn select_branches<V: Clone>(branches: &[(&[bool], V)], default: V) -> Vec<V> {
+    assert!(!branches.is_empty(), "branches must not be empty");
+    let mut expr = Then { branches: vec![] };
+    for &(mask, ref value) in branches {
+        expr = expr.when(mask).then(value.clone());
+    }
+    expr.otherwise(default)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{when, select_branches};
+
+    #[test]
+    fn test_when_then_otherwise() {
+        let res = when(&[true, false, true]).then(1).otherwise(0);
+        assert_eq!(res, vec![1, 0, 1]);
+
+        let values = vec![10, 20, 30, 40];
+        let res = when(&[true, true, false, false])
+            .then_values(&values)
+            .when(&[true, false, true, false])
+            .then(0)
+            .otherwise_values(&[-1, -2, -3, -4]);
+        assert_eq!(res, vec![10, 20, 0, -4]);
+    }
+
+    #[test]
+    fn test_select_branches() {
+        let small = vec![true, false, false, true];
+        let medium = vec![true, true, false, false];
+        let res = select_branches(&[(&small, "small"), (&medium, "medium")], "large");
+        assert_eq!(res, vec!["small", "medium", "large", "small"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_when_length_mismatch() {
+        when(&[true, false]).then(1).when(&[true]).then(2).otherwise(0);
+    }
+}
@@ -0,0 +1,350 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use nullvec::prelude::{Array, Scalar, Nullable, NullVec, Slicer};
+
+use error::Error;
+use traits::{AsType, DType};
+
+/// Infer `Array` dtype from `Scalar` values.
+///
+/// Unlike `From<Vec<Scalar>>`, leading or all `Null` values are allowed.
+/// Integers are promoted to `f64` if any float exists, and mixed
+/// incompatible values are stored as `String`.
+pub fn infer_array(values: Vec<Scalar>) -> Array {
+    let mut has_int = false;
+    let mut has_float = false;
+    let mut has_bool = false;
+    let mut has_str = false;
+
+    for value in &values {
+        match *value {
+            Scalar::Null => {}
+            Scalar::f64(_) | Scalar::f32(_) => has_float = true,
+            Scalar::bool(_) => has_bool = true,
+            Scalar::String(_) => has_str = true,
+            _ => has_int = true,
+        }
+    }
+
+    if has_str || (has_bool && (has_int || has_float)) {
+        values
+            .iter()
+            .map(|x| match *x {
+                Scalar::Null => Nullable::Null,
+                Scalar::String(ref s) => Nullable::Value(s.clone()),
+                ref other => Nullable::Value(other.to_string()),
+            })
+            .collect::<NullVec<String>>()
+            .into()
+    } else if has_bool {
+        values
+            .iter()
+            .map(|x| x.as_bool())
+            .collect::<NullVec<bool>>()
+            .into()
+    } else if has_int && !has_float {
+        values
+            .iter()
+            .map(|x| x.as_i64())
+            .collect::<NullVec<i64>>()
+            .into()
+    } else {
+        values
+            .iter()
+            .map(|x| x.as_f64())
+            .collect::<NullVec<f64>>()
+            .into()
+    }
+}
+
+/// Build `Array` of the specified dtype (as returned by `Array::dtype`) from `Scalar` values.
+///
+/// Non-string values are converted to its string representation for "str" dtype.
+pub fn scalars_to_array(values: Vec<Scalar>, dtype: &str) -> Array {
+    match dtype {
+        "i64" => values.iter().map(|x| x.as_i64()).collect::<NullVec<i64>>().into(),
+        "i32" => values.iter().map(|x| x.as_i32()).collect::<NullVec<i32>>().into(),
+        "i16" => values.iter().map(|x| x.as_i16()).collect::<NullVec<i16>>().into(),
+        "i8" => values.iter().map(|x| x.as_i8()).collect::<NullVec<i8>>().into(),
+        "isize" => {
+            values
+                .iter()
+                .map(|x| x.as_isize())
+                .collect::<NullVec<isize>>()
+                .into()
+        }
+        "u64" => values.iter().map(|x| x.as_u64()).collect::<NullVec<u64>>().into(),
+        "u32" => values.iter().map(|x| x.as_u32()).collect::<NullVec<u32>>().into(),
+        "u16" => values.iter().map(|x| x.as_u16()).collect::<NullVec<u16>>().into(),
+        "u8" => values.iter().map(|x| x.as_u8()).collect::<NullVec<u8>>().into(),
+        "usize" => {
+            values
+                .iter()
+                .map(|x| x.as_usize())
+                .collect::<NullVec<usize>>()
+                .into()
+        }
+        "f64" => values.iter().map(|x| x.as_f64()).collect::<NullVec<f64>>().into(),
+        "f32" => values.iter().map(|x| x.as_f32()).collect::<NullVec<f32>>().into(),
+        "bool" => values.iter().map(|x| x.as_bool()).collect::<NullVec<bool>>().into(),
+        "str" => {
+            values
+                .into_iter()
+                .map(|x| match x {
+                    Scalar::Null => Nullable::Null,
+                    Scalar::String(s) => Nullable::Value(s),
+                    other => Nullable::Value(other.to_string()),
+                })
+                .collect::<NullVec<String>>()
+                .into()
+        }
+        _ => panic!("unknown dtype: {}", dtype),
+    }
+}
+
+/// Values of `Array` as `Scalar`s.
+pub fn array_to_scalars(array: &Array) -> Vec<Scalar> {
+    (0..array.len()).map(|i| array.iloc(&i)).collect()
+}
+
+/// Value as an integer if it represents one exactly.
+fn exact_integer(value: &Scalar) -> Option<i128> {
+    match *value {
+        Scalar::i64(v) => Some(i128::from(v)),
+        Scalar::i32(v) => Some(i128::from(v)),
+        Scalar::i16(v) => Some(i128::from(v)),
+        Scalar::i8(v) => Some(i128::from(v)),
+        Scalar::isize(v) => Some(v as i128),
+        Scalar::u64(v) => Some(i128::from(v)),
+        Scalar::u32(v) => Some(i128::from(v)),
+        Scalar::u16(v) => Some(i128::from(v)),
+        Scalar::u8(v) => Some(i128::from(v)),
+        Scalar::usize(v) => Some(v as i128),
+        Scalar::f64(v) => float_to_integer(v),
+        Scalar::f32(v) => float_to_integer(f64::from(v)),
+        Scalar::bool(v) => Some(i128::from(v)),
+        Scalar::String(ref v) => {
+            let v = v.trim();
+            v.parse().ok().or_else(|| v.parse().ok().and_then(float_to_integer))
+        }
+        Scalar::Null => None,
+    }
+}
+
+fn float_to_integer(value: f64) -> Option<i128> {
+    // i128 can hold any float in the range of 64-bit integers
+    if value.fract() == 0. && value.abs() < 1e30 {
+        Some(value as i128)
+    } else {
+        None
+    }
+}
+
+/// Value as a float if it represents one exactly.
+fn exact_float(value: &Scalar) -> Option<f64> {
+    match *value {
+        Scalar::f64(v) => Some(v),
+        Scalar::f32(v) => Some(f64::from(v)),
+        Scalar::bool(v) => Some(if v { 1. } else { 0. }),
+        Scalar::String(ref v) => v.trim().parse().ok(),
+        ref other => exact_integer(other).and_then(|v| {
+            let f = v as f64;
+            if f as i128 == v { Some(f) } else { None }
+        }),
+    }
+}
+
+/// Value as a bool, integers must be 0 or 1 and strings "true" or "false".
+fn exact_bool(value: &Scalar) -> Option<bool> {
+    match *value {
+        Scalar::bool(v) => Some(v),
+        Scalar::String(ref v) => match v.trim().to_lowercase().as_str() {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        },
+        ref other => match exact_integer(other) {
+            Some(0) => Some(false),
+            Some(1) => Some(true),
+            _ => None,
+        },
+    }
+}
+
+macro_rules! cast_integer {
+    ($value:expr, $t:ident) => {
+        exact_integer($value).and_then(|v| $t::try_from(v).ok()).map(Scalar::$t)
+    }
+}
+
+/// Convert `value` to `dtype` (as returned by `Array::dtype`) without loss,
+/// `None` if impossible. Null and NaN result in `Scalar::Null`.
+pub fn cast_scalar(value: &Scalar, dtype: &str) -> Option<Scalar> {
+    match *value {
+        Scalar::Null => return Some(Scalar::Null),
+        Scalar::f64(v) if v.is_nan() => return Some(Scalar::Null),
+        Scalar::f32(v) if v.is_nan() => return Some(Scalar::Null),
+        _ => {}
+    }
+    match dtype {
+        "i64" => cast_integer!(value, i64),
+        "i32" => cast_integer!(value, i32),
+        "i16" => cast_integer!(value, i16),
+        "i8" => cast_integer!(value, i8),
+        "isize" => cast_integer!(value, isize),
+        "u64" => cast_integer!(value, u64),
+        "u32" => cast_integer!(value, u32),
+        "u16" => cast_integer!(value, u16),
+        "u8" => cast_integer!(value, u8),
+        "usize" => cast_integer!(value, usize),
+        "f64" => exact_float(value).map(Scalar::f64),
+        "f32" => match *value {
+            // decimal strings are rarely exact in f64, parse them as f32
+            Scalar::String(ref v) => v.trim().parse::<f32>().ok().map(Scalar::f32),
+            ref other => exact_float(other).and_then(|v| {
+                let f = v as f32;
+                if f64::from(f) == v || v.is_infinite() { Some(Scalar::f32(f)) } else { None }
+            }),
+        },
+        "bool" => exact_bool(value).map(Scalar::bool),
+        "str" => Some(match *value {
+            Scalar::String(ref v) => Scalar::String(v.clone()),
+            ref other => Scalar::String(other.to_string()),
+        }),
+        _ => panic!("unknown dtype: {}", dtype),
+    }
+}
+
+/// Convert `values` to `dtype` by `cast_scalar`, values which can't be
+/// converted are null if `coerce`, otherwise `Error::Cast` is returned.
+pub fn cast_scalars(values: &[Scalar], dtype: &str, coerce: bool) -> Result<Vec<Scalar>, Error> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, x)| match cast_scalar(x, dtype) {
+            Some(v) => Ok(v),
+            None if coerce => Ok(Scalar::Null),
+            None => Err(Error::Cast {
+                location: i,
+                value: x.to_string(),
+                dtype: dtype.to_string(),
+            }),
+        })
+        .collect()
+}
+
+impl AsType for Array {
+    fn try_astype(&self, dtype: DType, coerce: bool) -> Result<Self, Error> {
+        let values = cast_scalars(&array_to_scalars(self), dtype.name(), coerce)?;
+        Ok(scalars_to_array(values, dtype.name()))
+    }
+}
+
+/// Kind and bit width of numeric dtype.
+fn numeric_kind(dtype: &str) -> Option<(char, u32)> {
+    match dtype {
+        "i8" => Some(('i', 8)),
+        "i16" => Some(('i', 16)),
+        "i32" => Some(('i', 32)),
+        "i64" | "isize" => Some(('i', 64)),
+        "u8" => Some(('u', 8)),
+        "u16" => Some(('u', 16)),
+        "u32" => Some(('u', 32)),
+        "u64" | "usize" => Some(('u', 64)),
+        "f32" => Some(('f', 32)),
+        "f64" => Some(('f', 64)),
+        _ => None,
+    }
+}
+
+/// Common dtype which values of both dtypes are promoted to, or `None` if
+/// they are incompatible.
+///
+/// - Integers of the same signedness are promoted to the wider one.
+/// - Signed and unsigned integers are promoted to `i64`, unless the unsigned
+///   one is `u64` or `usize` whose values may not fit in `i64`.
+/// - Floats and integers are promoted to `f64`.
+/// - Other dtypes are only compatible with themselves.
+pub fn promote_dtype(left: &str, right: &str) -> Option<String> {
+    if left == right {
+        return Some(left.to_string());
+    }
+    let promoted = match (numeric_kind(left)?, numeric_kind(right)?) {
+        (('i', lw), ('i', rw)) => if lw >= rw { ('i', lw) } else { ('i', rw) },
+        (('u', lw), ('u', rw)) => if lw >= rw { ('u', lw) } else { ('u', rw) },
+        (('f', _), _) | (_, ('f', _)) => ('f', 64),
+        (('u', 64), _) | (_, ('u', 64)) => return None,
+        _ => ('i', 64),
+    };
+    // keep the original name if it is the result, e.g. "isize"
+    Some(match promoted {
+        (k, w) if numeric_kind(left) == Some((k, w)) => left.to_string(),
+        (k, w) if numeric_kind(right) == Some((k, w)) => right.to_string(),
+        (k, w) => format!("{}{}", k, w),
+    })
+}
+
+/// Length of `duration` in nanoseconds, the unit of datetime values.
+pub fn duration_nanos(duration: Duration) -> i64 {
+    duration.as_secs() as i64 * 1_000_000_000 + i64::from(duration.subsec_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, Scalar, Nullable};
+
+    #[test]
+    fn test_infer_array() {
+        use nullvec::prelude::{NullVec, Slicer};
+        use super::infer_array;
+
+        let res = infer_array(vec![Scalar::i64(1), Scalar::Null, Scalar::i64(3)]);
+        let exp: Array = NullVec::with_mask(vec![1i64, 0, 3], Some(vec![false, true, false]))
+            .into();
+        assert_eq!(res, exp);
+
+        let res = infer_array(vec![Scalar::Null, Scalar::i64(1), Scalar::f64(2.5)]);
+        let exp: Vec<Nullable<f64>> = vec![Nullable::Null, Nullable::Value(1.), Nullable::Value(2.5)];
+        assert_eq!(res, Array::Float64Array(exp.into()));
+
+        let res = infer_array(vec![Scalar::bool(true), Scalar::i64(1)]);
+        assert_eq!(res, Array::new(vec!["true".to_string(), "1".to_string()]));
+
+        let res = infer_array(vec![Scalar::Null, Scalar::Null]);
+        assert_eq!(res.dtype(), "f64");
+        assert_eq!(res.len(), 2);
+    }
+
+    #[test]
+    fn test_scalars_to_array() {
+        use super::scalars_to_array;
+
+        let res = scalars_to_array(vec![Scalar::Null, Scalar::i64(1)], "i8");
+        let exp: Vec<Nullable<i8>> = vec![Nullable::Null, Nullable::Value(1)];
+        assert_eq!(res, Array::Int8Array(exp.into()));
+
+        let res = scalars_to_array(vec![Scalar::i64(1), Scalar::String("a".to_string())], "str");
+        assert_eq!(res, Array::new(vec!["1".to_string(), "a".to_string()]));
+    }
+
+    #[test]
+    fn test_promote_dtype() {
+        use super::promote_dtype;
+
+        assert_eq!(promote_dtype("i32", "i32"), Some("i32".to_string()));
+        assert_eq!(promote_dtype("i8", "i32"), Some("i32".to_string()));
+        assert_eq!(promote_dtype("u64", "u16"), Some("u64".to_string()));
+        assert_eq!(promote_dtype("isize", "i32"), Some("isize".to_string()));
+        assert_eq!(promote_dtype("u8", "i8"), Some("i64".to_string()));
+        assert_eq!(promote_dtype("u32", "i64"), Some("i64".to_string()));
+        assert_eq!(promote_dtype("u64", "i64"), None);
+        assert_eq!(promote_dtype("i8", "usize"), None);
+        assert_eq!(promote_dtype("u64", "f32"), Some("f64".to_string()));
+        assert_eq!(promote_dtype("i64", "f32"), Some("f64".to_string()));
+        assert_eq!(promote_dtype("f32", "f64"), Some("f64".to_string()));
+        assert_eq!(promote_dtype("str", "i64"), None);
+        assert_eq!(promote_dtype("bool", "u8"), None);
+    }
+}
@@ -0,0 +1,212 @@
+//! Datetime helpers, datetime values are stored as `i64` nanoseconds since
+//! the UNIX epoch in UTC.
+
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone};
+
+use convert::duration_nanos;
+use indexer::Indexer;
+
+/// Values which can be interpreted as datetime in UTC.
+pub trait DatetimeLike {
+    fn to_naive_utc(&self) -> NaiveDateTime;
+
+    /// Wall clock time in the timezone of the value, which is UTC for values
+    /// without timezone.
+    fn to_naive_local(&self) -> NaiveDateTime {
+        self.to_naive_utc()
+    }
+
+    /// Format wall clock time using chrono `format`.
+    fn format_local(&self, format: &str) -> String {
+        self.to_naive_local().format(format).to_string()
+    }
+
+    /// Nanoseconds since the UNIX epoch, panics if out of the range of `i64`.
+    fn to_nanos(&self) -> i64 {
+        self.to_naive_utc()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .expect("datetime out of range of nanoseconds")
+    }
+}
+
+/// `i64` is regarded as nanoseconds since the UNIX epoch.
+impl DatetimeLike for i64 {
+    fn to_naive_utc(&self) -> NaiveDateTime {
+        DateTime::from_timestamp_nanos(*self).naive_utc()
+    }
+
+    fn to_nanos(&self) -> i64 {
+        *self
+    }
+}
+
+impl DatetimeLike for NaiveDateTime {
+    fn to_naive_utc(&self) -> NaiveDateTime {
+        *self
+    }
+}
+
+/// Timezone-aware datetime created by `tz_localize` or `tz_convert`, whose
+/// periods and formatting follow its wall clock time.
+impl<Z> DatetimeLike for DateTime<Z>
+where
+    Z: TimeZone,
+    Z::Offset: fmt::Display,
+{
+    fn to_naive_utc(&self) -> NaiveDateTime {
+        self.naive_utc()
+    }
+
+    fn to_naive_local(&self) -> NaiveDateTime {
+        self.naive_local()
+    }
+
+    fn format_local(&self, format: &str) -> String {
+        self.format(format).to_string()
+    }
+}
+
+/// Parse string using chrono `format`.
+///
+/// If `format` doesn't contain offset, the value is regarded as local time
+/// of `tz`. Date only formats are parsed as midnight.
+pub fn parse_datetime(value: &str, format: &str, tz: &FixedOffset) -> Option<i64> {
+    if let Ok(dt) = DateTime::parse_from_str(value, format) {
+        return dt.timestamp_nanos_opt();
+    }
+    let naive = match NaiveDateTime::parse_from_str(value, format) {
+        Ok(naive) => naive,
+        Err(_) => NaiveDate::parse_from_str(value, format).ok()?.and_hms_opt(0, 0, 0)?,
+    };
+    tz.from_local_datetime(&naive).single()?.timestamp_nanos_opt()
+}
+
+/// Datetime index from `start` to `end` (inclusive) by `freq`, labels are
+/// nanoseconds since the UNIX epoch.
+pub fn date_range<T: DatetimeLike>(start: &T, end: &T, freq: Duration) -> Indexer<i64> {
+    let step = duration_nanos(freq);
+    assert!(step > 0, "freq must be positive");
+    let (start, end) = (start.to_nanos(), end.to_nanos());
+    let mut values: Vec<i64> = vec![];
+    let mut current = start;
+    while current <= end {
+        values.push(current);
+        current = match current.checked_add(step) {
+            Some(v) => v,
+            None => break,
+        };
+    }
+    Indexer::new(values)
+}
+
+/// Range of nanoseconds covered by partial timestamp `value`, as (start, end)
+/// where end is exclusive.
+///
+/// `value` is "%Y-%m-%d %H:%M:%S" truncated after any component, such as
+/// "2018" or "2018-03-02 09". "T" can be used instead of the space. `None`
+/// if `value` is invalid.
+pub fn partial_datetime_range(value: &str) -> Option<(i64, i64)> {
+    let mut parts = value.trim().splitn(2, [' ', 'T']);
+    let date: Vec<&str> = parts.next()?.split('-').collect();
+    let time: Vec<&str> = match parts.next() {
+        Some(time) if date.len() == 3 => time.split(':').collect(),
+        Some(_) => return None,
+        None => vec![],
+    };
+    if date.len() > 3 || time.len() > 3 {
+        return None;
+    }
+    let mut components: Vec<u32> = vec![];
+    for x in date.iter().chain(time.iter()) {
+        if x.is_empty() || !x.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        components.push(x.parse().ok()?);
+    }
+    let get = |i: usize, default: u32| components.get(i).cloned().unwrap_or(default);
+
+    let year = components[0] as i32;
+    let start = NaiveDate::from_ymd_opt(year, get(1, 1), get(2, 1))?
+        .and_hms_opt(get(3, 0), get(4, 0), get(5, 0))?;
+    let end = match components.len() {
+        1 => NaiveDate::from_ymd_opt(year + 1, 1, 1)?.and_hms_opt(0, 0, 0)?,
+        2 => {
+            let (y, m) = if get(1, 1) == 12 { (year + 1, 1) } else { (year, get(1, 1) + 1) };
+            NaiveDate::from_ymd_opt(y, m, 1)?.and_hms_opt(0, 0, 0)?
+        }
+        n => {
+            let seconds = [86_400, 3_600, 60, 1][n - 3];
+            start + chrono::Duration::seconds(seconds)
+        }
+    };
+    Some((start.to_nanos(), end.to_nanos()))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::time::Duration;
+
+    use chrono::FixedOffset;
+
+    use super::{parse_datetime, date_range, partial_datetime_range};
+
+    #[test]
+    fn test_parse_datetime() {
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let jst = FixedOffset::east_opt(9 * 3600).unwrap();
+
+        let res = parse_datetime("2018-01-02 03:04:05", "%Y-%m-%d %H:%M:%S", &utc);
+        assert_eq!(res, Some(1_514_862_245_000_000_000));
+        let res = parse_datetime("2018-01-02 12:04:05", "%Y-%m-%d %H:%M:%S", &jst);
+        assert_eq!(res, Some(1_514_862_245_000_000_000));
+        // offset in value has priority
+        let res = parse_datetime("2018-01-02 03:04:05 +0000", "%Y-%m-%d %H:%M:%S %z", &jst);
+        assert_eq!(res, Some(1_514_862_245_000_000_000));
+
+        let res = parse_datetime("02/01/2018", "%d/%m/%Y", &utc);
+        assert_eq!(res, Some(1_514_851_200_000_000_000));
+        assert_eq!(parse_datetime("2018-13-01", "%Y-%m-%d", &utc), None);
+    }
+
+    #[test]
+    fn test_date_range() {
+        // 2018-01-02 00:00 UTC
+        let base: i64 = 1_514_851_200_000_000_000;
+        let hour: i64 = 3_600_000_000_000;
+        let res = date_range(&base, &(base + 3 * hour), Duration::from_secs(3600));
+        assert_eq!(res.values, vec![base, base + hour, base + 2 * hour, base + 3 * hour]);
+        let res = date_range(&base, &(base + 3 * hour), Duration::from_secs(2 * 3600));
+        assert_eq!(res.values, vec![base, base + 2 * hour]);
+        let res = date_range(&base, &(base - 1), Duration::from_secs(1));
+        assert_eq!(res.values, Vec::<i64>::new());
+    }
+
+    #[test]
+    fn test_partial_datetime_range() {
+        let day: i64 = 86_400_000_000_000;
+        // 2018-01-01, 2018-02-01, 2019-01-01 00:00 UTC
+        let jan: i64 = 1_514_764_800_000_000_000;
+        let feb: i64 = 1_517_443_200_000_000_000;
+        let next: i64 = 1_546_300_800_000_000_000;
+        assert_eq!(partial_datetime_range("2018"), Some((jan, next)));
+        assert_eq!(partial_datetime_range("2018-01"), Some((jan, feb)));
+        assert_eq!(partial_datetime_range("2018-12"), Some((next - 31 * day, next)));
+        assert_eq!(partial_datetime_range("2018-01-02"), Some((jan + day, jan + 2 * day)));
+        let hour = day / 24;
+        let res = partial_datetime_range("2018-01-02 03");
+        assert_eq!(res, Some((jan + day + 3 * hour, jan + day + 4 * hour)));
+        let res = partial_datetime_range("2018-01-02T03:04:05");
+        let start = jan + day + 3 * hour + 4 * 60_000_000_000 + 5_000_000_000;
+        assert_eq!(res, Some((start, start + 1_000_000_000)));
+
+        assert_eq!(partial_datetime_range("2018-13"), None);
+        assert_eq!(partial_datetime_range("2018-01 03"), None);
+        assert_eq!(partial_datetime_range("March"), None);
+        assert_eq!(partial_datetime_range(""), None);
+    }
+}
@@ -0,0 +1,427 @@
+//! Stateful transforms of `DataFrame` columns.
+//!
+//! Each encoder is created by `fit` on training data, records the state
+//! needed to transform (categories, statistics or bin edges) and applies the
+//! same transform to other frames by `transform`. Encoders can be persisted
+//! with the `serde` feature. Columns not fitted are kept as they are.
+
+use std::collections::{BTreeSet, HashMap};
+use std::f64;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Nullable, NullVec, Scalar};
+
+use convert::array_to_scalars;
+use frame::DataFrame;
+use series::Series;
+use traits::IndexerIndex;
+
+/// String representation of each value of `array`, `None` for nulls.
+fn category_values(array: &Array) -> Vec<Option<String>> {
+    array_to_scalars(array)
+        .into_iter()
+        .map(|x| match x {
+            Scalar::Null => None,
+            Scalar::String(s) => Some(s),
+            other => Some(other.to_string()),
+        })
+        .collect()
+}
+
+/// Values of numeric `array` as `f64`, NaN for nulls.
+fn float_values(array: &Array) -> Vec<f64> {
+    array_to_scalars(array)
+        .iter()
+        .map(|x| match x.as_f64() {
+            Nullable::Value(v) => v,
+            Nullable::Null => f64::NAN,
+        })
+        .collect()
+}
+
+/// Sorted distinct non-null categories of each column.
+fn fit_categories<I, C>(df: &DataFrame<I, C>, columns: &[C]) -> Vec<(C, Vec<String>)>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    columns
+        .iter()
+        .map(|c| {
+            let values = category_values(&df.values[df.columns.get_loc(c)]);
+            let categories: BTreeSet<String> = values.into_iter().flatten().collect();
+            (c.clone(), categories.into_iter().collect())
+        })
+        .collect()
+}
+
+/// Replace fitted columns of `df` by `func` applied to their arrays.
+fn replace_columns<'n, I, C, S, F>(
+    df: &DataFrame<I, C>,
+    states: &[(C, S)],
+    func: F,
+) -> DataFrame<'n, 'n, 'n, I, C>
+where
+    I: 'n + Clone + Eq + Hash,
+    C: 'n + Clone + Eq + Hash,
+    F: Fn(&Array, &S) -> Array,
+{
+    let mut new_values: Vec<Array> = df.values.iter().map(|x| (**x).clone()).collect();
+    for (column, state) in states {
+        let loc = df.columns.get_loc(column);
+        new_values[loc] = func(&df.values[loc], state);
+    }
+    DataFrame::from_vec(
+        new_values,
+        df.index.clone().into_owned(),
+        df.columns.clone().into_owned(),
+    )
+}
+
+/// Encode categories as integer codes in sorted order.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LabelEncoder<C> {
+    /// Categories of each column, the code is the location in the list
+    pub categories: Vec<(C, Vec<String>)>,
+}
+
+impl<C> LabelEncoder<C>
+where
+    C: Clone + Eq + Hash,
+{
+    /// Record distinct values of `columns`, compared as strings.
+    pub fn fit<I>(df: &DataFrame<I, C>, columns: &[C]) -> Self
+    where
+        I: Clone + Eq + Hash,
+    {
+        LabelEncoder { categories: fit_categories(df, columns) }
+    }
+
+    /// Replace values of fitted columns with `i64` codes, nulls and
+    /// categories unseen in `fit` become null.
+    pub fn transform<'n, I>(&self, df: &DataFrame<I, C>) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        I: 'n + Clone + Eq + Hash,
+        C: 'n,
+    {
+        replace_columns(df, &self.categories, |array, categories| {
+            let codes: HashMap<&String, i64> =
+                categories.iter().enumerate().map(|(i, x)| (x, i as i64)).collect();
+            category_values(array)
+                .iter()
+                .map(|x| match x.as_ref().and_then(|x| codes.get(x)) {
+                    Some(&code) => Nullable::Value(code),
+                    None => Nullable::Null,
+                })
+                .collect::<NullVec<i64>>()
+                .into()
+        })
+    }
+}
+
+/// Encode categories as indicator columns.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OneHotEncoder<C> {
+    /// Categories of each column, in the order of indicator columns
+    pub categories: Vec<(C, Vec<String>)>,
+}
+
+impl<C> OneHotEncoder<C>
+where
+    C: Clone + Eq + Hash + ToString,
+{
+    /// Record distinct values of `columns`, compared as strings.
+    pub fn fit<I>(df: &DataFrame<I, C>, columns: &[C]) -> Self
+    where
+        I: Clone + Eq + Hash,
+    {
+        OneHotEncoder { categories: fit_categories(df, columns) }
+    }
+
+    /// Replace each fitted column with `bool` columns named
+    /// "{column}_{category}" for its categories, other columns are renamed to
+    /// their string representation.
+    ///
+    /// Nulls and categories unseen in `fit` are false in all indicators.
+    pub fn transform<'n, I>(&self, df: &DataFrame<I, C>) -> DataFrame<'n, 'n, 'n, I, String>
+    where
+        I: 'n + Clone + Eq + Hash,
+    {
+        let fitted: HashMap<&C, &Vec<String>> =
+            self.categories.iter().map(|(c, x)| (c, x)).collect();
+        let mut new_values: Vec<Array> = vec![];
+        let mut new_columns: Vec<String> = vec![];
+        for (column, array) in df.columns.values.iter().zip(&df.values) {
+            match fitted.get(column) {
+                Some(categories) => {
+                    let values = category_values(array);
+                    for category in categories.iter() {
+                        let flags: Vec<bool> = values
+                            .iter()
+                            .map(|x| x.as_ref() == Some(category))
+                            .collect();
+                        new_values.push(Array::new(flags));
+                        new_columns.push(format!("{}_{}", column.to_string(), category));
+                    }
+                }
+                None => {
+                    new_values.push((**array).clone());
+                    new_columns.push(column.to_string());
+                }
+            }
+        }
+        DataFrame::from_vec(new_values, df.index.clone().into_owned(), new_columns)
+    }
+}
+
+/// Standardize numeric columns to zero mean and unit variance.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StandardScaler<C> {
+    /// Mean and population standard deviation of each column
+    pub stats: Vec<(C, (f64, f64))>,
+}
+
+impl<C> StandardScaler<C>
+where
+    C: Clone + Eq + Hash,
+{
+    /// Record mean and standard deviation of `columns` ignoring nulls. Mean
+    /// and standard deviation of columns without values are 0.
+    pub fn fit<I>(df: &DataFrame<I, C>, columns: &[C]) -> Self
+    where
+        I: Clone + Eq + Hash,
+    {
+        let stats = columns
+            .iter()
+            .map(|c| {
+                let values = float_values(&df.values[df.columns.get_loc(c)]);
+                let valid: Vec<f64> = values.into_iter().filter(|x| !x.is_nan()).collect();
+                if valid.is_empty() {
+                    return (c.clone(), (0., 0.));
+                }
+                let n = valid.len() as f64;
+                let mean = valid.iter().sum::<f64>() / n;
+                let var = valid.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+                (c.clone(), (mean, var.sqrt()))
+            })
+            .collect();
+        StandardScaler { stats }
+    }
+
+    /// Replace values of fitted columns with `(x - mean) / std` as `f64`,
+    /// columns without variance are only centered. Nulls are kept.
+    pub fn transform<'n, I>(&self, df: &DataFrame<I, C>) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        I: 'n + Clone + Eq + Hash,
+        C: 'n,
+    {
+        replace_columns(df, &self.stats, |array, &(mean, std)| {
+            let scale = if std > 0. { std } else { 1. };
+            array_to_scalars(array)
+                .iter()
+                .map(|x| match x.as_f64() {
+                    Nullable::Value(v) => Nullable::Value((v - mean) / scale),
+                    Nullable::Null => Nullable::Null,
+                })
+                .collect::<NullVec<f64>>()
+                .into()
+        })
+    }
+}
+
+/// Discretize numeric columns into quantile bins.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuantileBinner<C> {
+    /// Increasing bin edges of each column
+    pub edges: Vec<(C, Vec<f64>)>,
+}
+
+impl<C> QuantileBinner<C>
+where
+    C: Clone + Eq + Hash,
+{
+    /// Record edges of `q` bins containing the same number of values, as
+    /// `Series::qcut`.
+    pub fn fit<I>(df: &DataFrame<I, C>, columns: &[C], q: usize) -> Self
+    where
+        I: Clone + Eq + Hash,
+    {
+        let edges = columns
+            .iter()
+            .map(|c| {
+                let values = float_values(&df.values[df.columns.get_loc(c)]);
+                let (_, edges) = Series::<f64, usize>::from_vec(values).qcut(q);
+                (c.clone(), edges)
+            })
+            .collect();
+        QuantileBinner { edges }
+    }
+
+    /// Replace values of fitted columns with `i64` bin numbers starting from
+    /// 0. Bins are right-closed and the first bin contains its left edge,
+    /// values out of the edges and nulls become null.
+    pub fn transform<'n, I>(&self, df: &DataFrame<I, C>) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        I: 'n + Clone + Eq + Hash,
+        C: 'n,
+    {
+        replace_columns(df, &self.edges, |array, edges| {
+            float_values(array)
+                .iter()
+                .map(|&x| {
+                    if edges.len() < 2 || x.is_nan() || x < edges[0] ||
+                        x > edges[edges.len() - 1]
+                    {
+                        Nullable::Null
+                    } else {
+                        let n = edges.partition_point(|&e| e < x);
+                        Nullable::Value(n.max(1) as i64 - 1)
+                    }
+                })
+                .collect::<NullVec<i64>>()
+                .into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec};
+
+    use frame::DataFrame;
+    use super::{LabelEncoder, OneHotEncoder, StandardScaler, QuantileBinner};
+
+    fn train() -> DataFrame<'static, 'static, 'static, i64, &'static str> {
+        DataFrame::from_vec(
+            vec![
+                Array::new(vec!["b", "a", "b", "c"]),
+                Array::new(vec![1., 2., 3., 4.]),
+            ],
+            vec![1, 2, 3, 4],
+            vec!["X", "Y"],
+        )
+    }
+
+    fn test() -> DataFrame<'static, 'static, 'static, i64, &'static str> {
+        DataFrame::from_vec(
+            vec![Array::new(vec!["c", "d"]), Array::new(vec![0., 2.5])],
+            vec![5, 6],
+            vec!["X", "Y"],
+        )
+    }
+
+    #[test]
+    fn test_label_encoder() {
+        let encoder = LabelEncoder::fit(&train(), &["X"]);
+        let categories: Vec<String> = vec!["a", "b", "c"].iter().map(|x| x.to_string()).collect();
+        assert_eq!(encoder.categories, vec![("X", categories)]);
+        let exp = DataFrame::from_vec(
+            vec![Array::new(vec![1i64, 0, 1, 2]), Array::new(vec![1., 2., 3., 4.])],
+            vec![1, 2, 3, 4],
+            vec!["X", "Y"],
+        );
+        assert_eq!(encoder.transform(&train()), exp);
+        let exp = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![2i64, 0], Some(vec![false, true])).into(),
+                Array::new(vec![0., 2.5]),
+            ],
+            vec![5, 6],
+            vec!["X", "Y"],
+        );
+        assert_eq!(encoder.transform(&test()), exp);
+    }
+
+    #[test]
+    fn test_one_hot_encoder() {
+        let encoder = OneHotEncoder::fit(&train(), &["X"]);
+        let res = encoder.transform(&test());
+        let exp: DataFrame<i64, String> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![false, false]),
+                Array::new(vec![false, false]),
+                Array::new(vec![true, false]),
+                Array::new(vec![0., 2.5]),
+            ],
+            vec![5, 6],
+            vec!["X_a", "X_b", "X_c", "Y"].into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+        );
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_standard_scaler() {
+        let scaler = StandardScaler::fit(&train(), &["Y"]);
+        let (mean, std) = scaler.stats[0].1;
+        assert_eq!(mean, 2.5);
+        assert_eq!(std, 1.25f64.sqrt());
+        let res = scaler.transform(&test());
+        let exp = DataFrame::from_vec(
+            vec![Array::new(vec!["c", "d"]), Array::new(vec![-2.5 / std, 0.])],
+            vec![5, 6],
+            vec!["X", "Y"],
+        );
+        assert_eq!(res, exp);
+
+        let y: Array = NullVec::with_mask(vec![0., 4.], Some(vec![true, false])).into();
+        let df = DataFrame::from_vec(vec![y], vec![1, 2], vec!["Y"]);
+        let exp = DataFrame::from_vec(
+            vec![NullVec::with_mask(vec![0., 1.5 / std], Some(vec![true, false])).into()],
+            vec![1, 2],
+            vec!["Y"],
+        );
+        assert_eq!(scaler.transform(&df), exp);
+
+        // nothing to fit, values are kept
+        let y: Array = NullVec::with_mask(vec![0., 0.], Some(vec![true, true])).into();
+        let nulls = DataFrame::from_vec(vec![y], vec![1, 2], vec!["Y"]);
+        let scaler = StandardScaler::fit(&nulls, &["Y"]);
+        assert_eq!(scaler.stats, vec![("Y", (0., 0.))]);
+        assert_eq!(scaler.transform(&df), df);
+    }
+
+    #[test]
+    fn test_quantile_binner() {
+        let binner = QuantileBinner::fit(&train(), &["Y"], 2);
+        assert_eq!(binner.edges, vec![("Y", vec![1., 2.5, 4.])]);
+        let exp = DataFrame::from_vec(
+            vec![Array::new(vec!["b", "a", "b", "c"]), Array::new(vec![0i64, 0, 1, 1])],
+            vec![1, 2, 3, 4],
+            vec!["X", "Y"],
+        );
+        assert_eq!(binner.transform(&train()), exp);
+        let exp = DataFrame::from_vec(
+            vec![
+                Array::new(vec!["c", "d"]),
+                NullVec::with_mask(vec![0i64, 0], Some(vec![true, false])).into(),
+            ],
+            vec![5, 6],
+            vec!["X", "Y"],
+        );
+        assert_eq!(binner.transform(&test()), exp);
+    }
+
+    #[cfg(all(feature = "serde", feature = "json"))]
+    #[test]
+    fn test_encoder_serde() {
+        use serde_json;
+
+        let encoder: LabelEncoder<String> = LabelEncoder {
+            categories: vec![("X".to_string(), vec!["a".to_string(), "b".to_string()])],
+        };
+        let json = serde_json::to_string(&encoder).unwrap();
+        assert_eq!(json, r#"{"categories":[["X",["a","b"]]]}"#);
+        let res: LabelEncoder<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(res, encoder);
+
+        let binner = QuantileBinner { edges: vec![("Y".to_string(), vec![1., 2.5])] };
+        let json = serde_json::to_string(&binner).unwrap();
+        let res: QuantileBinner<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(res, binner);
+    }
+}
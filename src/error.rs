@@ -0,0 +1,85 @@
+use std::error;
+use std::fmt;
+
+/// Error returned by `try_*` methods, corresponding methods without `try_` panic instead.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// Lengths of values and index (or columns) are different
+    LengthMismatch { expected: usize, actual: usize },
+    /// Index of binary operation operands are different
+    IndexMismatch,
+    /// Columns of appended `DataFrame` are different
+    ColumnsMismatch,
+    /// Label is not found, holds `Debug` representation of the label
+    KeyNotFound(String),
+    /// Location is out of bounds
+    OutOfBounds { location: usize, len: usize },
+    /// Label is duplicated, holds `Debug` representation of the label
+    DuplicateLabel(String),
+    /// Column is not found, holds `Debug` representation of the label and
+    /// the most similar column if exists
+    ColumnNotFound { label: String, suggestion: Option<String> },
+    /// Label can't be parsed as the specified type, holds `Debug`
+    /// representation of the label
+    ParseLabel(String),
+    /// Dtypes of a column differ, holds `Debug` representation of the column
+    /// and both dtypes
+    DtypeMismatch { column: String, left: String, right: String },
+    /// Integer operation overflowed at the location
+    Overflow { location: usize },
+    /// Value at the location can't be converted to the dtype without loss
+    Cast { location: usize, value: String, dtype: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::LengthMismatch { expected, actual } => {
+                write!(f, "Length mismatch! expected {}, actual {}", expected, actual)
+            }
+            Error::IndexMismatch => write!(f, "index must be the same!"),
+            Error::ColumnsMismatch => write!(f, "columns must be identical"),
+            Error::KeyNotFound(ref label) => write!(f, "label not found: {}", label),
+            Error::OutOfBounds { location, len } => {
+                write!(f, "location {} is out of bounds for length {}", location, len)
+            }
+            Error::DuplicateLabel(ref label) => write!(f, "duplicated label: {}", label),
+            Error::ColumnNotFound {
+                ref label,
+                suggestion: Some(ref suggestion),
+            } => write!(f, "column not found: {}, did you mean {}?", label, suggestion),
+            Error::ColumnNotFound { ref label, .. } => write!(f, "column not found: {}", label),
+            Error::ParseLabel(ref label) => write!(f, "label can't be parsed: {}", label),
+            Error::DtypeMismatch {
+                ref column,
+                ref left,
+                ref right,
+            } => write!(f, "dtype mismatch in column {}: {} and {}", column, left, right),
+            Error::Overflow { location } => write!(f, "integer overflow at location {}", location),
+            Error::Cast {
+                location,
+                ref value,
+                ref dtype,
+            } => write!(f, "{} at location {} can't be cast to {}", value, location, dtype),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::Error;
+
+    #[test]
+    fn test_display() {
+        let e = Error::LengthMismatch {
+            expected: 3,
+            actual: 2,
+        };
+        assert_eq!(e.to_string(), "Length mismatch! expected 3, actual 2");
+        let e = Error::KeyNotFound("\"A\"".to_string());
+        assert_eq!(e.to_string(), "label not found: \"A\"");
+    }
+}
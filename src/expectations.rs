@@ -0,0 +1,266 @@
+//! Data expectations, `expect(&df).expect_unique(&"id").expect_no_nulls(&"x").validate()`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Nullable, Scalar};
+
+use algos::key::ScalarKey;
+use convert::array_to_scalars;
+use frame::DataFrame;
+use traits::RowIndex;
+
+/// Outcome of a single expectation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpectationResult {
+    /// Expectation with its arguments, such as `expect_unique("id")`
+    pub expectation: String,
+    /// Whether the expectation is met
+    pub success: bool,
+    /// Locations of rows which don't meet the expectation
+    pub unexpected: Vec<usize>,
+    /// Reason of the failure which is not specific to rows
+    pub message: Option<String>,
+}
+
+impl ExpectationResult {
+    fn from_unexpected(expectation: String, unexpected: Vec<usize>) -> Self {
+        ExpectationResult {
+            expectation,
+            success: unexpected.is_empty(),
+            unexpected,
+            message: None,
+        }
+    }
+
+    fn from_message(expectation: String, message: Option<String>) -> Self {
+        ExpectationResult {
+            expectation,
+            success: message.is_none(),
+            unexpected: vec![],
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ExpectationResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.success {
+            return write!(f, "[PASS] {}", self.expectation);
+        }
+        match self.message {
+            Some(ref message) => write!(f, "[FAIL] {}: {}", self.expectation, message),
+            None => {
+                write!(
+                    f,
+                    "[FAIL] {}: {} unexpected rows at {:?}",
+                    self.expectation,
+                    self.unexpected.len(),
+                    self.unexpected
+                )
+            }
+        }
+    }
+}
+
+/// Results of all expectations, created by `Expectations::validate`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Validation {
+    /// Results in the order of expectations
+    pub results: Vec<ExpectationResult>,
+}
+
+impl Validation {
+    /// Whether all expectations are met.
+    pub fn success(&self) -> bool {
+        self.results.iter().all(|x| x.success)
+    }
+
+    /// Results of expectations which are not met.
+    pub fn failures(&self) -> Vec<&ExpectationResult> {
+        self.results.iter().filter(|x| !x.success).collect()
+    }
+}
+
+impl fmt::Display for Validation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let lines: Vec<String> = self.results.iter().map(|x| x.to_string()).collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// Start expectations on `df`.
+///
+/// Each expectation is evaluated immediately, and its result is collected
+/// into `Validation` by `validate`. Missing columns fail the expectation
+/// instead of panicking.
+pub fn expect<'a, 'v, 'i, 'c, I, C>(
+    df: &'a DataFrame<'v, 'i, 'c, I, C>,
+) -> Expectations<'a, 'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + fmt::Debug,
+{
+    Expectations {
+        df,
+        results: vec![],
+    }
+}
+
+/// Expectations on a `DataFrame`, created by `expect`.
+pub struct Expectations<'a, 'v: 'a, 'i: 'a, 'c: 'a, I: 'a, C: 'a>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    df: &'a DataFrame<'v, 'i, 'c, I, C>,
+    results: Vec<ExpectationResult>,
+}
+
+impl<'a, 'v, 'i, 'c, I, C> Expectations<'a, 'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + fmt::Debug,
+{
+    /// Evaluate `func` on values of `column`, the expectation fails if the
+    /// column doesn't exist.
+    fn expect_column<F>(mut self, expectation: String, column: &C, func: F) -> Self
+    where
+        F: Fn(&Array) -> Result<Vec<usize>, String>,
+    {
+        let result = match self.df.try_get(column) {
+            Ok(values) => {
+                match func(&values) {
+                    Ok(unexpected) => ExpectationResult::from_unexpected(expectation, unexpected),
+                    Err(message) => ExpectationResult::from_message(expectation, Some(message)),
+                }
+            }
+            Err(e) => ExpectationResult::from_message(expectation, Some(e.to_string())),
+        };
+        self.results.push(result);
+        self
+    }
+
+    /// Expect non-null values of numeric `column` to be between `min` and
+    /// `max`, inclusive.
+    pub fn expect_column_values_between(self, column: &C, min: f64, max: f64) -> Self {
+        let expectation = format!("expect_column_values_between({:?}, {}, {})", column, min, max);
+        self.expect_column(expectation, column, |values| {
+            if !values.is_numeric() {
+                return Err(format!("dtype {} is not numeric", values.dtype()));
+            }
+            let unexpected = array_to_scalars(values)
+                .iter()
+                .enumerate()
+                .filter(|&(_, x)| match x.as_f64() {
+                    Nullable::Value(v) => v < min || v > max,
+                    Nullable::Null => false,
+                })
+                .map(|(i, _)| i)
+                .collect();
+            Ok(unexpected)
+        })
+    }
+
+    /// Expect non-null values of `column` to be unique. All rows sharing a
+    /// value are unexpected.
+    pub fn expect_unique(self, column: &C) -> Self {
+        let expectation = format!("expect_unique({:?})", column);
+        self.expect_column(expectation, column, |values| {
+            let keys: Vec<ScalarKey> =
+                array_to_scalars(values).iter().map(ScalarKey::from).collect();
+            let mut counts: HashMap<&ScalarKey, usize> = HashMap::new();
+            for key in &keys {
+                *counts.entry(key).or_insert(0) += 1;
+            }
+            let unexpected = keys.iter()
+                .enumerate()
+                .filter(|&(_, k)| *k != ScalarKey::Null && counts[k] > 1)
+                .map(|(i, _)| i)
+                .collect();
+            Ok(unexpected)
+        })
+    }
+
+    /// Expect `column` to have no nulls.
+    pub fn expect_no_nulls(self, column: &C) -> Self {
+        let expectation = format!("expect_no_nulls({:?})", column);
+        self.expect_column(expectation, column, |values| {
+            let unexpected = array_to_scalars(values)
+                .iter()
+                .enumerate()
+                .filter(|&(_, x)| *x == Scalar::Null)
+                .map(|(i, _)| i)
+                .collect();
+            Ok(unexpected)
+        })
+    }
+
+    /// Expect the number of rows to be between `min` and `max`, inclusive.
+    pub fn expect_row_count_between(mut self, min: usize, max: usize) -> Self {
+        let expectation = format!("expect_row_count_between({}, {})", min, max);
+        let len = self.df.len();
+        let message = if len < min || len > max {
+            Some(format!("row count is {}", len))
+        } else {
+            None
+        };
+        self.results.push(ExpectationResult::from_message(expectation, message));
+        self
+    }
+
+    /// Collect results of all expectations.
+    pub fn validate(self) -> Validation {
+        Validation { results: self.results }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec};
+
+    use super::expect;
+    use frame::DataFrame;
+
+    #[test]
+    fn test_expectations() {
+        let mask = vec![false, false, true, false];
+        let x: Array = NullVec::with_mask(vec![1., 5., 0., 11.], Some(mask)).into();
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![1i64, 2, 2, 3]), x, Array::new(vec!["a", "b", "c", "d"])],
+            vec![10, 20, 30, 40],
+            vec!["id", "x", "name"],
+        );
+        let res = expect(&df)
+            .expect_unique(&"id")
+            .expect_unique(&"name")
+            .expect_no_nulls(&"x")
+            .expect_column_values_between(&"x", 0., 10.)
+            .expect_column_values_between(&"name", 0., 10.)
+            .expect_row_count_between(1, 3)
+            .expect_no_nulls(&"X")
+            .validate();
+        assert!(!res.success());
+        let success: Vec<bool> = res.results.iter().map(|x| x.success).collect();
+        assert_eq!(success, vec![false, true, false, false, false, false, false]);
+        assert_eq!(res.failures().len(), 6);
+        assert_eq!(res.results[0].unexpected, vec![1, 2]);
+        assert_eq!(res.results[2].unexpected, vec![2]);
+        assert_eq!(res.results[3].unexpected, vec![3]);
+
+        let exp = "[FAIL] expect_unique(\"id\"): 2 unexpected rows at [1, 2]\n\
+                   [PASS] expect_unique(\"name\")\n\
+                   [FAIL] expect_no_nulls(\"x\"): 1 unexpected rows at [2]\n\
+                   [FAIL] expect_column_values_between(\"x\", 0, 10): 1 unexpected rows at [3]\n\
+                   [FAIL] expect_column_values_between(\"name\", 0, 10): dtype str is not numeric\n\
+                   [FAIL] expect_row_count_between(1, 3): row count is 4\n\
+                   [FAIL] expect_no_nulls(\"X\"): column not found: \"X\", did you mean \"x\"?";
+        assert_eq!(res.to_string(), exp);
+
+        let res = expect(&df).expect_unique(&"name").expect_row_count_between(4, 4).validate();
+        assert!(res.success());
+        assert!(res.failures().is_empty());
+    }
+}
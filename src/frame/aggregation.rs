@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::hash::Hash;
+use std::string::ToString;
 
 use nullvec::prelude::{Array, Scalar, Nullable, NullVec};
 use nullvec::prelude::BasicAggregation as NBasicAggregation;
@@ -8,9 +9,13 @@ use nullvec::prelude::ComparisonAggregation as NComparisonAggregation;
 
 
 use super::DataFrame;
+use super::groupby::aggregate_by;
+use algos::grouper::{Grouper, HashGrouper};
+use convert::{array_to_scalars, scalars_to_array};
 use indexer::Indexer;
 use series::Series;
-use traits::{BasicAggregation, NumericAggregation, ComparisonAggregation, Description};
+use traits::{Slicer, Agg, BasicAggregation, NumericAggregation, ComparisonAggregation,
+             Description};
 
 impl<'v, 'i, 'c, I, C> BasicAggregation<'c> for DataFrame<'v, 'i, 'c, I, C>
 where
@@ -138,3 +143,114 @@ where
         DataFrame::from_cow(new_values, Cow::Owned(Indexer::new(new_index)), ndf.columns)
     }
 }
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + ToString,
+{
+    /// Summarize numeric columns per group as a flat `DataFrame`.
+    ///
+    /// Index is sorted groups, and columns are named as "{column}_{stat}" for
+    /// each of count, sum, mean, min and max.
+    pub fn summary_by<'n, G>(&self, keys: &[G]) -> DataFrame<'n, 'n, 'n, G, String>
+    where
+        G: Clone + Eq + Hash + Ord,
+    {
+        assert!(self.index.len() == keys.len(), "Length mismatch!");
+
+        let grouper: HashGrouper<G> = HashGrouper::groupby(keys);
+        let mut groups: Vec<G> = grouper.keys();
+        groups.sort();
+        let arrays: Vec<Vec<Array>> = groups
+            .iter()
+            .map(|g| {
+                let locs = grouper.get(g).unwrap();
+                self.values.iter().map(|x| x.ilocs(locs)).collect()
+            })
+            .collect();
+
+        let mut new_values: Vec<Array> = vec![];
+        let mut new_columns: Vec<String> = vec![];
+        for (loc, (column, values)) in self.columns.iter().zip(&self.values).enumerate() {
+            if !values.is_numeric() {
+                continue;
+            }
+            let dtype = values.dtype();
+            let chunks: Vec<&Array> = arrays.iter().map(|x| &x[loc]).collect();
+
+            let counts: Vec<usize> = chunks.iter().map(|x| x.count()).collect();
+            let sums: Vec<Scalar> = chunks.iter().map(|x| x.sum()).collect();
+            let means: NullVec<f64> = chunks.iter().map(|x| x.mean()).collect();
+            let mins: Vec<Scalar> = chunks.iter().map(|x| x.min()).collect();
+            let maxs: Vec<Scalar> = chunks.iter().map(|x| x.max()).collect();
+
+            new_values.push(Array::new(counts));
+            let sum_dtype = scalar_dtype(&sums, &dtype);
+            new_values.push(scalars_to_array(sums, &sum_dtype));
+            new_values.push(Array::Float64Array(means));
+            new_values.push(scalars_to_array(mins, &dtype));
+            new_values.push(scalars_to_array(maxs, &dtype));
+            for stat in &["count", "sum", "mean", "min", "max"] {
+                new_columns.push(format!("{}_{}", column.to_string(), stat));
+            }
+        }
+        DataFrame::from_vec(new_values, groups, new_columns)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Aggregate each numeric column by `agg` with explicit null handling.
+    ///
+    /// Nulls are skipped if `skipna`, otherwise a column having nulls results
+    /// in null. A column with less than `min_count` valid values also results
+    /// in null, and `Agg::Count` always counts valid values. The sum of a
+    /// column without valid values is zero if `min_count` is 0.
+    pub fn aggregate_with<'n>(
+        &self,
+        agg: Agg,
+        skipna: bool,
+        min_count: usize,
+    ) -> Series<'n, 'n, Scalar, C>
+    where
+        C: 'n,
+    {
+        let mut new_values: Vec<Scalar> = vec![];
+        let mut new_columns: Vec<C> = vec![];
+        for (column, values) in self.columns.values.iter().zip(&self.values) {
+            if !values.is_numeric() {
+                continue;
+            }
+            let valid: Vec<usize> = array_to_scalars(values)
+                .iter()
+                .enumerate()
+                .filter(|&(_, x)| *x != Scalar::Null)
+                .map(|(i, _)| i)
+                .collect();
+            let value = if agg == Agg::Count {
+                Scalar::usize(valid.len())
+            } else if (!skipna && valid.len() < values.len()) || valid.len() < min_count {
+                Scalar::Null
+            } else {
+                // aggregate valid values only, as nullvec regards the sum of
+                // nulls as null
+                aggregate_by(&values.ilocs(&valid), agg)
+            };
+            new_values.push(value);
+            new_columns.push(column.clone());
+        }
+        Series::new(new_values, new_columns)
+    }
+}
+
+/// dtype of the first non-null value, `default` if all values are null
+fn scalar_dtype(values: &[Scalar], default: &str) -> String {
+    values
+        .iter()
+        .find(|x| **x != Scalar::Null)
+        .map_or(default.to_string(), |x| x.dtype())
+}
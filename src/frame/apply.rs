@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Nullable, Scalar};
+
+use super::DataFrame;
+use algos::key::ScalarKey;
+use convert::{array_to_scalars, infer_array, scalars_to_array};
+use series::Series;
+use traits::{Slicer, IndexerIndex};
+
+/// Apply `func` to values of numeric `array` as `f64`.
+///
+/// `f32` arrays keep their dtype, and other numeric arrays result in `f64`.
+fn map_numeric_array<F>(array: &Array, func: F) -> Array
+where
+    F: Fn(f64) -> f64,
+{
+    let dtype = if array.dtype() == "f32" { "f32" } else { "f64" };
+    let values: Vec<Scalar> = array_to_scalars(array)
+        .into_iter()
+        .map(|x| match x {
+            Scalar::f32(v) => Scalar::f32(func(f64::from(v)) as f32),
+            other => match other.as_f64() {
+                Nullable::Value(v) => Scalar::f64(func(v)),
+                Nullable::Null => Scalar::Null,
+            },
+        })
+        .collect();
+    scalars_to_array(values, dtype)
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Apply `func` to values of each row. Result has the same index as the
+    /// original.
+    pub fn apply_rows<'n, W, F>(&self, mut func: F) -> Series<'n, 'n, W, I>
+    where
+        I: 'n,
+        W: Clone,
+        F: FnMut(&[Scalar]) -> W,
+    {
+        let new_values: Vec<W> = (0..self.index.len())
+            .map(|i| {
+                let row: Vec<Scalar> = self.values.iter().map(|x| x.iloc(&i)).collect();
+                func(&row)
+            })
+            .collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Apply `func` to each column. Result is labelled by columns.
+    pub fn apply_cols<'n, W, F>(&self, mut func: F) -> Series<'n, 'n, W, C>
+    where
+        C: 'n,
+        W: Clone,
+        F: FnMut(&Array) -> W,
+    {
+        let new_values: Vec<W> = self.values.iter().map(|x| func(x)).collect();
+        Series::new(new_values, self.columns.clone().into_owned())
+    }
+
+    /// Apply `func` to each value of numeric columns as `f64`, nulls and
+    /// non-numeric columns are kept.
+    ///
+    /// `f32` columns keep their dtype, and other numeric columns result in
+    /// `f64`.
+    pub fn applymap<'n, F>(&'n self, func: F) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        F: Fn(f64) -> f64,
+    {
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| if x.is_numeric() {
+                Cow::Owned(map_numeric_array(x, &func))
+            } else {
+                Cow::Borrowed(&**x)
+            })
+            .collect();
+        DataFrame::from_cow(new_values, Cow::Borrowed(&*self.index), Cow::Borrowed(&*self.columns))
+    }
+
+    /// Apply `func` to each value of all columns including nulls. Dtype of
+    /// each column is inferred from results.
+    pub fn applymap_scalar<'n, F>(&'n self, func: F) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        F: Fn(Scalar) -> Scalar,
+    {
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| {
+                let values: Vec<Scalar> = array_to_scalars(x).into_iter().map(&func).collect();
+                Cow::Owned(infer_array(values))
+            })
+            .collect();
+        DataFrame::from_cow(new_values, Cow::Borrowed(&*self.index), Cow::Borrowed(&*self.columns))
+    }
+
+    /// Replace each value of `column` by the value of `mapping` keyed by it.
+    /// Values missing in `mapping` result in nulls, and dtype of the column
+    /// is inferred from results.
+    pub fn map_column<'n, K, W>(
+        &'n self,
+        column: &C,
+        mapping: &HashMap<K, W>,
+    ) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        K: Clone + Eq + Hash + Into<Scalar>,
+        W: Clone + Into<Scalar>,
+    {
+        let keyed: HashMap<ScalarKey, Scalar> = mapping
+            .iter()
+            .map(|(k, v)| (ScalarKey::from(&k.clone().into()), v.clone().into()))
+            .collect();
+        let loc = self.columns.get_loc(column);
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .enumerate()
+            .map(|(i, x)| if i == loc {
+                let values: Vec<Scalar> = array_to_scalars(x)
+                    .iter()
+                    .map(|v| keyed.get(&ScalarKey::from(v)).cloned().unwrap_or(Scalar::Null))
+                    .collect();
+                Cow::Owned(infer_array(values))
+            } else {
+                Cow::Borrowed(&**x)
+            })
+            .collect();
+        DataFrame::from_cow(new_values, Cow::Borrowed(&*self.index), Cow::Borrowed(&*self.columns))
+    }
+}
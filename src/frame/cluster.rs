@@ -0,0 +1,84 @@
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use algos::cluster;
+use series::Series;
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Cluster rows into `k` clusters by k-means on numeric columns, the
+    /// initialization is reproducible by `seed`.
+    ///
+    /// Returns the cluster of each row labelled by the index, and centroids
+    /// labelled by cluster and numeric columns. Nulls must be removed or
+    /// filled beforehand.
+    pub fn kmeans<'n>(
+        &self,
+        k: usize,
+        seed: u64,
+    ) -> (Series<'n, 'n, usize, I>, DataFrame<'n, 'n, 'n, usize, C>)
+    where
+        I: 'n,
+        C: 'n,
+    {
+        let (columns, points) = self.numeric_rows("kmeans");
+        let (labels, centroids) = cluster::kmeans(&points, k, seed);
+
+        let new_values: Vec<Array> = (0..columns.len())
+            .map(|j| Array::new(centroids.iter().map(|x| x[j]).collect::<Vec<f64>>()))
+            .collect();
+        let centroids = DataFrame::from_vec(new_values, (0..k).collect::<Vec<usize>>(), columns);
+        (Series::new(labels, self.index.clone().into_owned()), centroids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec};
+
+    use super::super::DataFrame;
+    use traits::ColIndex;
+
+    #[test]
+    fn test_frame_kmeans() {
+        let df: DataFrame<&str, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![0i64, 10, 0, 10]),
+                Array::new(vec!["a", "b", "c", "d"]),
+                Array::new(vec![0., 10., 2., 12.]),
+            ],
+            vec!["p", "q", "r", "s"],
+            vec!["X", "S", "Y"],
+        );
+        let (labels, centroids) = df.kmeans(2, 42);
+        assert_eq!(labels.index, df.index);
+        assert_eq!(labels.values[0], labels.values[2]);
+        assert_eq!(labels.values[1], labels.values[3]);
+        assert!(labels.values[0] != labels.values[1]);
+
+        assert_eq!(centroids.columns.values, vec!["X", "Y"]);
+        assert_eq!(centroids.index.values, vec![0, 1]);
+        let (a, b) = (labels.values[0], labels.values[1]);
+        let exp_x = if a == 0 { vec![0., 10.] } else { vec![10., 0.] };
+        let exp_y = if b == 1 { vec![1., 11.] } else { vec![11., 1.] };
+        assert_eq!(centroids.get(&"X"), Array::new(exp_x));
+        assert_eq!(centroids.get(&"Y"), Array::new(exp_y));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_frame_kmeans_null() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![NullVec::with_mask(vec![0., 1.], Some(vec![false, true])).into()],
+            vec![1, 2],
+            vec!["X"],
+        );
+        df.kmeans(1, 0);
+    }
+}
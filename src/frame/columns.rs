@@ -0,0 +1,47 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use error::Error;
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + Debug,
+{
+    /// Get column, or return `Error::ColumnNotFound` with the most similar
+    /// column
+    pub fn try_get(&self, label: &C) -> Result<Array, Error> {
+        let loc = self.try_column_loc(label)?;
+        Ok(self.values[loc].clone().into_owned())
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + Debug + ToString,
+{
+    /// Get column whose name matches `name` ignoring case. Exact match is
+    /// preferred if multiple columns match.
+    pub fn get_ci(&self, name: &str) -> Array {
+        self.try_get_ci(name).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Get column ignoring case, or return `Error::ColumnNotFound` with the
+    /// most similar column
+    pub fn try_get_ci(&self, name: &str) -> Result<Array, Error> {
+        let names: Vec<String> = self.columns.values.iter().map(|x| x.to_string()).collect();
+        let lower = name.to_lowercase();
+        let loc = names
+            .iter()
+            .position(|x| x == name)
+            .or_else(|| names.iter().position(|x| x.to_lowercase() == lower));
+        match loc {
+            Some(loc) => Ok(self.values[loc].clone().into_owned()),
+            None => Err(self.column_not_found(&format!("{:?}", name))),
+        }
+    }
+}
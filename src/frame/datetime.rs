@@ -0,0 +1,17 @@
+use std::hash::Hash;
+
+use super::{DataFrame, DataFrameView};
+
+impl<'v, 'i, 'c, C> DataFrame<'v, 'i, 'c, i64, C>
+where
+    C: Clone + Eq + Hash,
+{
+    /// Rows whose label is within partial timestamp `value` such as
+    /// "2018-03", index must be sorted nanoseconds since the UNIX epoch.
+    ///
+    /// Columns are borrowed. See `Indexer::partial_locs`.
+    pub fn loc_partial<'a>(&'a self, value: &str) -> DataFrameView<'a, i64, C> {
+        let locs = self.index.partial_locs(value);
+        self.irange(locs.start, locs.end)
+    }
+}
@@ -0,0 +1,55 @@
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use algos::duplicates::Duplicates;
+use algos::key::tagged_row_keys;
+use traits::{RowIndex, NullEquality};
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Whether each row duplicates an earlier row on all columns.
+    ///
+    /// Rows having `Null` are never duplicated with `NullEquality::Distinct`.
+    pub fn duplicated(&self, nulls: NullEquality) -> Vec<bool> {
+        let arrays: Vec<&Array> = self.values.iter().map(|x| &**x).collect();
+        Duplicates::duplicated(&tagged_row_keys(&arrays, nulls, 0), Duplicates::First)
+    }
+
+    /// Drop rows duplicating an earlier row, the first occurrence is kept.
+    pub fn drop_duplicates(&'c self, nulls: NullEquality) -> Self {
+        let flags: Vec<bool> = self.duplicated(nulls).iter().map(|x| !x).collect();
+        self.blocs(&flags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec};
+
+    use super::super::DataFrame;
+    use traits::NullEquality;
+
+    #[test]
+    fn test_drop_duplicates() {
+        let mask = vec![false, false, true, true, false];
+        let x: Array = NullVec::with_mask(vec![1i64, 1, 0, 0, 1], Some(mask)).into();
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![x, Array::new(vec!["a", "a", "b", "b", "c"])],
+            vec![1, 2, 3, 4, 5],
+            vec!["X", "Y"],
+        );
+        assert_eq!(df.duplicated(NullEquality::Equal), vec![false, true, false, true, false]);
+        assert_eq!(df.duplicated(NullEquality::Distinct), vec![false, true, false, false, false]);
+
+        let res = df.drop_duplicates(NullEquality::Equal);
+        assert_eq!(res.index.values, vec![1, 3, 5]);
+        let res = df.drop_duplicates(NullEquality::Distinct);
+        assert_eq!(res.index.values, vec![1, 3, 4, 5]);
+    }
+}
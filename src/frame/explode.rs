@@ -0,0 +1,70 @@
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+
+use super::DataFrame;
+use convert::{infer_array, scalars_to_array};
+use indexer::Indexer;
+use traits::{Slicer, IndexerIndex};
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Explode list columns in lockstep, so that each element of lists becomes
+    /// a row.
+    ///
+    /// A list column is given as a list of each row, and all lists in a row
+    /// must have the same length. Other columns and index labels are repeated
+    /// for each element. Rows with empty lists are kept with null values.
+    /// List columns replace existing columns of the same name, or are
+    /// appended.
+    pub fn explode_multi<'n>(&self, lists: &[(C, &[Array])]) -> DataFrame<'n, 'n, 'n, I, C> {
+        let nrows = self.index.len();
+        for &(_, list) in lists {
+            assert!(list.len() == nrows, "List column and DataFrame length are different");
+        }
+
+        let mut locs: Vec<usize> = vec![];
+        // location in the list of each row, None for empty lists
+        let mut positions: Vec<Option<usize>> = vec![];
+        for row in 0..nrows {
+            let len = lists.first().map_or(1, |&(_, list)| list[row].len());
+            assert!(
+                lists.iter().all(|&(_, list)| list[row].len() == len),
+                "Lists in row {} have different lengths",
+                row
+            );
+            if len == 0 {
+                locs.push(row);
+                positions.push(None);
+            }
+            for i in 0..len {
+                locs.push(row);
+                positions.push(Some(i));
+            }
+        }
+
+        let mut new_values: Vec<Array> = self.values.iter().map(|x| x.ilocs(&locs)).collect();
+        let mut new_columns: Vec<C> = self.columns.values.clone();
+        for &(ref column, list) in lists {
+            let values: Vec<Scalar> = locs.iter()
+                .zip(&positions)
+                .map(|(row, pos)| pos.map_or(Scalar::Null, |x| list[*row].iloc(&x)))
+                .collect();
+            let new_value = match list.iter().find(|x| x.len() > 0) {
+                Some(array) => scalars_to_array(values, &array.dtype()),
+                None => infer_array(values),
+            };
+            if self.columns.contains(column) {
+                new_values[self.columns.get_loc(column)] = new_value;
+            } else {
+                new_values.push(new_value);
+                new_columns.push(column.clone());
+            }
+        }
+        let new_index: Indexer<I> = self.index.ilocs(&locs);
+        DataFrame::from_vec(new_values, new_index, new_columns)
+    }
+}
@@ -0,0 +1,296 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+use nullvec::prelude::BasicAggregation as NBasicAggregation;
+use nullvec::prelude::NumericAggregation as NNumericAggregation;
+use nullvec::prelude::ComparisonAggregation as NComparisonAggregation;
+
+use super::DataFrame;
+use algos::grouper::{Grouper, HashGrouper};
+use algos::key::{ScalarKey, factorize_rows, row_key};
+use convert::{array_to_scalars, scalars_to_array};
+use groupby::GroupBy;
+use indexer::Indexer;
+use series::Series;
+use traits::{Slicer, IndexerIndex, Agg, BasicAggregation, NumericAggregation,
+             ComparisonAggregation, SelectionAggregation};
+
+/// Aggregate `array` by `agg`.
+pub fn aggregate_by(array: &Array, agg: Agg) -> Scalar {
+    match agg {
+        Agg::Sum => array.sum(),
+        Agg::Count => Scalar::usize(array.count()),
+        Agg::Mean => array.mean().into(),
+        Agg::Var => array.var().into(),
+        Agg::Std => array.std().into(),
+        Agg::Min => array.min(),
+        Agg::Max => array.max(),
+    }
+}
+
+/// Dtype of the result of `aggregate_by` for `dtype` array.
+pub fn aggregated_dtype(dtype: &str, agg: Agg) -> String {
+    match agg {
+        Agg::Sum | Agg::Min | Agg::Max => dtype.to_string(),
+        Agg::Count => "usize".to_string(),
+        Agg::Mean | Agg::Var | Agg::Std => "f64".to_string(),
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Group by values of `keys` columns.
+    ///
+    /// Key columns are removed from grouped data, and groups are labelled by
+    /// values of key columns in the order of `keys`. Rows are grouped by their
+    /// hashes, so that keys are only created for each group.
+    pub fn groupby_cols(
+        &'i self,
+        keys: &[C],
+    ) -> GroupBy<'i, DataFrame<'i, 'i, 'i, I, C>, Vec<ScalarKey>> {
+        let locs: Vec<usize> = keys.iter().map(|x| self.columns.get_loc(x)).collect();
+        let arrays: Vec<&Array> = locs.iter().map(|x| &*self.values[*x]).collect();
+
+        let mut values: Vec<Cow<Array>> = vec![];
+        let mut columns: Vec<C> = vec![];
+        for (i, (column, array)) in self.columns.values.iter().zip(&self.values).enumerate() {
+            if !locs.contains(&i) {
+                values.push(Cow::Borrowed(&**array));
+                columns.push(column.clone());
+            }
+        }
+        let data = DataFrame::from_cow(
+            values,
+            Cow::Borrowed(&*self.index),
+            Cow::Owned(Indexer::new(columns)),
+        );
+        let (codes, firsts) = factorize_rows(&arrays);
+        let uniques: Vec<Vec<ScalarKey>> = firsts.iter().map(|x| row_key(&arrays, *x)).collect();
+        GroupBy {
+            data: Cow::Owned(data),
+            grouper: HashGrouper::from_codes(&codes, uniques),
+        }
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+    G: 'c + Clone + Eq + Hash + Ord,
+{
+    /// Aggregate the array of each group, result has the dtype of the
+    /// original array if `dtype` is `None`.
+    fn aggregate_array<F>(
+        &self,
+        array: &Array,
+        groups: &[G],
+        func: F,
+        dtype: Option<&str>,
+    ) -> Array
+    where
+        F: Fn(&Array) -> Scalar,
+    {
+        let values: Vec<Scalar> = groups
+            .iter()
+            .map(|g| func(&array.ilocs(self.grouper.get(g).unwrap())))
+            .collect();
+        let dtype = dtype.map_or_else(|| array.dtype(), |x| x.to_string());
+        scalars_to_array(values, &dtype)
+    }
+
+    /// Aggregate numeric columns of each group.
+    fn aggregate<F>(&'c self, func: F, dtype: Option<&str>) -> DataFrame<'c, 'c, 'c, G, C>
+    where
+        F: Fn(&Array) -> Scalar,
+    {
+        let groups = self.groups();
+        let mut new_values: Vec<Array> = vec![];
+        let mut new_columns: Vec<C> = vec![];
+        for (column, array) in self.data.columns.values.iter().zip(&self.data.values) {
+            if array.is_numeric() {
+                new_values.push(self.aggregate_array(array, &groups, &func, dtype));
+                new_columns.push(column.clone());
+            }
+        }
+        DataFrame::from_vec(new_values, groups, new_columns)
+    }
+
+    /// Ordinal of each row within its group, starting from 0. Result has the
+    /// same index as the original.
+    pub fn cumcount<'n>(&self) -> Series<'n, 'n, usize, I>
+    where
+        I: 'n,
+    {
+        let mut new_values: Vec<usize> = vec![0; self.data.index.len()];
+        for locs in self.grouper.groups.values() {
+            for (i, loc) in locs.iter().enumerate() {
+                new_values[*loc] = i;
+            }
+        }
+        Series::new(new_values, self.data.index.clone().into_owned())
+    }
+
+    /// Number of each non-null value of `column` within each group, labelled
+    /// by pairs of group key and value.
+    ///
+    /// Groups are in sorted order, and values in each group are sorted by
+    /// descending count then by value.
+    pub fn value_counts<'n>(&self, column: &C) -> Series<'n, 'n, usize, (G, ScalarKey)>
+    where
+        G: 'n,
+    {
+        let array = &self.data.values[self.data.columns.get_loc(column)];
+        let keys: Vec<ScalarKey> = array_to_scalars(array).iter().map(ScalarKey::from).collect();
+        let mut new_values: Vec<usize> = vec![];
+        let mut new_index: Vec<(G, ScalarKey)> = vec![];
+        for g in self.groups() {
+            let mut counts: HashMap<&ScalarKey, usize> = HashMap::new();
+            for &loc in self.grouper.get(&g).unwrap() {
+                if keys[loc] != ScalarKey::Null {
+                    *counts.entry(&keys[loc]).or_insert(0) += 1;
+                }
+            }
+            let mut counts: Vec<(&ScalarKey, usize)> = counts.into_iter().collect();
+            counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+            for (key, count) in counts {
+                new_values.push(count);
+                new_index.push((g.clone(), key.clone()));
+            }
+        }
+        Series::new(new_values, new_index)
+    }
+
+    /// Select a row of each group by its locations, groups which select
+    /// nothing are dropped.
+    fn select<F>(&'c self, func: F) -> DataFrame<'c, 'c, 'c, G, C>
+    where
+        F: Fn(&[usize]) -> Option<usize>,
+    {
+        let mut locs: Vec<usize> = vec![];
+        let mut new_index: Vec<G> = vec![];
+        for g in self.groups() {
+            if let Some(loc) = func(self.grouper.get(&g).unwrap()) {
+                locs.push(loc);
+                new_index.push(g);
+            }
+        }
+        let new_values: Vec<Array> = self.data.values.iter().map(|x| x.ilocs(&locs)).collect();
+        DataFrame::from_vec(new_values, new_index, self.data.columns.values.clone())
+    }
+
+    /// Apply multiple aggregations to numeric columns of each group.
+    ///
+    /// Result has a column for each pair of column and aggregation.
+    pub fn agg(&'c self, aggs: &[Agg]) -> DataFrame<'c, 'c, 'c, G, (C, Agg)> {
+        let groups = self.groups();
+        let mut new_values: Vec<Array> = vec![];
+        let mut new_columns: Vec<(C, Agg)> = vec![];
+        for (column, array) in self.data.columns.values.iter().zip(&self.data.values) {
+            if !array.is_numeric() {
+                continue;
+            }
+            for agg in aggs {
+                let dtype = aggregated_dtype(&array.dtype(), *agg);
+                let values =
+                    self.aggregate_array(array, &groups, |x| aggregate_by(x, *agg), Some(&dtype));
+                new_values.push(values);
+                new_columns.push((column.clone(), *agg));
+            }
+        }
+        DataFrame::from_vec(new_values, groups, new_columns)
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> BasicAggregation<'c> for GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+    G: 'c + Clone + Eq + Hash + Ord,
+{
+    type Kept = DataFrame<'c, 'c, 'c, G, C>;
+    type Counted = DataFrame<'c, 'c, 'c, G, C>;
+
+    fn sum(&'c self) -> Self::Kept {
+        self.aggregate(|x| x.sum(), None)
+    }
+
+    fn count(&'c self) -> Self::Counted {
+        self.aggregate(|x| Scalar::usize(x.count()), Some("usize"))
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> NumericAggregation<'c> for GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+    G: 'c + Clone + Eq + Hash + Ord,
+{
+    type Coerced = DataFrame<'c, 'c, 'c, G, C>;
+
+    fn mean(&'c self) -> Self::Coerced {
+        self.aggregate(|x| x.mean().into(), Some("f64"))
+    }
+
+    fn var(&'c self) -> Self::Coerced {
+        self.aggregate(|x| x.var().into(), Some("f64"))
+    }
+
+    fn unbiased_var(&'c self) -> Self::Coerced {
+        self.aggregate(|x| x.unbiased_var().into(), Some("f64"))
+    }
+
+    fn std(&'c self) -> Self::Coerced {
+        self.aggregate(|x| x.std().into(), Some("f64"))
+    }
+
+    fn unbiased_std(&'c self) -> Self::Coerced {
+        self.aggregate(|x| x.unbiased_std().into(), Some("f64"))
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> ComparisonAggregation<'c>
+    for GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+    G: 'c + Clone + Eq + Hash + Ord,
+{
+    type Kept = DataFrame<'c, 'c, 'c, G, C>;
+
+    fn min(&'c self) -> Self::Kept {
+        self.aggregate(|x| x.min(), None)
+    }
+
+    fn max(&'c self) -> Self::Kept {
+        self.aggregate(|x| x.max(), None)
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> SelectionAggregation<'c>
+    for GroupBy<'c, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+    G: 'c + Clone + Eq + Hash + Ord,
+{
+    // groups which don't have the row are dropped
+    type Selected = DataFrame<'c, 'c, 'c, G, C>;
+
+    fn first(&'c self) -> Self::Selected {
+        self.nth(0)
+    }
+
+    fn last(&'c self) -> Self::Selected {
+        self.select(|x| x.last().cloned())
+    }
+
+    fn nth(&'c self, n: usize) -> Self::Selected {
+        self.select(|x| x.get(n).cloned())
+    }
+}
@@ -0,0 +1,188 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Nullable, Scalar};
+
+use super::DataFrame;
+use convert::{array_to_scalars, scalars_to_array};
+use traits::Slicer;
+
+/// Source locations of values shifted by `periods`, `usize::MAX` where the
+/// source is out of bounds.
+fn shifted_locs(len: usize, periods: isize) -> Vec<usize> {
+    (0..len as isize)
+        .map(|i| {
+            let src = i - periods;
+            if src >= 0 && src < len as isize {
+                src as usize
+            } else {
+                usize::MAX
+            }
+        })
+        .collect()
+}
+
+macro_rules! define_float_fn {
+    ($m:ident, $doc:expr) => {
+        #[doc = $doc]
+        pub fn $m<'n>(&'n self) -> DataFrame<'n, 'n, 'n, I, C> {
+            self.applymap(|x| x.$m())
+        }
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    define_float_fn!(exp, "Exponential of each value in numeric columns.");
+    define_float_fn!(ln, "Natural logarithm of each value in numeric columns.");
+    define_float_fn!(log10, "Base 10 logarithm of each value in numeric columns.");
+    define_float_fn!(sqrt, "Square root of each value in numeric columns.");
+    define_float_fn!(floor, "Largest integer less than or equal to each numeric value.");
+    define_float_fn!(ceil, "Smallest integer greater than or equal to each numeric value.");
+
+    /// Raise each value in numeric columns to integer power `n`.
+    pub fn powi<'n>(&'n self, n: i32) -> DataFrame<'n, 'n, 'n, I, C> {
+        self.applymap(|x| x.powi(n))
+    }
+
+    /// Raise each value in numeric columns to float power `n`.
+    pub fn powf<'n>(&'n self, n: f64) -> DataFrame<'n, 'n, 'n, I, C> {
+        self.applymap(|x| x.powf(n))
+    }
+
+    /// Shift values of all columns by `periods` keeping the index, forward
+    /// if positive and backward if negative. Locations without value are null.
+    pub fn shift<'n>(&'n self, periods: isize) -> DataFrame<'n, 'n, 'n, I, C> {
+        let locs = shifted_locs(self.index.len(), periods);
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| Cow::Owned(x.ilocs_forced(&locs)))
+            .collect();
+        DataFrame::from_cow(new_values, Cow::Borrowed(&*self.index), Cow::Borrowed(&*self.columns))
+    }
+
+    /// Apply `func` to each value of numeric columns and the value `periods`
+    /// before as `f64`. Result is null where either is null, and non-numeric
+    /// columns are kept.
+    fn map_shifted<'n, F>(&'n self, periods: isize, func: F) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        let locs = shifted_locs(self.index.len(), periods);
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| if x.is_numeric() {
+                let prev = array_to_scalars(&x.ilocs_forced(&locs));
+                let values: Vec<Scalar> = array_to_scalars(x)
+                    .iter()
+                    .zip(&prev)
+                    .map(|(v, p)| match (v.as_f64(), p.as_f64()) {
+                        (Nullable::Value(v), Nullable::Value(p)) => Scalar::f64(func(v, p)),
+                        _ => Scalar::Null,
+                    })
+                    .collect();
+                Cow::Owned(scalars_to_array(values, "f64"))
+            } else {
+                Cow::Borrowed(&**x)
+            })
+            .collect();
+        DataFrame::from_cow(new_values, Cow::Borrowed(&*self.index), Cow::Borrowed(&*self.columns))
+    }
+
+    /// Difference from the value `periods` before in numeric columns, null
+    /// where it doesn't exist.
+    pub fn diff<'n>(&'n self, periods: isize) -> DataFrame<'n, 'n, 'n, I, C> {
+        self.map_shifted(periods, |x, prev| x - prev)
+    }
+
+    /// Fractional change from the value `periods` before in numeric columns,
+    /// null where it doesn't exist.
+    pub fn pct_change<'n>(&'n self, periods: isize) -> DataFrame<'n, 'n, 'n, I, C> {
+        self.map_shifted(periods, |x, prev| x / prev - 1.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec};
+
+    use super::super::DataFrame;
+    use traits::ColIndex;
+
+    #[test]
+    fn test_frame_math() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![1i64, 4]),
+                Array::new(vec!["a", "b"]),
+                NullVec::with_mask(vec![0.25f32, 0.], Some(vec![false, true])).into(),
+            ],
+            vec![10, 20],
+            vec!["X", "Y", "Z"],
+        );
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![1., 2.]),
+                Array::new(vec!["a", "b"]),
+                NullVec::with_mask(vec![0.5f32, 0.], Some(vec![false, true])).into(),
+            ],
+            vec![10, 20],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.sqrt(), exp);
+        assert_eq!(df.powf(0.5), exp);
+
+        let res = df.powi(2);
+        assert_eq!(res.dtypes(), vec!["f64", "str", "f32"]);
+        assert_eq!(res.get(&"X"), Array::new(vec![1., 16.]));
+    }
+
+    #[test]
+    fn test_frame_shift_diff() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![1i64, 4, 2]),
+                Array::new(vec!["a", "b", "c"]),
+                NullVec::with_mask(vec![2., 0., 3.], Some(vec![false, true, false])).into(),
+            ],
+            vec![10, 20, 30],
+            vec!["X", "Y", "Z"],
+        );
+
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![0i64, 1, 4], Some(vec![true, false, false])).into(),
+                NullVec::with_mask(
+                    vec![String::new(), "a".to_string(), "b".to_string()],
+                    Some(vec![true, false, false]),
+                ).into(),
+                NullVec::with_mask(vec![0., 2., 0.], Some(vec![true, false, true])).into(),
+            ],
+            vec![10, 20, 30],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.shift(1), exp);
+
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![0., 3., -2.], Some(vec![true, false, false])).into(),
+                Array::new(vec!["a", "b", "c"]),
+                NullVec::with_mask(vec![0., 0., 0.], Some(vec![true, true, true])).into(),
+            ],
+            vec![10, 20, 30],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.diff(1), exp);
+
+        let res = df.pct_change(2);
+        let exp: Array = NullVec::with_mask(vec![0., 0., 1.], Some(vec![true, true, false])).into();
+        assert_eq!(res.get(&"X"), exp);
+        let exp: Array =
+            NullVec::with_mask(vec![0., 0., 0.5], Some(vec![true, true, false])).into();
+        assert_eq!(res.get(&"Z"), exp);
+    }
+}
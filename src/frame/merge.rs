@@ -0,0 +1,192 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+
+use super::DataFrame;
+use algos::join::hash_merge;
+use algos::key::tagged_row_keys;
+use convert::{array_to_scalars, promote_dtype, scalars_to_array};
+use error::Error;
+use indexer::Indexer;
+use traits::{Slicer, IndexerIndex, How, NullEquality};
+
+/// Cast `values` to `dtype` unless it already is.
+fn promote_array<'a>(values: &'a Array, dtype: &str) -> Cow<'a, Array> {
+    if values.dtype() == dtype {
+        Cow::Borrowed(values)
+    } else {
+        Cow::Owned(scalars_to_array(array_to_scalars(values), dtype))
+    }
+}
+
+impl<'v, 'i, 'c, I> DataFrame<'v, 'i, 'c, I, String>
+where
+    I: Clone + Eq + Hash,
+{
+    /// Join rows whose values of `on` columns are equal, index is reset.
+    ///
+    /// Key columns appear once at their position in `self`, followed by other
+    /// columns of `other`. Non-key columns existing in both are suffixed with
+    /// "_x" and "_y". `Null` keys match each other.
+    pub fn merge<'a, J>(
+        &self,
+        other: &DataFrame<J, String>,
+        on: &[&str],
+        how: How,
+    ) -> DataFrame<'a, 'a, 'a, usize, String>
+    where
+        J: Clone + Eq + Hash,
+    {
+        self.merge_with_suffixes(other, on, how, ("_x", "_y"))
+    }
+
+    /// Merge with suffixes appended to overlapping non-key columns.
+    pub fn merge_with_suffixes<'a, J>(
+        &self,
+        other: &DataFrame<J, String>,
+        on: &[&str],
+        how: How,
+        suffixes: (&str, &str),
+    ) -> DataFrame<'a, 'a, 'a, usize, String>
+    where
+        J: Clone + Eq + Hash,
+    {
+        match self.try_merge_with_suffixes(other, on, how, suffixes) {
+            Ok(df) => df,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Merge with the specified equality of `Null` keys.
+    ///
+    /// Rows having `Null` in any key never match with `NullEquality::Distinct`,
+    /// as SQL joins.
+    pub fn merge_with_nulls<'a, J>(
+        &self,
+        other: &DataFrame<J, String>,
+        on: &[&str],
+        how: How,
+        nulls: NullEquality,
+    ) -> DataFrame<'a, 'a, 'a, usize, String>
+    where
+        J: Clone + Eq + Hash,
+    {
+        match self.merge_impl(other, on, how, ("_x", "_y"), nulls) {
+            Ok(df) => df,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Merge, or return `Error::ColumnNotFound` if any key column doesn't exist
+    /// and `Error::DtypeMismatch` if dtypes of any key column can't be promoted.
+    pub fn try_merge<'a, J>(
+        &self,
+        other: &DataFrame<J, String>,
+        on: &[&str],
+        how: How,
+    ) -> Result<DataFrame<'a, 'a, 'a, usize, String>, Error>
+    where
+        J: Clone + Eq + Hash,
+    {
+        self.try_merge_with_suffixes(other, on, how, ("_x", "_y"))
+    }
+
+    /// Merge with suffixes, or return `Error::ColumnNotFound` if any key column
+    /// doesn't exist and `Error::DtypeMismatch` if dtypes of any key column
+    /// can't be promoted.
+    pub fn try_merge_with_suffixes<'a, J>(
+        &self,
+        other: &DataFrame<J, String>,
+        on: &[&str],
+        how: How,
+        suffixes: (&str, &str),
+    ) -> Result<DataFrame<'a, 'a, 'a, usize, String>, Error>
+    where
+        J: Clone + Eq + Hash,
+    {
+        self.merge_impl(other, on, how, suffixes, NullEquality::Equal)
+    }
+
+    fn merge_impl<'a, J>(
+        &self,
+        other: &DataFrame<J, String>,
+        on: &[&str],
+        how: How,
+        suffixes: (&str, &str),
+        nulls: NullEquality,
+    ) -> Result<DataFrame<'a, 'a, 'a, usize, String>, Error>
+    where
+        J: Clone + Eq + Hash,
+    {
+        let on: Vec<String> = on.iter().map(|x| x.to_string()).collect();
+        // keys are promoted to the common dtype, so that 2 and 2.0 match
+        let mut lkeys: Vec<Cow<Array>> = Vec::with_capacity(on.len());
+        let mut rkeys: Vec<Cow<Array>> = Vec::with_capacity(on.len());
+        for key in &on {
+            self.try_column_loc(key)?;
+            other.try_column_loc(key)?;
+            let lvalues = &self.values[self.columns.get_loc(key)];
+            let rvalues = &other.values[other.columns.get_loc(key)];
+            let dtype = match promote_dtype(&lvalues.dtype(), &rvalues.dtype()) {
+                Some(dtype) => dtype,
+                None => {
+                    return Err(Error::DtypeMismatch {
+                        column: format!("{:?}", key),
+                        left: lvalues.dtype(),
+                        right: rvalues.dtype(),
+                    })
+                }
+            };
+            lkeys.push(promote_array(lvalues, &dtype));
+            rkeys.push(promote_array(rvalues, &dtype));
+        }
+        let (lindexer, rindexer) = {
+            let lkeys: Vec<&Array> = lkeys.iter().map(|x| x.as_ref()).collect();
+            let rkeys: Vec<&Array> = rkeys.iter().map(|x| x.as_ref()).collect();
+            hash_merge(
+                &tagged_row_keys(&lkeys, nulls, 0),
+                &tagged_row_keys(&rkeys, nulls, 1),
+                how,
+            )
+        };
+
+        let mut new_values: Vec<Array> = vec![];
+        let mut new_columns: Vec<String> = vec![];
+        for (column, values) in self.columns.values.iter().zip(&self.values) {
+            let lvalues = values.ilocs_forced(&lindexer);
+            if let Some(pos) = on.iter().position(|x| x == column) {
+                // fill keys of rows only in right
+                let lvalues = lkeys[pos].ilocs_forced(&lindexer);
+                let rvalues = rkeys[pos].ilocs_forced(&rindexer);
+                let keys: Vec<Scalar> = (0..lindexer.len())
+                    .map(|i| match lvalues.iloc(&i) {
+                        Scalar::Null => rvalues.iloc(&i),
+                        value => value,
+                    })
+                    .collect();
+                new_values.push(scalars_to_array(keys, &lkeys[pos].dtype()));
+                new_columns.push(column.clone());
+            } else if other.columns.contains(column) {
+                new_values.push(lvalues);
+                new_columns.push(format!("{}{}", column, suffixes.0));
+            } else {
+                new_values.push(lvalues);
+                new_columns.push(column.clone());
+            }
+        }
+        for (column, values) in other.columns.values.iter().zip(&other.values) {
+            if on.contains(column) {
+                continue;
+            }
+            new_values.push(values.ilocs_forced(&rindexer));
+            if self.columns.contains(column) {
+                new_columns.push(format!("{}{}", column, suffixes.1));
+            } else {
+                new_columns.push(column.clone());
+            }
+        }
+        let index: Indexer<usize> = Indexer::<usize>::from_len(lindexer.len());
+        Ok(DataFrame::from_vec(new_values, index, new_columns))
+    }
+}
@@ -0,0 +1,343 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+
+use super::DataFrame;
+use convert::{array_to_scalars, scalars_to_array};
+use indexer::Indexer;
+use series::Series;
+use traits::{Slicer, IndexerIndex, RowIndex, DropHow};
+
+/// Whether each element of `array` is null.
+fn null_flags(array: &Array) -> Vec<bool> {
+    array_to_scalars(array).iter().map(|x| *x == Scalar::Null).collect()
+}
+
+/// Replace nulls of `array` with `value` cast to the dtype of `array`.
+fn fill_array(array: &Array, value: &Scalar) -> Array {
+    let values: Vec<Scalar> = array_to_scalars(array)
+        .into_iter()
+        .map(|x| if x == Scalar::Null { value.clone() } else { x })
+        .collect();
+    scalars_to_array(values, &array.dtype())
+}
+
+/// Replace nulls of `array` with the previous valid value, or the next one
+/// if `forward` is false. Leading (trailing) nulls are kept.
+fn propagate_array(array: &Array, forward: bool) -> Array {
+    let mut values = array_to_scalars(array);
+    let mut last = Scalar::Null;
+    {
+        let mut fill = |x: &mut Scalar| if *x == Scalar::Null {
+            *x = last.clone();
+        } else {
+            last = x.clone();
+        };
+        if forward {
+            values.iter_mut().for_each(&mut fill);
+        } else {
+            values.iter_mut().rev().for_each(&mut fill);
+        }
+    }
+    scalars_to_array(values, &array.dtype())
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Apply `func` to columns having nulls, other columns are cloned.
+    fn map_null_columns<F>(&self, func: F) -> Self
+    where
+        F: Fn(&C, &Array) -> Option<Array>,
+    {
+        let new_values: Vec<Cow<Array>> = self.columns
+            .values
+            .iter()
+            .zip(&self.values)
+            .map(|(c, x)| {
+                let filled = if array_to_scalars(x).contains(&Scalar::Null) {
+                    func(c, x)
+                } else {
+                    None
+                };
+                Cow::Owned(filled.unwrap_or_else(|| x.clone().into_owned()))
+            })
+            .collect();
+        DataFrame::from_cow(new_values, self.index.clone(), self.columns.clone())
+    }
+
+    /// Replace nulls of all columns with `value`, which is cast to the dtype
+    /// of each column.
+    pub fn fillna(&self, value: &Scalar) -> Self {
+        self.map_null_columns(|_, x| Some(fill_array(x, value)))
+    }
+
+    /// Replace nulls of each column with its value in `values`, columns not
+    /// found in `values` are kept.
+    pub fn fillna_by(&self, values: &HashMap<C, Scalar>) -> Self {
+        self.map_null_columns(|c, x| values.get(c).map(|v| fill_array(x, v)))
+    }
+
+    /// Replace nulls with the previous valid value of the column, leading
+    /// nulls are kept.
+    pub fn ffill(&self) -> Self {
+        self.map_null_columns(|_, x| Some(propagate_array(x, true)))
+    }
+
+    /// Replace nulls with the next valid value of the column, trailing nulls
+    /// are kept.
+    pub fn bfill(&self) -> Self {
+        self.map_null_columns(|_, x| Some(propagate_array(x, false)))
+    }
+
+    /// Apply `func` to null flags of each column.
+    fn map_null_flags<'n, F>(&self, func: F) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        I: 'n,
+        C: 'n,
+        F: Fn(bool) -> bool,
+    {
+        let new_values: Vec<Array> = self.values
+            .iter()
+            .map(|x| Array::new(null_flags(x).into_iter().map(&func).collect::<Vec<bool>>()))
+            .collect();
+        DataFrame::from_vec(new_values, self.index.values.clone(), self.columns.values.clone())
+    }
+
+    /// Whether each value is null, as `bool` columns.
+    pub fn isnull<'n>(&self) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        I: 'n,
+        C: 'n,
+    {
+        self.map_null_flags(|x| x)
+    }
+
+    /// Whether each value is not null, as `bool` columns.
+    pub fn notnull<'n>(&self) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        I: 'n,
+        C: 'n,
+    {
+        self.map_null_flags(|x| !x)
+    }
+
+    /// Number of nulls of each column.
+    pub fn null_count<'n>(&self) -> Series<'n, 'n, usize, C>
+    where
+        C: 'n,
+    {
+        let new_values: Vec<usize> = self.values
+            .iter()
+            .map(|x| null_flags(x).iter().filter(|&&f| f).count())
+            .collect();
+        Series::new(new_values, self.columns.clone().into_owned())
+    }
+
+    /// Remove rows having any null (or whose values are all null) in
+    /// `subset` columns, all columns are checked if `subset` is `None`.
+    pub fn dropna(&'c self, how: DropHow, subset: Option<&[C]>) -> Self {
+        let locs: Vec<usize> = match subset {
+            Some(columns) => columns.iter().map(|x| self.columns.get_loc(x)).collect(),
+            None => (0..self.values.len()).collect(),
+        };
+        let nulls: Vec<Vec<bool>> = locs.iter().map(|&x| null_flags(&self.values[x])).collect();
+        let flags: Vec<bool> = (0..self.index.len())
+            .map(|i| {
+                let mut row = nulls.iter().map(|x| x[i]);
+                match how {
+                    DropHow::Any => !row.any(|x| x),
+                    DropHow::All => !row.all(|x| x),
+                }
+            })
+            .collect();
+        self.blocs(&flags)
+    }
+
+    /// Remove columns whose ratio of nulls is greater than `threshold`.
+    pub fn drop_null_columns(&self, threshold: f64) -> Self {
+        let len = self.index.len();
+        let mut new_values: Vec<Cow<Array>> = vec![];
+        let mut new_columns: Vec<C> = vec![];
+        for (column, values) in self.columns.values.iter().zip(&self.values) {
+            let nulls = null_flags(values).iter().filter(|&&x| x).count();
+            if len == 0 || nulls as f64 / len as f64 <= threshold {
+                new_values.push(values.clone());
+                new_columns.push(column.clone());
+            }
+        }
+        DataFrame::from_cow(new_values, self.index.clone(), Cow::Owned(Indexer::new(new_columns)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashMap;
+
+    use nullvec::prelude::{Array, NullVec, Scalar};
+
+    use super::super::DataFrame;
+    use series::Series;
+    use traits::DropHow;
+
+    #[test]
+    fn test_frame_fillna() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![0, 1i64, 0, 3], Some(vec![true, false, true, false]))
+                    .into(),
+                NullVec::with_mask(vec![1.5, 0., 0., 0.], Some(vec![false, true, true, true]))
+                    .into(),
+                Array::new(vec!["a", "b", "c", "d"]),
+            ],
+            vec![1, 2, 3, 4],
+            vec!["X", "Y", "Z"],
+        );
+
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![0i64, 1, 0, 3]),
+                Array::new(vec![1.5, 0., 0., 0.]),
+                Array::new(vec!["a", "b", "c", "d"]),
+            ],
+            vec![1, 2, 3, 4],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.fillna(&Scalar::i64(0)), exp);
+
+        let mut values = HashMap::new();
+        values.insert("Y", Scalar::f64(-1.));
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![0, 1i64, 0, 3], Some(vec![true, false, true, false]))
+                    .into(),
+                Array::new(vec![1.5, -1., -1., -1.]),
+                Array::new(vec!["a", "b", "c", "d"]),
+            ],
+            vec![1, 2, 3, 4],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.fillna_by(&values), exp);
+
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![0, 1i64, 1, 3], Some(vec![true, false, false, false]))
+                    .into(),
+                Array::new(vec![1.5, 1.5, 1.5, 1.5]),
+                Array::new(vec!["a", "b", "c", "d"]),
+            ],
+            vec![1, 2, 3, 4],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.ffill(), exp);
+
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![1i64, 1, 3, 3]),
+                NullVec::with_mask(vec![1.5, 0., 0., 0.], Some(vec![false, true, true, true]))
+                    .into(),
+                Array::new(vec!["a", "b", "c", "d"]),
+            ],
+            vec![1, 2, 3, 4],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.bfill(), exp);
+    }
+
+    #[test]
+    fn test_frame_dropna() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![0, 1i64, 0, 3], Some(vec![true, false, true, false]))
+                    .into(),
+                NullVec::with_mask(vec![1.5, 0., 0., 4.5], Some(vec![false, true, true, false]))
+                    .into(),
+                Array::new(vec!["a", "b", "c", "d"]),
+            ],
+            vec![1, 2, 3, 4],
+            vec!["X", "Y", "Z"],
+        );
+
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![3i64], Some(vec![false])).into(),
+                NullVec::with_mask(vec![4.5], Some(vec![false])).into(),
+                Array::new(vec!["d"]),
+            ],
+            vec![4],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.dropna(DropHow::Any, None), exp);
+        assert_eq!(df.dropna(DropHow::All, None), df);
+
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![0, 1i64, 3], Some(vec![true, false, false])).into(),
+                NullVec::with_mask(vec![1.5, 0., 4.5], Some(vec![false, true, false])).into(),
+                Array::new(vec!["a", "b", "d"]),
+            ],
+            vec![1, 2, 4],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.dropna(DropHow::All, Some(&["X", "Y"])), exp);
+
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![0, 3i64], Some(vec![true, false])).into(),
+                NullVec::with_mask(vec![1.5, 4.5], Some(vec![false, false])).into(),
+                Array::new(vec!["a", "d"]),
+            ],
+            vec![1, 4],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.dropna(DropHow::Any, Some(&["Y"])), exp);
+    }
+
+    #[test]
+    fn test_frame_drop_null_columns() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![0, 1i64, 0], Some(vec![true, false, true])).into(),
+                NullVec::with_mask(vec![1.5, 0., 3.], Some(vec![false, true, false])).into(),
+                Array::new(vec!["a", "b", "c"]),
+            ],
+            vec![1, 2, 3],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.drop_null_columns(1.).columns.values, vec!["X", "Y", "Z"]);
+        assert_eq!(df.drop_null_columns(0.5).columns.values, vec!["Y", "Z"]);
+        assert_eq!(df.drop_null_columns(0.).columns.values, vec!["Z"]);
+        assert_eq!(df.drop_null_columns(0.5).index, df.index);
+    }
+
+    #[test]
+    fn test_frame_isnull() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![0, 1i64, 0], Some(vec![true, false, true])).into(),
+                Array::new(vec!["a", "b", "c"]),
+            ],
+            vec![1, 2, 3],
+            vec!["X", "Y"],
+        );
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![true, false, true]), Array::new(vec![false, false, false])],
+            vec![1, 2, 3],
+            vec!["X", "Y"],
+        );
+        assert_eq!(df.isnull(), exp);
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![false, true, false]), Array::new(vec![true, true, true])],
+            vec![1, 2, 3],
+            vec!["X", "Y"],
+        );
+        assert_eq!(df.notnull(), exp);
+        let exp: Series<usize, &str> = Series::new(vec![2, 0], vec!["X", "Y"]);
+        assert_eq!(df.null_count(), exp);
+    }
+}
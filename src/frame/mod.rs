@@ -1,17 +1,52 @@
 use std::borrow::{Borrow, Cow};
+use std::fmt::Debug;
 use std::hash::Hash;
 use std::slice;
+use std::str::FromStr;
 use std::vec;
 
-use nullvec::prelude::Array;
+use nullvec::prelude::{Array, Nullable};
 
-use groupby::GroupBy;
+use algos::distance::suggest;
+use convert::array_to_scalars;
+use error::Error;
+use groupby::{GroupBy, GroupKeys};
 use indexer::Indexer;
-use traits::{Slicer, IndexerIndex, RowIndex, ColIndex};
+use traits::{Slicer, IndexerIndex, RowIndex, ColIndex, AsType, DType};
 
 mod aggregation;
+mod apply;
+mod cluster;
+mod columns;
+#[cfg(feature = "chrono")]
+mod datetime;
+mod duplicates;
+mod explode;
 mod formatting;
+mod groupby;
+mod math;
+mod merge;
+mod missing;
+mod multi;
+mod ops;
+mod panel;
+mod pairwise;
+mod pca;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod profile;
 mod reshape;
+mod round;
+mod rows;
+mod schema;
+mod session;
+mod sort;
+mod view;
+
+pub use self::schema::{schema_diff, SchemaDiff};
+pub use self::rows::RowView;
+pub use self::sort::{natural_cmp, SortKey};
+pub use self::view::{Chunks, DataFrameView};
 
 #[derive(Clone)]
 pub struct DataFrame<'v, 'i, 'c, I, C>
@@ -39,7 +74,7 @@ where
     type Key = I;
     type Row = Array;
 
-    fn len(&'c self) -> usize {
+    fn len(&self) -> usize {
         self.index.len()
     }
 
@@ -72,9 +107,12 @@ where
         )
     }
 
-    fn blocs(&self, _labels: &[bool]) -> Self {
-        unimplemented!()
-        // ToDo: fix Series impl
+    fn blocs(&self, flags: &[bool]) -> Self {
+        assert!(self.len() == flags.len(), "Length mismatch!");
+        let new_index = self.index.blocs(flags);
+        let new_values: Vec<Cow<Array>> =
+            self.values.iter().map(|x| Cow::Owned(x.blocs(flags))).collect();
+        DataFrame::from_cow(new_values, Cow::Owned(new_index), self.columns.clone())
     }
 }
 
@@ -86,12 +124,13 @@ where
     type Key = C;
     type Column = Array;
 
-    fn get(&'i self, _label: &Self::Key) -> Self::Column {
-        unimplemented!();
+    fn get(&'i self, label: &Self::Key) -> Self::Column {
+        let loc = self.columns.get_loc(label);
+        self.iget(&loc)
     }
 
-    fn iget(&'i self, _loc: &usize) -> Self::Column {
-        unimplemented!();
+    fn iget(&'i self, loc: &usize) -> Self::Column {
+        self.values[*loc].clone().into_owned()
     }
 
     fn gets<'l>(&'i self, labels: &'l [Self::Key]) -> Self {
@@ -115,6 +154,103 @@ where
     }
 }
 
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + Debug,
+{
+    /// `Error::ColumnNotFound` for `label`, with the most similar column.
+    fn column_not_found(&self, label: &str) -> Error {
+        let columns: Vec<String> = self.columns
+            .values
+            .iter()
+            .map(|x| format!("{:?}", x))
+            .collect();
+        // compare without quotes of string labels
+        let names: Vec<&str> = columns.iter().map(|x| x.trim_matches('"')).collect();
+        Error::ColumnNotFound {
+            label: label.to_string(),
+            suggestion: suggest(label.trim_matches('"'), &names).map(|x| {
+                columns[names.iter().position(|n| *n == x).unwrap()].clone()
+            }),
+        }
+    }
+
+    /// Location of column, or `Error::ColumnNotFound`
+    fn try_column_loc(&self, label: &C) -> Result<usize, Error> {
+        if self.columns.contains(label) {
+            Ok(self.columns.get_loc(label))
+        } else {
+            Err(self.column_not_found(&format!("{:?}", label)))
+        }
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash + Debug,
+    C: Clone + Eq + Hash + Debug,
+{
+    /// Slice rows using given labels, or return `Error::KeyNotFound`
+    pub fn try_locs(&'c self, labels: &[I]) -> Result<Self, Error> {
+        let locations = self.index.try_get_locs(labels)?;
+        Ok(self.reindex_by_index(&locations))
+    }
+
+    /// Slice rows using given locations, or return `Error::OutOfBounds`
+    pub fn try_ilocs(&'c self, locations: &[usize]) -> Result<Self, Error> {
+        if let Some(&location) = locations.iter().find(|&&x| x >= self.len()) {
+            return Err(Error::OutOfBounds {
+                location,
+                len: self.len(),
+            });
+        }
+        Ok(self.reindex_by_index(locations))
+    }
+
+    /// Slice columns using given labels, or return `Error::ColumnNotFound`
+    pub fn try_gets(&'i self, labels: &[C]) -> Result<Self, Error> {
+        let locations = labels
+            .iter()
+            .map(|x| self.try_column_loc(x))
+            .collect::<Result<Vec<usize>, Error>>()?;
+        Ok(self.igets(&locations))
+    }
+
+    /// Convert columns to paired dtypes, other columns are kept.
+    ///
+    /// Values which can't be converted without loss become null if `coerce`,
+    /// otherwise `Error::Cast` is returned. `Error::ColumnNotFound` is
+    /// returned for unknown columns.
+    pub fn try_astype(&self, dtypes: &[(C, DType)], coerce: bool) -> Result<Self, Error> {
+        let mut new_values = self.values.clone();
+        for &(ref column, dtype) in dtypes {
+            let loc = self.try_column_loc(column)?;
+            new_values[loc] = Cow::Owned(self.values[loc].try_astype(dtype, coerce)?);
+        }
+        Ok(DataFrame::from_cow(new_values, self.index.clone(), self.columns.clone()))
+    }
+
+    /// Convert columns to paired dtypes, panic where `try_astype` returns an
+    /// error.
+    pub fn astype(&self, dtypes: &[(C, DType)], coerce: bool) -> Self {
+        self.try_astype(dtypes, coerce).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Add column, or return `Error::LengthMismatch` / `Error::DuplicateLabel`
+    pub fn try_insert(&mut self, values: Array, name: C) -> Result<(), Error> {
+        if self.len() != values.len() {
+            return Err(Error::LengthMismatch {
+                expected: self.len(),
+                actual: values.len(),
+            });
+        }
+        self.columns.to_mut().try_push(name)?;
+        self.values.push(Cow::Owned(values));
+        Ok(())
+    }
+}
+
 /// /////////////////////////////////////////////////////////////////////////////
 /// Misc
 /// /////////////////////////////////////////////////////////////////////////////
@@ -125,6 +261,16 @@ where
     C: Clone + Eq + Hash,
 {
     pub fn from_vec<X, Y>(values: Vec<Array>, index: X, columns: Y) -> Self
+    where
+        X: Into<Indexer<I>>,
+        Y: Into<Indexer<C>>,
+    {
+        DataFrame::try_from_vec(values, index, columns).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create `DataFrame`, or return `Error::LengthMismatch` if the number of
+    /// columns or length of any values is different
+    pub fn try_from_vec<X, Y>(values: Vec<Array>, index: X, columns: Y) -> Result<Self, Error>
     where
         X: Into<Indexer<I>>,
         Y: Into<Indexer<C>>,
@@ -133,18 +279,28 @@ where
         let index: Indexer<I> = index.into();
         let columns: Indexer<C> = columns.into();
 
-        assert!(values.len() == columns.len(), "Length mismatch!");
+        if values.len() != columns.len() {
+            return Err(Error::LengthMismatch {
+                expected: columns.len(),
+                actual: values.len(),
+            });
+        }
         let values: Vec<Cow<Array>> = values.into_iter().map(Cow::Owned).collect();
 
         let len = index.len();
         for value in &values {
-            assert!(value.len() == len, "Length mismatch!");
+            if value.len() != len {
+                return Err(Error::LengthMismatch {
+                    expected: len,
+                    actual: value.len(),
+                });
+            }
         }
-        DataFrame {
+        Ok(DataFrame {
             values: values,
             index: Cow::Owned(index),
             columns: Cow::Owned(columns),
-        }
+        })
     }
 
     fn from_cow(
@@ -160,6 +316,25 @@ where
         }
     }
 
+    /// Convert index labels to other type by parsing their string representation.
+    pub fn index_astype<'n, J>(&self) -> DataFrame<'v, 'n, 'c, J, C>
+    where
+        I: ToString,
+        J: 'n + Clone + Eq + Hash + FromStr,
+    {
+        self.try_index_astype().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Convert index labels to other type, or return `Error::ParseLabel`
+    pub fn try_index_astype<'n, J>(&self) -> Result<DataFrame<'v, 'n, 'c, J, C>, Error>
+    where
+        I: ToString,
+        J: 'n + Clone + Eq + Hash + FromStr,
+    {
+        let index = self.index.try_astype()?;
+        Ok(DataFrame::from_cow(self.values.clone(), Cow::Owned(index), self.columns.clone()))
+    }
+
     pub fn dtypes(&self) -> Vec<String> {
         self.iter().map(|x| x.dtype()).collect()
     }
@@ -180,6 +355,42 @@ where
         self.igets(&indexer)
     }
 
+    /// Rows at `locs`, columns are borrowed.
+    fn irows<'a>(&'a self, locs: &[usize]) -> DataFrameView<'a, I, C> {
+        let values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| Cow::Owned(x.ilocs(locs)))
+            .collect();
+        let labels: Vec<I> = locs.iter().map(|&i| self.index.values[i].clone()).collect();
+        DataFrame::from_cow(values, Cow::Owned(Indexer::new(labels)), Cow::Borrowed(&*self.columns))
+    }
+
+    /// Rows from `start` to `end` (exclusive), columns are borrowed.
+    fn irange<'a>(&'a self, start: usize, end: usize) -> DataFrameView<'a, I, C> {
+        let locs: Vec<usize> = (start..end).collect();
+        self.irows(&locs)
+    }
+
+    /// Numeric columns and their values as `f64` rows, `method` is used in
+    /// the panic message if any value is null.
+    fn numeric_rows(&self, method: &str) -> (Vec<C>, Vec<Vec<f64>>) {
+        let mut columns: Vec<C> = vec![];
+        let mut rows: Vec<Vec<f64>> = vec![vec![]; self.index.values.len()];
+        for (column, values) in self.columns.values.iter().zip(&self.values) {
+            if !values.is_numeric() {
+                continue;
+            }
+            columns.push(column.clone());
+            for (row, value) in rows.iter_mut().zip(array_to_scalars(values)) {
+                match value.as_f64() {
+                    Nullable::Value(v) if !v.is_nan() => row.push(v),
+                    _ => panic!("{} can't handle nulls, use dropna or fillna", method),
+                }
+            }
+        }
+        (columns, rows)
+    }
+
     pub fn insert(&mut self, values: Array, name: C) {
         assert!(self.len() == values.len(), "Length mismatch!");
 
@@ -194,6 +405,49 @@ where
 
         GroupBy::new(self, other)
     }
+
+    /// Group by multiple keys, such as `(&keys1, &keys2)`, labelled by tuples.
+    pub fn groupby_multi<K>(&'i self, keys: K) -> GroupBy<'i, DataFrame<'i, 'i, 'i, I, C>, K::Key>
+    where
+        K: GroupKeys,
+    {
+        GroupBy::new(self, &keys.combine())
+    }
+}
+
+impl<'v, 'i, 'c> DataFrame<'v, 'i, 'c, usize, usize> {
+    /// Create `DataFrame` labelled by locations, both index and columns are
+    /// `0, 1, 2, ...`.
+    pub fn from_arrays(values: Vec<Array>) -> Self {
+        let len = values.first().map_or(0, |x| x.len());
+        let ncols = values.len();
+        DataFrame::from_vec(
+            values,
+            Indexer::<usize>::from_len(len),
+            Indexer::<usize>::from_len(ncols),
+        )
+    }
+
+    /// Create `DataFrame` from row-major matrix, labelled by locations.
+    ///
+    /// All rows must have the same length.
+    pub fn from_matrix<T>(rows: Vec<Vec<T>>) -> Self
+    where
+        T: Clone,
+        Vec<T>: Into<Array>,
+    {
+        let ncols = rows.first().map_or(0, |x| x.len());
+        for row in &rows {
+            assert!(row.len() == ncols, "Length mismatch!");
+        }
+        let values: Vec<Array> = (0..ncols)
+            .map(|i| {
+                let column: Vec<T> = rows.iter().map(|x| x[i].clone()).collect();
+                column.into()
+            })
+            .collect();
+        DataFrame::from_arrays(values)
+    }
 }
 
 /// /////////////////////////////////////////////////////////////////////////////
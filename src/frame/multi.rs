@@ -0,0 +1,104 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use groupby::GroupBy;
+use indexer::{Indexer, MultiLabel};
+use traits::Slicer;
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: MultiLabel,
+    C: Clone + Eq + Hash,
+{
+    /// Select rows whose first index level is `head`, the level is removed.
+    ///
+    /// Result is empty if `head` is not found.
+    pub fn xs_first<'n>(&'c self, head: &I::Head) -> DataFrame<'n, 'n, 'c, I::Tail, C>
+    where
+        I::Tail: 'n,
+    {
+        let locs = self.index.xs_first_locs(head);
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| Cow::Owned(x.ilocs(&locs)))
+            .collect();
+        let new_index = self.index.ilocs(&locs).drop_first_level();
+        DataFrame::from_cow(
+            new_values,
+            Cow::Owned(new_index),
+            Cow::Borrowed(self.columns.borrow()),
+        )
+    }
+
+    /// Remove the first index level.
+    pub fn drop_first_level<'n>(&'c self) -> DataFrame<'n, 'n, 'c, I::Tail, C>
+    where
+        I::Tail: 'n,
+    {
+        self.with_index(self.index.drop_first_level())
+    }
+
+    /// Swap the first and second index levels.
+    pub fn swap_first_two<'n>(&'c self) -> DataFrame<'n, 'n, 'c, I::Swapped, C>
+    where
+        I::Swapped: 'n,
+    {
+        self.with_index(self.index.swap_first_two())
+    }
+
+    /// Group rows by the first index level.
+    pub fn groupby_first_level(&'i self) -> GroupBy<'i, DataFrame<'i, 'i, 'i, I, C>, I::Head>
+    where
+        I::Head: Ord,
+    {
+        let keys = self.index.get_level_head();
+        GroupBy::new(self, &keys)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: MultiLabel,
+{
+    /// Select columns whose first level is `head`, the level is removed.
+    pub fn xs_first_columns<'n>(&'i self, head: &C::Head) -> DataFrame<'n, 'i, 'n, I, C::Tail>
+    where
+        C::Tail: 'n,
+    {
+        let locs = self.columns.xs_first_locs(head);
+        let new_values: Vec<Cow<Array>> = locs.iter()
+            .map(|&loc| Cow::Owned(self.values[loc].clone().into_owned()))
+            .collect();
+        let new_columns = self.columns.ilocs(&locs).drop_first_level();
+        DataFrame::from_cow(
+            new_values,
+            Cow::Borrowed(self.index.borrow()),
+            Cow::Owned(new_columns),
+        )
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    fn with_index<'n, J>(&'c self, index: Indexer<J>) -> DataFrame<'n, 'n, 'c, J, C>
+    where
+        J: 'n + Clone + Eq + Hash,
+    {
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| Cow::Owned(x.clone().into_owned()))
+            .collect();
+        DataFrame::from_cow(
+            new_values,
+            Cow::Owned(index),
+            Cow::Borrowed(self.columns.borrow()),
+        )
+    }
+}
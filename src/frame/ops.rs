@@ -0,0 +1,462 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub, Div};
+
+use nullvec::prelude::{Array, Scalar};
+
+use super::DataFrame;
+use convert::{array_to_scalars, promote_dtype, scalars_to_array};
+use series::Series;
+use traits::RowIndex;
+
+/// Cast both arrays to their common dtype if they differ.
+fn promote_arrays<'a>(left: &'a Array, right: &'a Array) -> (Cow<'a, Array>, Cow<'a, Array>) {
+    let (ldtype, rdtype) = (left.dtype(), right.dtype());
+    if ldtype == rdtype {
+        return (Cow::Borrowed(left), Cow::Borrowed(right));
+    }
+    let dtype = promote_dtype(&ldtype, &rdtype)
+        .unwrap_or_else(|| panic!("unsupported dtypes: {} and {}", ldtype, rdtype));
+    let cast = |x: &Array| scalars_to_array(array_to_scalars(x), &dtype);
+    (Cow::Owned(cast(left)), Cow::Owned(cast(right)))
+}
+
+macro_rules! define_array_op {
+    ($m:ident, $array_m:ident) => {
+        /// Element-wise operation of numeric arrays, dtypes are promoted if
+        /// they differ.
+        fn $array_m(left: &Array, right: &Array) -> Array {
+            let (left, right) = promote_arrays(left, right);
+            match (&*left, &*right) {
+                (Array::Int64Array(l), Array::Int64Array(r)) => Array::Int64Array(l.$m(r)),
+                (Array::Int32Array(l), Array::Int32Array(r)) => Array::Int32Array(l.$m(r)),
+                (Array::Int16Array(l), Array::Int16Array(r)) => Array::Int16Array(l.$m(r)),
+                (Array::Int8Array(l), Array::Int8Array(r)) => Array::Int8Array(l.$m(r)),
+                (Array::IsizeArray(l), Array::IsizeArray(r)) => Array::IsizeArray(l.$m(r)),
+                (Array::UInt64Array(l), Array::UInt64Array(r)) => Array::UInt64Array(l.$m(r)),
+                (Array::UInt32Array(l), Array::UInt32Array(r)) => Array::UInt32Array(l.$m(r)),
+                (Array::UInt16Array(l), Array::UInt16Array(r)) => Array::UInt16Array(l.$m(r)),
+                (Array::UInt8Array(l), Array::UInt8Array(r)) => Array::UInt8Array(l.$m(r)),
+                (Array::UsizeArray(l), Array::UsizeArray(r)) => Array::UsizeArray(l.$m(r)),
+                (Array::Float64Array(l), Array::Float64Array(r)) => Array::Float64Array(l.$m(r)),
+                (Array::Float32Array(l), Array::Float32Array(r)) => Array::Float32Array(l.$m(r)),
+                (l, _) => panic!("unsupported dtype: {}", l.dtype()),
+            }
+        }
+    }
+}
+
+define_array_op!(add, add_arrays);
+define_array_op!(sub, sub_arrays);
+define_array_op!(mul, mul_arrays);
+define_array_op!(div, div_arrays);
+
+/// Absolute value of signed numeric `value`, others are returned as they are.
+fn abs_scalar(value: Scalar) -> Scalar {
+    match value {
+        Scalar::i64(v) => Scalar::i64(v.abs()),
+        Scalar::i32(v) => Scalar::i32(v.abs()),
+        Scalar::i16(v) => Scalar::i16(v.abs()),
+        Scalar::i8(v) => Scalar::i8(v.abs()),
+        Scalar::isize(v) => Scalar::isize(v.abs()),
+        Scalar::f64(v) => Scalar::f64(v.abs()),
+        Scalar::f32(v) => Scalar::f32(v.abs()),
+        other => other,
+    }
+}
+
+/// Array which repeats `value` `len` times.
+fn repeat_scalar(value: &Scalar, len: usize) -> Array {
+    scalars_to_array(vec![value.clone(); len], &value.dtype())
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    fn assert_binop(&self, other: &DataFrame<I, C>) {
+        assert!(self.index == other.index, "index must be the same!");
+        assert!(self.columns == other.columns, "columns must be identical");
+    }
+
+    /// Apply `func` to each numeric column with its location, other columns
+    /// are kept as they are.
+    fn map_numeric<'a, F>(&'a self, func: F) -> Vec<Cow<'a, Array>>
+    where
+        F: Fn(usize, &Array) -> Array,
+    {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(i, x)| if x.is_numeric() {
+                Cow::Owned(func(i, x))
+            } else {
+                Cow::Borrowed(&**x)
+            })
+            .collect()
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Absolute value of each numeric column, non-numeric columns are kept.
+    pub fn abs<'n>(&'n self) -> DataFrame<'n, 'n, 'n, I, C> {
+        let new_values = self.map_numeric(|_, x| {
+            let values: Vec<Scalar> = array_to_scalars(x).into_iter().map(abs_scalar).collect();
+            scalars_to_array(values, &x.dtype())
+        });
+        DataFrame::from_cow(new_values, Cow::Borrowed(&*self.index), Cow::Borrowed(&*self.columns))
+    }
+}
+
+macro_rules! define_frame_op {
+    ($t:ident, $m:ident, $array_m:ident) => {
+
+        impl<'lv, 'li, 'lc, 'rv, 'ri, 'rc, 'l, 'r, I, C> $t<&'r DataFrame<'rv, 'ri, 'rc, I, C>>
+            for &'l DataFrame<'lv, 'li, 'lc, I, C>
+        where
+            I: Clone + Eq + Hash,
+            C: Clone + Eq + Hash,
+        {
+            type Output = DataFrame<'l, 'l, 'l, I, C>;
+
+            /// Element-wise operation of all columns, index and columns must
+            /// be the same. Columns must be numeric.
+            fn $m(self, other: &'r DataFrame<I, C>) -> Self::Output {
+                self.assert_binop(other);
+                let new_values: Vec<Cow<Array>> = self.values
+                    .iter()
+                    .zip(&other.values)
+                    .map(|(l, r)| Cow::Owned($array_m(l, r)))
+                    .collect();
+                DataFrame::from_cow(
+                    new_values,
+                    Cow::Borrowed(&*self.index),
+                    Cow::Borrowed(&*self.columns),
+                )
+            }
+        }
+
+        impl<'lv, 'li, 'lc, 'rv, 'ri, 'rc, 'l, I, C> $t<DataFrame<'rv, 'ri, 'rc, I, C>>
+            for &'l DataFrame<'lv, 'li, 'lc, I, C>
+        where
+            I: Clone + Eq + Hash,
+            C: Clone + Eq + Hash,
+        {
+            type Output = DataFrame<'l, 'l, 'l, I, C>;
+
+            fn $m(self, other: DataFrame<I, C>) -> Self::Output {
+                self.$m(&other)
+            }
+        }
+
+        impl<'lv, 'li, 'lc, 'rv, 'ri, 'rc, 'r, I, C> $t<&'r DataFrame<'rv, 'ri, 'rc, I, C>>
+            for DataFrame<'lv, 'li, 'lc, I, C>
+        where
+            I: Clone + Eq + Hash,
+            C: Clone + Eq + Hash,
+        {
+            type Output = DataFrame<'lv, 'li, 'lc, I, C>;
+
+            fn $m(self, other: &'r DataFrame<I, C>) -> Self::Output {
+                self.assert_binop(other);
+                let new_values: Vec<Cow<Array>> = self.values
+                    .iter()
+                    .zip(&other.values)
+                    .map(|(l, r)| Cow::Owned($array_m(l, r)))
+                    .collect();
+                DataFrame::from_cow(new_values, self.index, self.columns)
+            }
+        }
+
+        impl<'lv, 'li, 'lc, 'rv, 'ri, 'rc, I, C> $t<DataFrame<'rv, 'ri, 'rc, I, C>>
+            for DataFrame<'lv, 'li, 'lc, I, C>
+        where
+            I: Clone + Eq + Hash,
+            C: Clone + Eq + Hash,
+        {
+            type Output = DataFrame<'lv, 'li, 'lc, I, C>;
+
+            fn $m(self, other: DataFrame<I, C>) -> Self::Output {
+                self.$m(&other)
+            }
+        }
+    }
+}
+
+define_frame_op!(Add, add, add_arrays);
+define_frame_op!(Sub, sub, sub_arrays);
+define_frame_op!(Mul, mul, mul_arrays);
+define_frame_op!(Div, div, div_arrays);
+
+macro_rules! define_frame_scalar_op {
+    ($t:ident, $m:ident, $array_m:ident) => {
+
+        // Broadcast
+        impl<'v, 'i, 'c, 'l, I, C, S> $t<S> for &'l DataFrame<'v, 'i, 'c, I, C>
+        where
+            I: Clone + Eq + Hash,
+            C: Clone + Eq + Hash,
+            S: Into<Scalar>,
+        {
+            type Output = DataFrame<'l, 'l, 'l, I, C>;
+
+            /// Operation of each numeric column with the scalar, non-numeric
+            /// columns are kept.
+            fn $m(self, other: S) -> Self::Output {
+                let other = repeat_scalar(&other.into(), self.len());
+                DataFrame::from_cow(
+                    self.map_numeric(|_, x| $array_m(x, &other)),
+                    Cow::Borrowed(&*self.index),
+                    Cow::Borrowed(&*self.columns),
+                )
+            }
+        }
+
+        impl<'v, 'i, 'c, I, C, S> $t<S> for DataFrame<'v, 'i, 'c, I, C>
+        where
+            I: Clone + Eq + Hash,
+            C: Clone + Eq + Hash,
+            S: Into<Scalar>,
+        {
+            type Output = DataFrame<'v, 'i, 'c, I, C>;
+
+            fn $m(self, other: S) -> Self::Output {
+                let other = repeat_scalar(&other.into(), self.len());
+                let new_values: Vec<Cow<Array>> = self.map_numeric(|_, x| $array_m(x, &other))
+                    .into_iter()
+                    .map(|x| Cow::Owned(x.into_owned()))
+                    .collect();
+                DataFrame::from_cow(new_values, self.index, self.columns)
+            }
+        }
+    }
+}
+
+define_frame_scalar_op!(Add, add, add_arrays);
+define_frame_scalar_op!(Sub, sub, sub_arrays);
+define_frame_scalar_op!(Mul, mul, mul_arrays);
+define_frame_scalar_op!(Div, div, div_arrays);
+
+macro_rules! define_frame_series_op {
+    ($rows_m:ident, $cols_m:ident, $array_m:ident) => {
+
+        impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+        where
+            I: Clone + Eq + Hash,
+            C: Clone + Eq + Hash,
+        {
+            /// Operation of each row with `other`, whose index must be the
+            /// same as columns. Non-numeric columns are kept.
+            pub fn $rows_m<'n, V>(&'n self, other: &Series<V, C>) -> DataFrame<'n, 'n, 'n, I, C>
+            where
+                V: Clone + Into<Scalar>,
+            {
+                assert!(*self.columns == *other.index, "index must be the same as columns");
+                let len = self.len();
+                let new_values = self.map_numeric(|i, x| {
+                    $array_m(x, &repeat_scalar(&other.values[i].clone().into(), len))
+                });
+                DataFrame::from_cow(
+                    new_values,
+                    Cow::Borrowed(&*self.index),
+                    Cow::Borrowed(&*self.columns),
+                )
+            }
+
+            /// Operation of each column with `other`, whose index must be the
+            /// same. Non-numeric columns are kept.
+            pub fn $cols_m<'n, V>(&'n self, other: &Series<V, I>) -> DataFrame<'n, 'n, 'n, I, C>
+            where
+                V: Clone,
+                Vec<V>: Into<Array>,
+            {
+                assert!(*self.index == *other.index, "index must be the same!");
+                let other = Array::new(other.values.to_vec());
+                DataFrame::from_cow(
+                    self.map_numeric(|_, x| $array_m(x, &other)),
+                    Cow::Borrowed(&*self.index),
+                    Cow::Borrowed(&*self.columns),
+                )
+            }
+        }
+    }
+}
+
+define_frame_series_op!(add_rows, add_cols, add_arrays);
+define_frame_series_op!(sub_rows, sub_cols, sub_arrays);
+define_frame_series_op!(mul_rows, mul_cols, mul_arrays);
+define_frame_series_op!(div_rows, div_cols, div_arrays);
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::Array;
+
+    use super::super::DataFrame;
+    use series::Series;
+
+    #[test]
+    fn test_frame_ops_refs() {
+        let l: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![1i64, 2]), Array::new(vec![1.5, 2.5])],
+            vec![10, 20],
+            vec!["X", "Y"],
+        );
+        let r: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![3i64, 4]), Array::new(vec![1i64, 2])],
+            vec![10, 20],
+            vec!["X", "Y"],
+        );
+
+        // operands are not moved
+        let res = &l + &r;
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![4i64, 6]), Array::new(vec![2.5, 4.5])],
+            vec![10, 20],
+            vec!["X", "Y"],
+        );
+        assert_eq!(res, exp);
+
+        let res = &l * &r;
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![3i64, 8]), Array::new(vec![1.5, 5.])],
+            vec![10, 20],
+            vec!["X", "Y"],
+        );
+        assert_eq!(res, exp);
+
+        let res = l.clone() - &r;
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![-2i64, -2]), Array::new(vec![0.5, 0.5])],
+            vec![10, 20],
+            vec!["X", "Y"],
+        );
+        assert_eq!(res, exp);
+
+        let res = &r / l.clone();
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![3i64, 2]), Array::new(vec![1. / 1.5, 2. / 2.5])],
+            vec![10, 20],
+            vec!["X", "Y"],
+        );
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_frame_abs() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![-1i32, 2]),
+                Array::new(vec!["-a", "b"]),
+                Array::new(vec![-1.5, -2.5]),
+                Array::new(vec![1u8, 2]),
+            ],
+            vec![10, 20],
+            vec!["X", "Y", "Z", "W"],
+        );
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![1i32, 2]),
+                Array::new(vec!["-a", "b"]),
+                Array::new(vec![1.5, 2.5]),
+                Array::new(vec![1u8, 2]),
+            ],
+            vec![10, 20],
+            vec!["X", "Y", "Z", "W"],
+        );
+        assert_eq!(df.abs(), exp);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_frame_ops_index_mismatch() {
+        let l: DataFrame<i64, &str> =
+            DataFrame::from_vec(vec![Array::new(vec![1i64, 2])], vec![10, 20], vec!["X"]);
+        let r: DataFrame<i64, &str> =
+            DataFrame::from_vec(vec![Array::new(vec![1i64, 2])], vec![10, 30], vec!["X"]);
+        let _ = &l + &r;
+    }
+
+    #[test]
+    fn test_frame_ops_scalar() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![1i64, 2]), Array::new(vec!["a", "b"]), Array::new(vec![1.5, 2.5])],
+            vec![10, 20],
+            vec!["X", "Y", "Z"],
+        );
+        let res = &df + 1i64;
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![2i64, 3]), Array::new(vec!["a", "b"]), Array::new(vec![2.5, 3.5])],
+            vec![10, 20],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(res, exp);
+
+        // integer columns are promoted
+        let res = df.clone() * 0.5;
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![0.5, 1.]),
+                Array::new(vec!["a", "b"]),
+                Array::new(vec![0.75, 1.25]),
+            ],
+            vec![10, 20],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(res, exp);
+
+        let res = &df - 1i64;
+        assert_eq!(res.dtypes(), vec!["i64", "str", "f64"]);
+        let res = df / 2.;
+        assert_eq!(res.dtypes(), vec!["f64", "str", "f64"]);
+    }
+
+    #[test]
+    fn test_frame_ops_series() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![1i64, 2]), Array::new(vec!["a", "b"]), Array::new(vec![1.5, 2.5])],
+            vec![10, 20],
+            vec!["X", "Y", "Z"],
+        );
+
+        let s: Series<i64, &str> = Series::new(vec![10, 20, 30], vec!["X", "Y", "Z"]);
+        let res = df.add_rows(&s);
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![11i64, 12]),
+                Array::new(vec!["a", "b"]),
+                Array::new(vec![31.5, 32.5]),
+            ],
+            vec![10, 20],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(res, exp);
+
+        let s: Series<f64, i64> = Series::new(vec![2., 4.], vec![10, 20]);
+        let res = df.div_cols(&s);
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![0.5, 0.5]),
+                Array::new(vec!["a", "b"]),
+                Array::new(vec![0.75, 0.625]),
+            ],
+            vec![10, 20],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_frame_ops_series_columns_mismatch() {
+        let df: DataFrame<i64, &str> =
+            DataFrame::from_vec(vec![Array::new(vec![1i64, 2])], vec![10, 20], vec!["X"]);
+        let s: Series<i64, &str> = Series::new(vec![10], vec!["Y"]);
+        let _ = df.mul_rows(&s);
+    }
+}
@@ -0,0 +1,121 @@
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Nullable};
+
+use super::DataFrame;
+use algos::correlation::{pearson, spearman, kendall, mutual_information};
+use convert::array_to_scalars;
+use traits::Metric;
+
+/// Values of numeric `array` as `f64`, `None` for nulls.
+fn to_floats(array: &Array) -> Vec<Option<f64>> {
+    array_to_scalars(array)
+        .iter()
+        .map(|x| match x.as_f64() {
+            Nullable::Value(v) if !v.is_nan() => Some(v),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Compute `metric` between `x` and `y` using rows where both are valid.
+fn measure(x: &[Option<f64>], y: &[Option<f64>], metric: Metric) -> f64 {
+    let (x, y): (Vec<f64>, Vec<f64>) = x.iter()
+        .zip(y)
+        .filter_map(|(a, b)| match (a, b) {
+            (&Some(a), &Some(b)) => Some((a, b)),
+            _ => None,
+        })
+        .unzip();
+    match metric {
+        Metric::Pearson => pearson(&x, &y),
+        Metric::Spearman => spearman(&x, &y),
+        Metric::Kendall => kendall(&x, &y),
+        Metric::MutualInformation => mutual_information(&x, &y),
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Compute `metric` between each pair of numeric columns, result is a
+    /// square frame labelled by the columns in both axes.
+    ///
+    /// Each pair uses rows where both values are not null.
+    pub fn pairwise<'n>(&self, metric: Metric) -> DataFrame<'n, 'n, 'n, C, C>
+    where
+        C: 'n,
+    {
+        let mut columns: Vec<C> = vec![];
+        let mut floats: Vec<Vec<Option<f64>>> = vec![];
+        for (column, values) in self.columns.values.iter().zip(&self.values) {
+            if values.is_numeric() {
+                columns.push(column.clone());
+                floats.push(to_floats(values));
+            }
+        }
+        let mut new_values: Vec<Vec<f64>> = vec![vec![0.; floats.len()]; floats.len()];
+        for i in 0..floats.len() {
+            for j in 0..i + 1 {
+                let value = measure(&floats[i], &floats[j], metric);
+                new_values[i][j] = value;
+                new_values[j][i] = value;
+            }
+        }
+        let new_values: Vec<Array> = new_values.into_iter().map(Array::new).collect();
+        DataFrame::from_vec(new_values, columns.clone(), columns)
+    }
+
+    /// Pearson correlation coefficients between numeric columns, shorthand
+    /// of `pairwise(Metric::Pearson)`.
+    pub fn corr<'n>(&self) -> DataFrame<'n, 'n, 'n, C, C>
+    where
+        C: 'n,
+    {
+        self.pairwise(Metric::Pearson)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec};
+
+    use super::super::DataFrame;
+    use traits::Metric;
+
+    #[test]
+    fn test_frame_pairwise() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![1i64, 2, 3, 4]),
+                Array::new(vec!["a", "b", "c", "d"]),
+                Array::new(vec![1., 8., 27., 64.]),
+                NullVec::with_mask(vec![4., 3., 0., 1.], Some(vec![false, false, true, false]))
+                    .into(),
+            ],
+            vec![1, 2, 3, 4],
+            vec!["X", "S", "Y", "Z"],
+        );
+
+        let exp: DataFrame<&str, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![1., 1., -1.]),
+                Array::new(vec![1., 1., -1.]),
+                Array::new(vec![-1., -1., 1.]),
+            ],
+            vec!["X", "Y", "Z"],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(df.pairwise(Metric::Spearman), exp);
+        assert_eq!(df.pairwise(Metric::Kendall), exp);
+
+        let res = df.corr();
+        assert_eq!(res.columns.values, vec!["X", "Y", "Z"]);
+        assert_eq!(res.index.values, vec!["X", "Y", "Z"]);
+        let res = df.pairwise(Metric::MutualInformation);
+        assert_eq!(res.index.values, vec!["X", "Y", "Z"]);
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+
+use super::DataFrame;
+use algos::key::{ScalarKey, row_keys};
+use convert::scalars_to_array;
+use indexer::Indexer;
+use traits::{Slicer, IndexerIndex};
+
+impl<'v, 'i, 'c, I> DataFrame<'v, 'i, 'c, I, String>
+where
+    I: Clone + Eq + Hash,
+{
+    /// Expand long panel data so that every combination of entity and time exists.
+    ///
+    /// Entities are distinct rows of `index_keys` in order of appearance, and
+    /// each entity gets a row for each value in `time_range`. Values of missing
+    /// rows are `fill_value`, which can be `Scalar::Null`. Rows whose time is not
+    /// in `time_range` are dropped, and only the first row is kept for duplicated
+    /// combinations. Index is reset.
+    pub fn complete<'a>(
+        &self,
+        index_keys: &[&str],
+        time_key: &str,
+        time_range: &Array,
+        fill_value: &Scalar,
+    ) -> DataFrame<'a, 'a, 'a, usize, String> {
+        let index_keys: Vec<String> = index_keys.iter().map(|x| x.to_string()).collect();
+        let time_key = time_key.to_string();
+        let keys: Vec<&Array> = index_keys
+            .iter()
+            .map(|x| &*self.values[self.columns.get_loc(x)])
+            .collect();
+        let times = &self.values[self.columns.get_loc(&time_key)];
+
+        // first location of each entity
+        let mut entities: Vec<usize> = vec![];
+        let mut entity_map: HashMap<Vec<ScalarKey>, usize> = HashMap::new();
+        let mut locs: HashMap<(usize, ScalarKey), usize> = HashMap::new();
+        for (i, key) in row_keys(&keys).into_iter().enumerate() {
+            let entity = *entity_map.entry(key).or_insert_with(|| {
+                entities.push(i);
+                entities.len() - 1
+            });
+            let time = ScalarKey::from(&times.iloc(&i));
+            locs.entry((entity, time)).or_insert(i);
+        }
+
+        let ntimes = time_range.len();
+        let mut entity_locs: Vec<usize> = Vec::with_capacity(entities.len() * ntimes);
+        let mut row_locs: Vec<usize> = Vec::with_capacity(entities.len() * ntimes);
+        for (entity, loc) in entities.iter().enumerate() {
+            for t in 0..ntimes {
+                let time = ScalarKey::from(&time_range.iloc(&t));
+                entity_locs.push(*loc);
+                row_locs.push(*locs.get(&(entity, time)).unwrap_or(&usize::MAX));
+            }
+        }
+
+        let mut new_values: Vec<Array> = Vec::with_capacity(self.values.len());
+        for (column, values) in self.columns.values.iter().zip(&self.values) {
+            let new_value: Vec<Scalar> = if index_keys.contains(column) {
+                entity_locs.iter().map(|x| values.iloc(x)).collect()
+            } else if *column == time_key {
+                (0..entity_locs.len())
+                    .map(|x| time_range.iloc(&(x % ntimes)))
+                    .collect()
+            } else {
+                row_locs
+                    .iter()
+                    .map(|x| if *x == usize::MAX {
+                        fill_value.clone()
+                    } else {
+                        values.iloc(x)
+                    })
+                    .collect()
+            };
+            new_values.push(scalars_to_array(new_value, &values.dtype()));
+        }
+        let index: Indexer<usize> = Indexer::<usize>::from_len(row_locs.len());
+        DataFrame::from_vec(new_values, index, self.columns.values.clone())
+    }
+}
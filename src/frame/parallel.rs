@@ -0,0 +1,70 @@
+use std::cmp;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+use rayon;
+use rayon::prelude::*;
+
+use super::{DataFrame, DataFrameView};
+use series::Series;
+use traits::Slicer;
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash + Send,
+    C: Clone + Eq + Hash + Send,
+{
+    /// Apply `func` to chunks of `size` rows in parallel, and concatenate its
+    /// results. Result has the same index as the original.
+    ///
+    /// The function must return a value for each row of the chunk.
+    pub fn par_apply_chunks<'n, W, F>(&self, size: usize, func: F) -> Series<'n, 'n, W, I>
+    where
+        I: 'n,
+        W: 'n + Clone + Send,
+        F: for<'a> Fn(&DataFrameView<'a, I, C>) -> Vec<W> + Sync + Send,
+    {
+        // Indexer can't be shared between threads, send components of chunks
+        let chunks: Vec<(Vec<Array>, Vec<I>, Vec<C>)> = self.chunks(size)
+            .map(|x| {
+                let values: Vec<Array> = x.values.into_iter().map(|v| v.into_owned()).collect();
+                (values, x.index.into_owned().values, self.columns.values.clone())
+            })
+            .collect();
+        let results: Vec<Vec<W>> = chunks
+            .into_par_iter()
+            .map(|(values, index, columns)| {
+                let chunk = DataFrame::from_vec(values, index, columns);
+                let values = func(&chunk);
+                assert!(
+                    values.len() == chunk.index.len(),
+                    "Function must return the same length as the chunk"
+                );
+                values
+            })
+            .collect();
+        let new_values: Vec<W> = results.into_iter().flat_map(|x| x.into_iter()).collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Apply `func` to values of each row in parallel. Result has the same
+    /// index as the original.
+    pub fn par_apply_rows<'n, W, F>(&self, func: F) -> Series<'n, 'n, W, I>
+    where
+        I: 'n,
+        W: 'n + Clone + Send,
+        F: Fn(&[Scalar]) -> W + Sync + Send,
+    {
+        // split into some chunks per thread to balance loads
+        let nthreads = rayon::current_num_threads();
+        let size = cmp::max(self.index.len() / (nthreads * 4), 1);
+        self.par_apply_chunks(size, |chunk| {
+            (0..chunk.index.len())
+                .map(|i| {
+                    let row: Vec<Scalar> = chunk.values.iter().map(|x| x.iloc(&i)).collect();
+                    func(&row)
+                })
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,114 @@
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use algos::linalg::symmetric_eigen;
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Principal component analysis of numeric columns, keeping the first
+    /// `n_components` components named "PC1", "PC2" and so on.
+    ///
+    /// Returns loadings labelled by numeric columns and components, and
+    /// centered rows projected onto the components labelled by the index.
+    /// Components are eigenvectors of the unbiased covariance matrix in the
+    /// order of explained variance. Nulls must be removed or filled beforehand.
+    pub fn pca<'n>(
+        &self,
+        n_components: usize,
+    ) -> (DataFrame<'n, 'n, 'n, C, String>, DataFrame<'n, 'n, 'n, I, String>)
+    where
+        I: 'n,
+        C: 'n,
+    {
+        let (columns, rows) = self.numeric_rows("pca");
+        let dim = columns.len();
+        assert!(n_components <= dim, "n_components must not exceed the number of numeric columns");
+        assert!(rows.len() > 1, "pca requires at least 2 rows");
+
+        let n = rows.len() as f64;
+        let means: Vec<f64> = (0..dim)
+            .map(|j| rows.iter().map(|x| x[j]).sum::<f64>() / n)
+            .collect();
+        let centered: Vec<Vec<f64>> = rows.iter()
+            .map(|x| x.iter().zip(&means).map(|(v, m)| v - m).collect())
+            .collect();
+        let covariance: Vec<Vec<f64>> = (0..dim)
+            .map(|a| {
+                (0..dim)
+                    .map(|b| centered.iter().map(|x| x[a] * x[b]).sum::<f64>() / (n - 1.))
+                    .collect()
+            })
+            .collect();
+        let (_, vectors) = symmetric_eigen(&covariance);
+
+        let names: Vec<String> = (1..n_components + 1).map(|x| format!("PC{}", x)).collect();
+        let loadings: Vec<Array> = vectors[..n_components]
+            .iter()
+            .map(|x| Array::new(x.clone()))
+            .collect();
+        let projected: Vec<Array> = vectors[..n_components]
+            .iter()
+            .map(|v| {
+                let values: Vec<f64> = centered
+                    .iter()
+                    .map(|x| x.iter().zip(v).map(|(a, b)| a * b).sum())
+                    .collect();
+                Array::new(values)
+            })
+            .collect();
+        (
+            DataFrame::from_vec(loadings, columns, names.clone()),
+            DataFrame::from_vec(projected, self.index.values.clone(), names),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, Scalar};
+
+    use super::super::DataFrame;
+    use traits::{Slicer, ColIndex};
+
+    #[test]
+    fn test_frame_pca() {
+        // points on the line y = x
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![-2., -1., 0., 1., 2.]),
+                Array::new(vec!["a", "b", "c", "d", "e"]),
+                Array::new(vec![-2., -1., 0., 1., 2.]),
+            ],
+            vec![1, 2, 3, 4, 5],
+            vec!["X", "S", "Y"],
+        );
+        let (loadings, projected) = df.pca(1);
+        assert_eq!(loadings.index.values, vec!["X", "Y"]);
+        assert_eq!(loadings.columns.values, vec!["PC1".to_string()]);
+        let h = 0.5f64.sqrt();
+        for i in 0..2 {
+            match loadings.get(&"PC1".to_string()).iloc(&i) {
+                Scalar::f64(v) => assert!((v - h).abs() < 1e-12),
+                _ => panic!("PC1 must be f64"),
+            }
+        }
+
+        assert_eq!(projected.index, df.index);
+        let exp = [-4. * h, -2. * h, 0., 2. * h, 4. * h];
+        for (i, e) in exp.iter().enumerate() {
+            match projected.get(&"PC1".to_string()).iloc(&i) {
+                Scalar::f64(v) => assert!((v - e).abs() < 1e-12),
+                _ => panic!("PC1 must be f64"),
+            }
+        }
+        let (loadings, projected) = df.pca(2);
+        assert_eq!(loadings.columns.values, vec!["PC1".to_string(), "PC2".to_string()]);
+        assert_eq!(projected.columns.len(), 2);
+    }
+}
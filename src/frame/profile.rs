@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Nullable, NullVec, Scalar};
+
+use super::DataFrame;
+use algos::key::ScalarKey;
+use convert::array_to_scalars;
+
+/// Number of the most frequent values listed by `DataFrame::profile`.
+const PROFILE_TOP: usize = 3;
+
+/// Whether `c` is a control character other than whitespace, or the
+/// replacement character left by invalid encoding.
+fn is_anomaly(c: char) -> bool {
+    c == '\u{FFFD}' || (c.is_control() && !c.is_whitespace())
+}
+
+/// Statistics of a column computed by `DataFrame::profile`.
+struct ColumnProfile {
+    dtype: String,
+    count: usize,
+    nulls: usize,
+    distinct: usize,
+    min: Nullable<String>,
+    max: Nullable<String>,
+    top: Nullable<String>,
+    whitespace: Nullable<usize>,
+    anomalies: Nullable<usize>,
+}
+
+impl ColumnProfile {
+    fn new(array: &Array) -> Self {
+        let scalars = array_to_scalars(array);
+        // count and the first value of each key, values are formatted as they
+        // are rather than as keys which are widened
+        let mut counts: HashMap<ScalarKey, (usize, &Scalar)> = HashMap::new();
+        let mut nulls = 0;
+        let mut whitespace = 0;
+        let mut anomalies = 0;
+        for value in &scalars {
+            match *value {
+                Scalar::Null => {
+                    nulls += 1;
+                    continue;
+                }
+                Scalar::String(ref s) => {
+                    if s.trim() != s {
+                        whitespace += 1;
+                    }
+                    if s.chars().any(is_anomaly) {
+                        anomalies += 1;
+                    }
+                }
+                _ => {}
+            }
+            counts.entry(ScalarKey::from(value)).or_insert((0, value)).0 += 1;
+        }
+
+        let mut frequent: Vec<(&ScalarKey, &(usize, &Scalar))> = counts.iter().collect();
+        frequent.sort_by(|a, b| (b.1).0.cmp(&(a.1).0).then(a.0.cmp(b.0)));
+        let top = if frequent.is_empty() {
+            Nullable::Null
+        } else {
+            let top: Vec<String> = frequent
+                .iter()
+                .take(PROFILE_TOP)
+                .map(|&(_, &(c, v))| format!("{} ({})", v, c))
+                .collect();
+            Nullable::Value(top.join(", "))
+        };
+        let to_string = |x: Option<&ScalarKey>| {
+            x.map_or(Nullable::Null, |k| Nullable::Value(counts[k].1.to_string()))
+        };
+
+        let dtype = array.dtype();
+        let (whitespace, anomalies) = if dtype == "str" {
+            (Nullable::Value(whitespace), Nullable::Value(anomalies))
+        } else {
+            (Nullable::Null, Nullable::Null)
+        };
+        ColumnProfile {
+            dtype,
+            count: scalars.len() - nulls,
+            nulls,
+            distinct: counts.len(),
+            min: to_string(counts.keys().min()),
+            max: to_string(counts.keys().max()),
+            top,
+            whitespace,
+            anomalies,
+        }
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Report of each column, labelled by columns of the original frame.
+    ///
+    /// Report has dtype, count of non-null values, nulls, distinct non-null
+    /// values, min and max as strings, and the most frequent values as
+    /// "value (count)". For string columns, it also counts values having
+    /// leading or trailing whitespace, and values containing control or
+    /// replacement characters as anomalies. These are null for other columns.
+    pub fn profile<'n>(&self) -> DataFrame<'n, 'n, 'n, C, &'static str>
+    where
+        C: 'n,
+    {
+        let profiles: Vec<ColumnProfile> =
+            self.values.iter().map(|x| ColumnProfile::new(x)).collect();
+        let dtypes: NullVec<String> =
+            profiles.iter().map(|x| Nullable::Value(x.dtype.clone())).collect();
+        let counts: NullVec<usize> = profiles.iter().map(|x| Nullable::Value(x.count)).collect();
+        let nulls: NullVec<usize> = profiles.iter().map(|x| Nullable::Value(x.nulls)).collect();
+        let distinct: NullVec<usize> =
+            profiles.iter().map(|x| Nullable::Value(x.distinct)).collect();
+        let mins: NullVec<String> = profiles.iter().map(|x| x.min.clone()).collect();
+        let maxs: NullVec<String> = profiles.iter().map(|x| x.max.clone()).collect();
+        let tops: NullVec<String> = profiles.iter().map(|x| x.top.clone()).collect();
+        let whitespace: NullVec<usize> = profiles.iter().map(|x| x.whitespace.clone()).collect();
+        let anomalies: NullVec<usize> = profiles.iter().map(|x| x.anomalies.clone()).collect();
+
+        let new_values: Vec<Array> = vec![
+            dtypes.into(),
+            counts.into(),
+            nulls.into(),
+            distinct.into(),
+            mins.into(),
+            maxs.into(),
+            tops.into(),
+            whitespace.into(),
+            anomalies.into(),
+        ];
+        let new_columns = vec![
+            "dtype", "count", "nulls", "distinct", "min", "max", "top", "whitespace", "anomalies",
+        ];
+        DataFrame::from_vec(new_values, self.columns.values.clone(), new_columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, Nullable, NullVec};
+
+    use super::super::DataFrame;
+    use traits::ColIndex;
+
+    #[test]
+    fn test_profile() {
+        let mask = vec![false, false, false, true, false];
+        let x: Array = NullVec::with_mask(vec![3i64, 1, 3, 0, 2], Some(mask)).into();
+        let y = Array::new(vec!["a", " b", "a\u{FFFD}", "a", "c\u{7}"]);
+        let df: DataFrame<i64, &str> =
+            DataFrame::from_vec(vec![x, y], vec![1, 2, 3, 4, 5], vec!["X", "Y"]);
+        let res = df.profile();
+        assert_eq!(res.index.values, vec!["X", "Y"]);
+        assert_eq!(res.get(&"dtype"), Array::new(vec!["i64", "str"]));
+        assert_eq!(res.get(&"count"), Array::new(vec![4usize, 5]));
+        assert_eq!(res.get(&"nulls"), Array::new(vec![1usize, 0]));
+        assert_eq!(res.get(&"distinct"), Array::new(vec![3usize, 4]));
+        assert_eq!(res.get(&"min"), Array::new(vec!["1", " b"]));
+        assert_eq!(res.get(&"max"), Array::new(vec!["3", "c\u{7}"]));
+        let exp = Array::new(vec!["3 (2), 1 (1), 2 (1)", "a (2),  b (1), a\u{FFFD} (1)"]);
+        assert_eq!(res.get(&"top"), exp);
+        let exp: NullVec<usize> = vec![Nullable::Null, Nullable::Value(1)].into();
+        assert_eq!(res.get(&"whitespace"), exp.into());
+        let exp: NullVec<usize> = vec![Nullable::Null, Nullable::Value(2)].into();
+        assert_eq!(res.get(&"anomalies"), exp.into());
+    }
+
+    #[test]
+    fn test_profile_formats_values() {
+        let x = Array::new(vec![u64::MAX, 1, u64::MAX]);
+        let y = Array::new(vec![0.1f32, 0.1, 0.5]);
+        let df: DataFrame<i64, &str> =
+            DataFrame::from_vec(vec![x, y], vec![1, 2, 3], vec!["X", "Y"]);
+        let res = df.profile();
+        let max = u64::MAX.to_string();
+        assert_eq!(res.get(&"min"), Array::new(vec!["1", "0.1"]));
+        assert_eq!(res.get(&"max"), Array::new(vec![max.as_str(), "0.5"]));
+        let top = format!("{} (2), 1 (1)", max);
+        assert_eq!(res.get(&"top"), Array::new(vec![top.as_str(), "0.1 (2), 0.5 (1)"]));
+    }
+}
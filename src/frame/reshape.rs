@@ -1,15 +1,41 @@
 use std::borrow::{Borrow, Cow};
+use std::collections::HashMap;
+use std::fmt::Debug;
 use std::hash::Hash;
+use std::ops::Sub;
 
-use nullvec::prelude::Array;
+use nullvec::prelude::{Array, Scalar};
 use nullvec::prelude::Append as NullVecAppend;
 
 use super::DataFrame;
-use algos::join::{JoinOp, HashJoin};
+use super::groupby::{aggregate_by, aggregated_dtype};
+use algos::join::{JoinOp, HashJoin, SortMergeJoin, asof_locs};
+use algos::key::ScalarKey;
+use convert::{array_to_scalars, promote_dtype, scalars_to_array};
+use error::Error;
 use indexer::Indexer;
-use traits::{Slicer, RowIndex, Append, Concatenation, Join};
+use traits::{Slicer, IndexerIndex, RowIndex, Append, Concatenation, Join, How, JoinMethod,
+             Direction, Agg};
 
 
+/// Dtype which appended arrays are promoted to by `promote_dtype`, or `None`
+/// if dtypes are incompatible. An array with only nulls takes the dtype of
+/// the other.
+fn appended_dtype(left: &Array, right: &Array) -> Option<String> {
+    let (ldtype, rdtype) = (left.dtype(), right.dtype());
+    let is_null = |x: &Array| (0..x.len()).all(|i| matches!(x.iloc(&i), Scalar::Null));
+    if ldtype == rdtype || is_null(right) {
+        Some(ldtype)
+    } else if is_null(left) {
+        Some(rdtype)
+    } else {
+        promote_dtype(&ldtype, &rdtype)
+    }
+}
+
+/// Append rows, dtypes of the same column are promoted if they differ.
+///
+/// Panics if columns are different or dtypes of a column can't be promoted.
 impl<'v, 'i, 'c, I, C> Append<'c> for DataFrame<'v, 'i, 'c, I, C>
 where
     I: Clone + Eq + Hash,
@@ -22,7 +48,16 @@ where
 
         let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(self.columns.len());
         for (svalues, ovalues) in self.values.iter().zip(&other.values) {
-            let new_value = svalues.append(ovalues);
+            let new_value = if svalues.dtype() == ovalues.dtype() {
+                svalues.append(ovalues)
+            } else {
+                let dtype = appended_dtype(svalues, ovalues).unwrap_or_else(|| {
+                    panic!("dtypes can't be promoted: {} and {}", svalues.dtype(), ovalues.dtype())
+                });
+                let mut values = array_to_scalars(svalues);
+                values.extend(array_to_scalars(ovalues));
+                scalars_to_array(values, &dtype)
+            };
             new_values.push(Cow::Owned(new_value));
         }
         DataFrame::from_cow(
@@ -82,4 +117,311 @@ where
             Cow::Owned(new_columns),
         )
     }
+
+    fn join_left(&self, other: &Self) -> Self {
+        let (new_index, lindexer, rindexer) =
+            HashJoin::left(&self.index.values, &other.index.values);
+        self.join_by_indexer(other, new_index, &lindexer, &rindexer)
+    }
+
+    fn join_right(&self, other: &Self) -> Self {
+        let (new_index, lindexer, rindexer) =
+            HashJoin::right(&self.index.values, &other.index.values);
+        self.join_by_indexer(other, new_index, &lindexer, &rindexer)
+    }
+
+    fn join_outer(&self, other: &Self) -> Self {
+        let (new_index, lindexer, rindexer) =
+            HashJoin::outer(&self.index.values, &other.index.values);
+        self.join_by_indexer(other, new_index, &lindexer, &rindexer)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Join using locations of each side, out of bounds locations are filled with nulls
+    fn join_by_indexer(
+        &self,
+        other: &Self,
+        new_index: Vec<I>,
+        lindexer: &[usize],
+        rindexer: &[usize],
+    ) -> Self {
+        let new_columns = self.columns.append(&other.columns);
+
+        let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(new_columns.len());
+        for values in &self.values {
+            new_values.push(Cow::Owned(values.ilocs_forced(lindexer)));
+        }
+        for values in &other.values {
+            new_values.push(Cow::Owned(values.ilocs_forced(rindexer)));
+        }
+
+        DataFrame::from_cow(
+            new_values,
+            Cow::Owned(Indexer::new(new_index)),
+            Cow::Owned(new_columns),
+        )
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash + Ord,
+    C: Clone + Eq + Hash,
+{
+    /// Join by index using the specified algorithm.
+    ///
+    /// `JoinMethod::Auto` uses sort-merge join if both index are sorted,
+    /// which avoids building a hash table. Sort-merge join returns rows
+    /// ordered by labels.
+    pub fn join_with(&self, other: &Self, how: How, method: JoinMethod) -> Self {
+        let sorted = match method {
+            JoinMethod::Auto => {
+                self.index.is_monotonic_increasing() && other.index.is_monotonic_increasing()
+            }
+            JoinMethod::Hash => false,
+            JoinMethod::SortMerge => true,
+        };
+        if !sorted {
+            return self.join(other, how);
+        }
+        let (left, right) = (&self.index.values, &other.index.values);
+        let (new_index, lindexer, rindexer) = match how {
+            How::Inner => SortMergeJoin::inner(left, right),
+            How::Left => SortMergeJoin::left(left, right),
+            How::Right => SortMergeJoin::right(left, right),
+            How::Outer => SortMergeJoin::outer(left, right),
+        };
+        self.join_by_indexer(other, new_index, &lindexer, &rindexer)
+    }
+
+    /// Join each row with the row of `other` whose label is nearest in
+    /// `direction`, keeping the index of `self`.
+    ///
+    /// Labels farther than `tolerance` are not matched and filled with nulls.
+    pub fn join_asof(&self, other: &Self, tolerance: Option<I>, direction: Direction) -> Self
+    where
+        I: Sub<Output = I>,
+    {
+        let rindexer = asof_locs(&self.index.values, &other.index.values, tolerance, direction);
+        let lindexer: Vec<usize> = (0..self.index.len()).collect();
+        self.join_by_indexer(other, self.index.values.clone(), &lindexer, &rindexer)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Rows of `other` whose label equals the value of `column` in each row,
+    /// labelled by the index of `self`. Rows without matching label are null.
+    ///
+    /// Labels and values are compared as `ScalarKey`, index of `other` must
+    /// be unique.
+    pub fn reindex_on<'n, J, D>(
+        &self,
+        column: &C,
+        other: &DataFrame<J, D>,
+    ) -> DataFrame<'n, 'n, 'n, I, D>
+    where
+        I: 'n,
+        J: Clone + Eq + Hash + Into<Scalar>,
+        D: 'n + Clone + Eq + Hash,
+    {
+        assert!(other.index.is_unique(), "index of other must be unique");
+        let locs: HashMap<ScalarKey, usize> = other.index
+            .values
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (ScalarKey::from(&x.clone().into()), i))
+            .collect();
+        let keys = array_to_scalars(&self.values[self.columns.get_loc(column)]);
+        let indexer: Vec<usize> = keys.iter()
+            .map(|x| *locs.get(&ScalarKey::from(x)).unwrap_or(&usize::MAX))
+            .collect();
+        let new_values: Vec<Array> =
+            other.values.iter().map(|x| x.ilocs_forced(&indexer)).collect();
+        DataFrame::from_vec(
+            new_values,
+            self.index.clone().into_owned(),
+            other.columns.clone().into_owned(),
+        )
+    }
+
+    /// Reshape long data to wide, index and columns are distinct values of
+    /// `index` and `columns` column in sorted order.
+    ///
+    /// Values of `values` column are aggregated by `aggfunc` for each cell,
+    /// and cells without values are null.
+    pub fn pivot_table<'n>(
+        &self,
+        index: &C,
+        columns: &C,
+        values: &C,
+        aggfunc: Agg,
+    ) -> DataFrame<'n, 'n, 'n, ScalarKey, ScalarKey> {
+        self.pivot_table_with(index, columns, values, aggfunc, false)
+    }
+
+    /// Same as `pivot_table`, adding a row and a column labelled "All" which
+    /// aggregate values of each column and row if `margins`.
+    pub fn pivot_table_with<'n>(
+        &self,
+        index: &C,
+        columns: &C,
+        values: &C,
+        aggfunc: Agg,
+        margins: bool,
+    ) -> DataFrame<'n, 'n, 'n, ScalarKey, ScalarKey> {
+        let values = &self.values[self.columns.get_loc(values)];
+        let dtype = aggregated_dtype(&values.dtype(), aggfunc);
+        self.pivot_cells(index, columns, margins, &dtype, Scalar::Null, |locs| {
+            aggregate_by(&values.ilocs(locs), aggfunc)
+        })
+    }
+
+    /// Number of rows of each pair of distinct values of `index` and
+    /// `columns` column in sorted order, 0 if no rows.
+    ///
+    /// If `margins`, a row and a column labelled "All" have the number of
+    /// rows of each column and row.
+    pub fn crosstab<'n>(
+        &self,
+        index: &C,
+        columns: &C,
+        margins: bool,
+    ) -> DataFrame<'n, 'n, 'n, ScalarKey, ScalarKey> {
+        self.pivot_cells(index, columns, margins, "usize", Scalar::usize(0), |locs| {
+            Scalar::usize(locs.len())
+        })
+    }
+
+    /// Compute `func` from row locations of each cell of `index` and
+    /// `columns`, cells without rows are `empty`.
+    fn pivot_cells<'n, F>(
+        &self,
+        index: &C,
+        columns: &C,
+        margins: bool,
+        dtype: &str,
+        empty: Scalar,
+        func: F,
+    ) -> DataFrame<'n, 'n, 'n, ScalarKey, ScalarKey>
+    where
+        F: Fn(&[usize]) -> Scalar,
+    {
+        let row_keys = &self.values[self.columns.get_loc(index)];
+        let col_keys = &self.values[self.columns.get_loc(columns)];
+
+        let mut cells: HashMap<(ScalarKey, ScalarKey), Vec<usize>> = HashMap::new();
+        let mut row_margins: HashMap<ScalarKey, Vec<usize>> = HashMap::new();
+        let mut col_margins: HashMap<ScalarKey, Vec<usize>> = HashMap::new();
+        for i in 0..self.len() {
+            let row = ScalarKey::from(&row_keys.iloc(&i));
+            let column = ScalarKey::from(&col_keys.iloc(&i));
+            if margins {
+                row_margins.entry(row.clone()).or_default().push(i);
+                col_margins.entry(column.clone()).or_default().push(i);
+            }
+            cells.entry((row, column)).or_default().push(i);
+        }
+        let mut new_index: Vec<ScalarKey> = cells.keys().map(|x| x.0.clone()).collect();
+        new_index.sort();
+        new_index.dedup();
+        let mut new_columns: Vec<ScalarKey> = cells.keys().map(|x| x.1.clone()).collect();
+        new_columns.sort();
+        new_columns.dedup();
+
+        let cell = |locs: Option<&Vec<usize>>| match locs {
+            Some(locs) => func(locs),
+            None => empty.clone(),
+        };
+        let all: Vec<usize> = (0..self.len()).collect();
+        let mut new_values: Vec<Array> = Vec::with_capacity(new_columns.len() + 1);
+        for column in &new_columns {
+            let mut cell_values: Vec<Scalar> = new_index
+                .iter()
+                .map(|row| cell(cells.get(&(row.clone(), column.clone()))))
+                .collect();
+            if margins {
+                cell_values.push(cell(col_margins.get(column)));
+            }
+            new_values.push(scalars_to_array(cell_values, dtype));
+        }
+        if margins {
+            let mut cell_values: Vec<Scalar> =
+                new_index.iter().map(|row| cell(row_margins.get(row))).collect();
+            cell_values.push(cell(Some(&all)));
+            new_values.push(scalars_to_array(cell_values, dtype));
+            new_index.push(ScalarKey::Str("All".to_string()));
+            new_columns.push(ScalarKey::Str("All".to_string()));
+        }
+        DataFrame::from_vec(new_values, new_index, new_columns)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + Debug,
+{
+    /// Append rows, or return `Error::ColumnsMismatch` if columns are different
+    /// and `Error::DtypeMismatch` if dtypes of a column can't be promoted
+    pub fn try_append<'o>(&'c self, other: &'o Self) -> Result<Self, Error> {
+        self.check_append(other, |l, r| appended_dtype(l, r).is_some())?;
+        Ok(self.append(other))
+    }
+
+    /// Append rows without dtype promotion, or return `Error::DtypeMismatch`
+    /// if dtypes of any column differ
+    pub fn try_append_strict<'o>(&'c self, other: &'o Self) -> Result<Self, Error> {
+        self.check_append(other, |l, r| l.dtype() == r.dtype())?;
+        Ok(self.append(other))
+    }
+
+    fn check_append<F>(&self, other: &Self, compatible: F) -> Result<(), Error>
+    where
+        F: Fn(&Array, &Array) -> bool,
+    {
+        if self.columns != other.columns {
+            return Err(Error::ColumnsMismatch);
+        }
+        let columns = self.columns.values.iter().zip(&self.values).zip(&other.values);
+        for ((column, svalues), ovalues) in columns {
+            if !compatible(svalues, ovalues) {
+                return Err(Error::DtypeMismatch {
+                    column: format!("{:?}", column),
+                    left: svalues.dtype(),
+                    right: ovalues.dtype(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Concatenate columns, or return `Error::IndexMismatch` if index are different
+    /// and `Error::DuplicateLabel` if any column is shared
+    pub fn try_concat<'o>(&'i self, other: &'o Self) -> Result<Self, Error> {
+        if self.index != other.index {
+            return Err(Error::IndexMismatch);
+        }
+        self.columns.try_append(&other.columns)?;
+        Ok(self.concat(other))
+    }
+
+    /// Inner join by index, or return `Error::DuplicateLabel` if any column is shared
+    pub fn try_join_inner(&self, other: &Self) -> Result<Self, Error> {
+        self.try_join(other, How::Inner)
+    }
+
+    /// Join by index, or return `Error::DuplicateLabel` if any column is shared
+    pub fn try_join(&self, other: &Self, how: How) -> Result<Self, Error> {
+        self.columns.try_append(&other.columns)?;
+        Ok(self.join(other, how))
+    }
 }
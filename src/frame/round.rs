@@ -0,0 +1,143 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+
+use super::DataFrame;
+use convert::{array_to_scalars, scalars_to_array};
+
+/// Round `value` to `decimals` decimal places, negative `decimals` rounds to
+/// tens, hundreds and so on. Halves are rounded away from zero.
+fn round_decimals(value: f64, decimals: i32) -> f64 {
+    let scale = 10f64.powi(decimals);
+    (value * scale).round() / scale
+}
+
+/// Round `value` to `figures` significant figures.
+fn round_significant(value: f64, figures: u32) -> f64 {
+    if value == 0. || !value.is_finite() {
+        return value;
+    }
+    let magnitude = value.abs().log10().floor() as i32;
+    round_decimals(value, figures as i32 - 1 - magnitude)
+}
+
+/// Apply `func` to values of float array, other arrays are cloned.
+fn map_float<F>(array: &Array, func: F) -> Array
+where
+    F: Fn(f64) -> f64,
+{
+    let dtype = array.dtype();
+    if dtype != "f64" && dtype != "f32" {
+        return array.clone();
+    }
+    let values: Vec<Scalar> = array_to_scalars(array)
+        .into_iter()
+        .map(|x| match x {
+            Scalar::f64(v) => Scalar::f64(func(v)),
+            Scalar::f32(v) => Scalar::f32(func(f64::from(v)) as f32),
+            other => other,
+        })
+        .collect();
+    scalars_to_array(values, &dtype)
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Apply `func` with its parameter to float columns found in `params`.
+    fn map_float_columns<T, F>(&self, params: &HashMap<C, T>, func: F) -> Self
+    where
+        T: Copy,
+        F: Fn(f64, T) -> f64,
+    {
+        let new_values: Vec<Cow<Array>> = self.columns
+            .values
+            .iter()
+            .zip(&self.values)
+            .map(|(c, x)| match params.get(c) {
+                Some(&p) => Cow::Owned(map_float(x, |v| func(v, p))),
+                None => Cow::Owned(x.clone().into_owned()),
+            })
+            .collect();
+        DataFrame::from_cow(new_values, self.index.clone(), self.columns.clone())
+    }
+
+    /// Round float columns to the number of decimal places given per column.
+    ///
+    /// Negative decimals round to tens, hundreds and so on. Columns not found
+    /// in `decimals` and non-float columns are kept as they are.
+    pub fn round(&self, decimals: &HashMap<C, i32>) -> Self {
+        self.map_float_columns(decimals, round_decimals)
+    }
+
+    /// Round float columns to the number of significant figures given per
+    /// column. Columns not found in `figures` and non-float columns are kept
+    /// as they are.
+    pub fn round_sig(&self, figures: &HashMap<C, u32>) -> Self {
+        assert!(figures.values().all(|&x| x > 0), "significant figures must be positive");
+        self.map_float_columns(figures, round_significant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashMap;
+
+    use nullvec::prelude::{Array, NullVec};
+
+    use super::super::DataFrame;
+    use super::{round_decimals, round_significant};
+    use traits::ColIndex;
+
+    #[test]
+    fn test_round_values() {
+        assert_eq!(round_decimals(1.25, 1), 1.3);
+        assert_eq!(round_decimals(-1.25, 1), -1.3);
+        assert_eq!(round_decimals(1234.5, -2), 1200.);
+        assert_eq!(round_significant(123456., 2), 120000.);
+        assert_eq!(round_significant(0.0012345, 3), 0.00123);
+        assert_eq!(round_significant(-98.7, 1), -100.);
+        assert_eq!(round_significant(0., 3), 0.);
+    }
+
+    #[test]
+    fn test_frame_round() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![1.234, 5.678]),
+                NullVec::with_mask(vec![1.55f32, 0.], Some(vec![false, true])).into(),
+                Array::new(vec![1i64, 2]),
+                Array::new(vec![1.234, 5.678]),
+            ],
+            vec![10, 20],
+            vec!["X", "Y", "Z", "W"],
+        );
+        let mut decimals = HashMap::new();
+        decimals.insert("X", 1);
+        decimals.insert("Y", 0);
+        decimals.insert("Z", -1);
+        let res = df.round(&decimals);
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![1.2, 5.7]),
+                NullVec::with_mask(vec![2f32, 0.], Some(vec![false, true])).into(),
+                Array::new(vec![1i64, 2]),
+                Array::new(vec![1.234, 5.678]),
+            ],
+            vec![10, 20],
+            vec!["X", "Y", "Z", "W"],
+        );
+        assert_eq!(res, exp);
+
+        let mut figures = HashMap::new();
+        figures.insert("W", 2);
+        let res = df.round_sig(&figures);
+        assert_eq!(res.get(&"W"), Array::new(vec![1.2, 5.7]));
+        assert_eq!(res.get(&"X"), Array::new(vec![1.234, 5.678]));
+    }
+}
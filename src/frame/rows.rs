@@ -0,0 +1,163 @@
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+
+use super::DataFrame;
+use convert::cast_scalar;
+use traits::{Slicer, IndexerIndex, RowIndex};
+
+/// Row of `DataFrame` passed to the predicate of `DataFrame::filter_rows`.
+pub struct RowView<'a, 'v: 'a, 'i: 'a, 'c: 'a, I, C>
+where
+    I: 'i + Clone + Hash,
+    C: 'c + Clone + Hash,
+{
+    frame: &'a DataFrame<'v, 'i, 'c, I, C>,
+    location: usize,
+}
+
+impl<'a, 'v, 'i, 'c, I, C> RowView<'a, 'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Index label of the row.
+    pub fn label(&self) -> &I {
+        &self.frame.index.values[self.location]
+    }
+
+    /// Location of the row.
+    pub fn location(&self) -> usize {
+        self.location
+    }
+
+    /// Value of `column` as `Scalar`, `None` if the column doesn't exist.
+    pub fn get_scalar(&self, column: &C) -> Option<Scalar> {
+        if !self.frame.columns.contains(column) {
+            return None;
+        }
+        let loc = self.frame.columns.get_loc(column);
+        Some(self.frame.values[loc].iloc(&self.location))
+    }
+
+    /// Value of `column` as `T`, `None` if the column doesn't exist, the
+    /// value is null or it can't be converted to `T` without loss.
+    pub fn get<T>(&self, column: &C) -> Option<T>
+    where
+        T: From<Scalar>,
+        Vec<T>: Into<Array>,
+    {
+        let dtype = Vec::<T>::new().into().dtype();
+        match self.get_scalar(column).and_then(|x| cast_scalar(&x, &dtype)) {
+            None | Some(Scalar::Null) => None,
+            Some(value) => Some(T::from(value)),
+        }
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Keep rows for which `func` returns true.
+    pub fn filter_rows<F>(&'c self, mut func: F) -> Self
+    where
+        F: FnMut(&RowView<I, C>) -> bool,
+    {
+        let flags: Vec<bool> = (0..self.index.len())
+            .map(|i| {
+                func(&RowView {
+                    frame: self,
+                    location: i,
+                })
+            })
+            .collect();
+        self.blocs(&flags)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash + AsRef<str>,
+    C: Clone + Eq + Hash,
+{
+    /// Rows whose label starts with `prefix`.
+    pub fn loc_startswith(&'c self, prefix: &str) -> Self {
+        self.filter_rows(|row| row.label().as_ref().starts_with(prefix))
+    }
+
+    /// Rows whose label contains `pat`.
+    pub fn loc_contains(&'c self, pat: &str) -> Self {
+        self.filter_rows(|row| row.label().as_ref().contains(pat))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec, Scalar};
+
+    use super::super::DataFrame;
+
+    #[test]
+    fn test_frame_filter_rows() {
+        let df: DataFrame<&str, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![10.5, 3., 25.]),
+                NullVec::with_mask(vec![1i64, 0, 7], Some(vec![false, true, false])).into(),
+                Array::new(vec!["apple", "pear", "melon"]),
+            ],
+            vec!["a", "b", "c"],
+            vec!["price", "qty", "name"],
+        );
+
+        let res = df.filter_rows(|row| row.get::<f64>(&"price").is_some_and(|x| x > 5.));
+        let exp: DataFrame<&str, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![10.5, 25.]),
+                NullVec::with_mask(vec![1i64, 7], Some(vec![false, false])).into(),
+                Array::new(vec!["apple", "melon"]),
+            ],
+            vec!["a", "c"],
+            vec!["price", "qty", "name"],
+        );
+        assert_eq!(res, exp);
+
+        // null and lossy conversions are None
+        let res = df.filter_rows(|row| row.get::<i64>(&"qty").is_none());
+        assert_eq!(res.index.values, vec!["b"]);
+        let res = df.filter_rows(|row| row.get::<i64>(&"price").is_some());
+        assert_eq!(res.index.values, vec!["b", "c"]);
+
+        let res = df.filter_rows(|row| {
+            row.get::<String>(&"name").is_some_and(|x| x.starts_with('p')) || *row.label() == "c"
+        });
+        assert_eq!(res.index.values, vec!["b", "c"]);
+
+        df.filter_rows(|row| {
+            assert_eq!(row.get_scalar(&"missing"), None);
+            if row.location() == 1 {
+                assert_eq!(row.get_scalar(&"qty"), Some(Scalar::Null));
+            }
+            true
+        });
+    }
+
+    #[test]
+    fn test_frame_loc_startswith() {
+        let df: DataFrame<&str, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![1, 2, 3]), Array::new(vec![1.5, 2.5, 3.5])],
+            vec!["2018-03-01", "2018-04-01", "2018-03-15"],
+            vec!["X", "Y"],
+        );
+        let res = df.loc_startswith("2018-03");
+        let exp = DataFrame::from_vec(
+            vec![Array::new(vec![1, 3]), Array::new(vec![1.5, 3.5])],
+            vec!["2018-03-01", "2018-03-15"],
+            vec!["X", "Y"],
+        );
+        assert_eq!(res, exp);
+        assert_eq!(df.loc_contains("-01").index.values, vec!["2018-03-01", "2018-04-01"]);
+    }
+}
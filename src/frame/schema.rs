@@ -0,0 +1,138 @@
+use std::any::type_name;
+use std::fmt;
+use std::hash::Hash;
+
+use super::DataFrame;
+use traits::IndexerIndex;
+
+/// Difference of schemas between two `DataFrame`s, created by `schema_diff`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaDiff<C> {
+    /// Columns only in the new frame with their dtypes
+    pub added: Vec<(C, String)>,
+    /// Columns only in the old frame with their dtypes
+    pub removed: Vec<(C, String)>,
+    /// Columns in both frames whose dtype differs, as (column, old, new)
+    pub retyped: Vec<(C, String, String)>,
+    /// Index label types as (old, new) if they differ
+    pub index: Option<(String, String)>,
+}
+
+impl<C> SchemaDiff<C> {
+    /// Whether both frames have the same schema.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.retyped.is_empty() &&
+            self.index.is_none()
+    }
+}
+
+/// Compare columns, dtypes and index label types of `old` and `new`.
+///
+/// Column order is ignored. Added columns are listed in the order of `new`,
+/// others in the order of `old`.
+pub fn schema_diff<I, J, C>(
+    old: &DataFrame<I, C>,
+    new: &DataFrame<J, C>,
+) -> SchemaDiff<C>
+where
+    I: Clone + Eq + Hash,
+    J: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    let mut removed: Vec<(C, String)> = vec![];
+    let mut retyped: Vec<(C, String, String)> = vec![];
+    for (column, values) in old.columns.values.iter().zip(&old.values) {
+        match new.columns.values.iter().position(|x| x == column) {
+            Some(loc) => {
+                let (old_dtype, new_dtype) = (values.dtype(), new.values[loc].dtype());
+                if old_dtype != new_dtype {
+                    retyped.push((column.clone(), old_dtype, new_dtype));
+                }
+            }
+            None => removed.push((column.clone(), values.dtype())),
+        }
+    }
+    let added: Vec<(C, String)> = new.columns
+        .values
+        .iter()
+        .zip(&new.values)
+        .filter(|&(c, _)| !old.columns.contains(c))
+        .map(|(c, v)| (c.clone(), v.dtype()))
+        .collect();
+
+    let (old_index, new_index) = (type_name::<I>(), type_name::<J>());
+    let index = if old_index == new_index {
+        None
+    } else {
+        Some((old_index.to_string(), new_index.to_string()))
+    };
+    SchemaDiff {
+        added,
+        removed,
+        retyped,
+        index,
+    }
+}
+
+impl<C: fmt::Debug> fmt::Display for SchemaDiff<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no schema changes");
+        }
+        let mut lines: Vec<String> = vec![];
+        if let Some((old, new)) = &self.index {
+            lines.push(format!("index: {} -> {}", old, new));
+        }
+        for (column, dtype) in &self.added {
+            lines.push(format!("added: {:?} ({})", column, dtype));
+        }
+        for (column, dtype) in &self.removed {
+            lines.push(format!("removed: {:?} ({})", column, dtype));
+        }
+        for (column, old, new) in &self.retyped {
+            lines.push(format!("retyped: {:?} ({} -> {})", column, old, new));
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::Array;
+
+    use super::super::DataFrame;
+    use super::schema_diff;
+
+    #[test]
+    fn test_schema_diff() {
+        let old: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![1i64, 2]), Array::new(vec![1., 2.]), Array::new(vec![1i64, 2])],
+            vec![1, 2],
+            vec!["a", "b", "c"],
+        );
+        let same: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![1., 2.]), Array::new(vec![3i64, 4]), Array::new(vec![3i64, 4])],
+            vec![3, 4],
+            vec!["b", "a", "c"],
+        );
+        let diff = schema_diff(&old, &same);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "no schema changes");
+
+        let new: DataFrame<usize, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![1., 2.]), Array::new(vec![true, false]), Array::new(vec![1., 2.])],
+            vec![0, 1],
+            vec!["a", "d", "b"],
+        );
+        let diff = schema_diff(&old, &new);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.added, vec![("d", "bool".to_string())]);
+        assert_eq!(diff.removed, vec![("c", "i64".to_string())]);
+        assert_eq!(diff.retyped, vec![("a", "i64".to_string(), "f64".to_string())]);
+        assert_eq!(diff.index, Some(("i64".to_string(), "usize".to_string())));
+        let exp = "index: i64 -> usize\nadded: \"d\" (bool)\nremoved: \"c\" (i64)\n\
+                   retyped: \"a\" (i64 -> f64)";
+        assert_eq!(diff.to_string(), exp);
+    }
+}
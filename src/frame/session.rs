@@ -0,0 +1,89 @@
+use std::hash::Hash;
+use std::time::Duration;
+
+use nullvec::prelude::{Array, Nullable};
+
+use super::DataFrame;
+use algos::key::factorize_rows;
+use convert::{array_to_scalars, duration_nanos};
+use series::Series;
+use traits::IndexerIndex;
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Session id of each row, a new session starts when the time since the
+    /// previous event of the same `by` key exceeds `gap`.
+    ///
+    /// `ts` column must be integer nanoseconds since the UNIX epoch without
+    /// nulls, and rows don't need to be sorted. Sessions are numbered from 0
+    /// in order of first appearance of keys, then by time within each key.
+    pub fn sessionize<'n>(&self, by: &C, ts: &C, gap: Duration) -> Series<'n, 'n, usize, I>
+    where
+        I: 'n,
+    {
+        let keys: &Array = &self.values[self.columns.get_loc(by)];
+        let timestamps: Vec<i64> = array_to_scalars(&self.values[self.columns.get_loc(ts)])
+            .iter()
+            .map(|x| match x.as_i64() {
+                Nullable::Value(v) => v,
+                Nullable::Null => panic!("timestamps must not be null"),
+            })
+            .collect();
+        let gap = duration_nanos(gap);
+
+        let (codes, firsts) = factorize_rows(&[keys]);
+        let mut groups: Vec<Vec<usize>> = vec![vec![]; firsts.len()];
+        for (i, code) in codes.iter().enumerate() {
+            groups[*code].push(i);
+        }
+
+        let mut new_values: Vec<usize> = vec![0; codes.len()];
+        let mut session = 0;
+        for mut locs in groups {
+            locs.sort_by_key(|&i| timestamps[i]);
+            for (n, &loc) in locs.iter().enumerate() {
+                if n > 0 && timestamps[loc] - timestamps[locs[n - 1]] > gap {
+                    session += 1;
+                }
+                new_values[loc] = session;
+            }
+            session += 1;
+        }
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::time::Duration;
+
+    use nullvec::prelude::Array;
+
+    use super::super::DataFrame;
+    use series::Series;
+
+    #[test]
+    fn test_sessionize() {
+        let sec = 1_000_000_000i64;
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec!["a", "b", "a", "a", "b", "a"]),
+                Array::new(vec![0, 10 * sec, 70 * sec, 30 * sec, 200 * sec, 130 * sec]),
+            ],
+            vec![1, 2, 3, 4, 5, 6],
+            vec!["user", "ts"],
+        );
+        let res = df.sessionize(&"user", &"ts", Duration::from_secs(60));
+        // gap of exactly 60 seconds continues the session
+        let exp: Series<usize, i64> = Series::new(vec![0, 1, 0, 0, 2, 0], vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(res, exp);
+
+        let res = df.sessionize(&"user", &"ts", Duration::from_secs(10));
+        let exp: Series<usize, i64> = Series::new(vec![0, 4, 2, 1, 5, 3], vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(res, exp);
+    }
+}
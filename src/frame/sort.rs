@@ -0,0 +1,336 @@
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use nullvec::prelude::Scalar;
+
+use super::DataFrame;
+use algos::key::ScalarKey;
+use algos::parallel::sort_by_kind;
+use convert::array_to_scalars;
+use traits::{IndexerIndex, RowIndex, NullOrder, SortKind};
+
+/// Order of a sort key, which is shared with sorting threads apart from the
+/// column label.
+#[derive(Clone, Copy)]
+struct KeyOrder {
+    ascending: bool,
+    nulls: Option<NullOrder>,
+    comparator: Option<fn(&Scalar, &Scalar) -> Ordering>,
+}
+
+impl KeyOrder {
+    fn cmp(&self, x: &Scalar, y: &Scalar) -> Ordering {
+        let ord = match (*x == Scalar::Null, *y == Scalar::Null) {
+            (true, true) => return Ordering::Equal,
+            (true, false) | (false, true) => {
+                let ord = if *x == Scalar::Null { Ordering::Less } else { Ordering::Greater };
+                match self.nulls {
+                    Some(NullOrder::First) => return ord,
+                    Some(NullOrder::Last) => return ord.reverse(),
+                    None => ord,
+                }
+            }
+            (false, false) => match self.comparator {
+                Some(func) => func(x, y),
+                None => ScalarKey::from(x).cmp(&ScalarKey::from(y)),
+            },
+        };
+        if self.ascending { ord } else { ord.reverse() }
+    }
+}
+
+/// Sort key of `DataFrame::sort_values_with`, ascending with nulls as the
+/// smallest value by default.
+#[derive(Clone)]
+pub struct SortKey<C> {
+    column: C,
+    order: KeyOrder,
+}
+
+impl<C> SortKey<C> {
+    pub fn new(column: C) -> Self {
+        SortKey {
+            column,
+            order: KeyOrder {
+                ascending: true,
+                nulls: None,
+                comparator: None,
+            },
+        }
+    }
+
+    /// Sort in descending order.
+    pub fn descending(mut self) -> Self {
+        self.order.ascending = false;
+        self
+    }
+
+    /// Put nulls first or last regardless of the sort order.
+    pub fn nulls(mut self, nulls: NullOrder) -> Self {
+        self.order.nulls = Some(nulls);
+        self
+    }
+
+    /// Compare non-null values by `comparator`, such as `natural_cmp`.
+    pub fn comparator(mut self, comparator: fn(&Scalar, &Scalar) -> Ordering) -> Self {
+        self.order.comparator = Some(comparator);
+        self
+    }
+}
+
+/// Natural order of strings, which compares runs of digits by their numeric
+/// value such as "file2" < "file10". Other values are compared as
+/// `ScalarKey`.
+pub fn natural_cmp(x: &Scalar, y: &Scalar) -> Ordering {
+    match (x, y) {
+        (Scalar::String(a), Scalar::String(b)) => natural_str_cmp(a, b),
+        _ => ScalarKey::from(x).cmp(&ScalarKey::from(y)),
+    }
+}
+
+/// Split `value` into runs of ASCII digits and other characters.
+fn digit_runs(value: &str) -> Vec<&str> {
+    let mut runs = vec![];
+    let mut start = 0;
+    let mut chars = value.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        let end = chars.peek().map_or(value.len(), |&(i, _)| i);
+        let next_digit = chars.peek().map(|&(_, n)| n.is_ascii_digit());
+        if next_digit != Some(c.is_ascii_digit()) {
+            runs.push(&value[start..end]);
+            start = end;
+        }
+    }
+    runs
+}
+
+fn natural_str_cmp(a: &str, b: &str) -> Ordering {
+    let runs_a = digit_runs(a);
+    let runs_b = digit_runs(b);
+    for (x, y) in runs_a.iter().zip(&runs_b) {
+        let is_digit = |s: &str| s.as_bytes()[0].is_ascii_digit();
+        let ord = if is_digit(x) && is_digit(y) {
+            let (tx, ty) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+            tx.len().cmp(&ty.len()).then(tx.cmp(ty)).then(x.len().cmp(&y.len()))
+        } else {
+            x.cmp(y)
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    runs_a.len().cmp(&runs_b.len())
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Locations which sort rows by `keys` using the algorithm of `kind`.
+    fn argsort_by(&self, keys: &[SortKey<C>], kind: SortKind) -> Vec<usize> {
+        let values: Vec<Vec<Scalar>> = keys.iter()
+            .map(|k| array_to_scalars(&self.values[self.columns.get_loc(&k.column)]))
+            .collect();
+        let orders: Vec<KeyOrder> = keys.iter().map(|k| k.order).collect();
+
+        sort_by_kind((0..self.len()).collect(), kind, |&a, &b| {
+            orders
+                .iter()
+                .zip(&values)
+                .map(|(o, v)| o.cmp(&v[a], &v[b]))
+                .fold(Ordering::Equal, |o, x| o.then(x))
+        })
+    }
+
+    /// Sort rows by values of `by` columns, index is permuted accordingly.
+    ///
+    /// Sort is stable, and later columns break ties of earlier ones. Null is
+    /// the smallest value.
+    pub fn sort_values(&'c self, by: &[C], ascending: &[bool]) -> Self {
+        assert!(by.len() == ascending.len(), "by and ascending must have the same length");
+        let keys: Vec<SortKey<C>> = by.iter()
+            .zip(ascending)
+            .map(|(c, &asc)| {
+                let key = SortKey::new(c.clone());
+                if asc { key } else { key.descending() }
+            })
+            .collect();
+        self.sort_values_with(&keys)
+    }
+
+    /// Sort rows by `keys`, each of which can specify its own order, null
+    /// position and comparator. Sort is stable, and later keys break ties of
+    /// earlier ones.
+    pub fn sort_values_with(&'c self, keys: &[SortKey<C>]) -> Self {
+        self.sort_values_kind(keys, SortKind::Stable)
+    }
+
+    /// Sort rows by `keys` using the algorithm of `kind`.
+    pub fn sort_values_kind(&'c self, keys: &[SortKey<C>], kind: SortKind) -> Self {
+        let indexer = self.argsort_by(keys, kind);
+        self.reindex_by_index(&indexer)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash + Ord,
+    C: Clone + Eq + Hash,
+{
+    /// Sort rows by index labels.
+    pub fn sort_index(&'c self) -> Self {
+        let (indexer, _) = self.index.argsort();
+        self.reindex_by_index(&indexer)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash + Ord + Sync,
+    C: Clone + Eq + Hash,
+{
+    /// Sort rows by index labels using the algorithm of `kind`.
+    pub fn sort_index_kind(&'c self, kind: SortKind) -> Self {
+        let labels = &self.index.values;
+        let indexer = sort_by_kind((0..self.len()).collect(), kind, |&a, &b| {
+            labels[a].cmp(&labels[b])
+        });
+        self.reindex_by_index(&indexer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::cmp::Ordering;
+
+    use nullvec::prelude::{Array, NullVec, Scalar};
+
+    use super::{natural_cmp, SortKey};
+    use super::super::DataFrame;
+    use traits::{ColIndex, NullOrder, SortKind};
+
+    #[test]
+    fn test_sort_values() {
+        let df: DataFrame<&str, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![2i64, 1, 2, 1, 2]),
+                Array::new(vec![1., 2., 3., 4., 3.]),
+                Array::new(vec![10i64, 20, 30, 40, 50]),
+            ],
+            vec!["a", "b", "c", "d", "e"],
+            vec!["X", "Y", "Z"],
+        );
+        let res = df.sort_values(&["X", "Y"], &[true, false]);
+        let exp: DataFrame<&str, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![1i64, 1, 2, 2, 2]),
+                Array::new(vec![4., 2., 3., 3., 1.]),
+                Array::new(vec![40i64, 20, 30, 50, 10]),
+            ],
+            vec!["d", "b", "c", "e", "a"],
+            vec!["X", "Y", "Z"],
+        );
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn test_sort_index() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![1i64, 2, 3]), Array::new(vec!["a", "b", "c"])],
+            vec![30, 10, 20],
+            vec!["X", "Y"],
+        );
+        let exp: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![2i64, 3, 1]), Array::new(vec!["b", "c", "a"])],
+            vec![10, 20, 30],
+            vec!["X", "Y"],
+        );
+        assert_eq!(df.sort_index(), exp);
+    }
+
+    #[test]
+    fn test_sort_values_with() {
+        let df: DataFrame<&str, &str> = DataFrame::from_vec(
+            vec![
+                NullVec::with_mask(vec![2i64, 0, 1, 0], Some(vec![false, true, false, true]))
+                    .into(),
+                Array::new(vec![
+                    "file10".to_string(),
+                    "file2".to_string(),
+                    "file1".to_string(),
+                    "file02".to_string(),
+                ]),
+            ],
+            vec!["a", "b", "c", "d"],
+            vec!["X", "Y"],
+        );
+
+        // nulls are the smallest value by default
+        let res = df.sort_values(&["X"], &[false]);
+        assert_eq!(res.index.values, vec!["a", "c", "b", "d"]);
+
+        let res = df.sort_values_with(&[SortKey::new("X").descending().nulls(NullOrder::First)]);
+        assert_eq!(res.index.values, vec!["b", "d", "a", "c"]);
+        let res = df.sort_values_with(&[SortKey::new("X").nulls(NullOrder::Last)]);
+        assert_eq!(res.index.values, vec!["c", "a", "b", "d"]);
+
+        // lexicographic, then natural order
+        let res = df.sort_values_with(&[SortKey::new("Y")]);
+        assert_eq!(res.index.values, vec!["d", "c", "a", "b"]);
+        let res = df.sort_values_with(&[SortKey::new("Y").comparator(natural_cmp)]);
+        assert_eq!(res.index.values, vec!["c", "b", "d", "a"]);
+
+        let res = df.sort_values_with(&[
+            SortKey::new("X").nulls(NullOrder::Last),
+            SortKey::new("Y").comparator(natural_cmp).descending(),
+        ]);
+        assert_eq!(res.index.values, vec!["c", "a", "d", "b"]);
+    }
+
+    #[test]
+    fn test_sort_kind() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![2i64, 1, 2, 1]), Array::new(vec![1i64, 2, 3, 4])],
+            vec![30, 10, 20, 40],
+            vec!["X", "Y"],
+        );
+        let keys = vec![SortKey::new("X")];
+        let res = df.sort_values_kind(&keys, SortKind::Stable);
+        assert_eq!(res, df.sort_values_with(&keys));
+        assert_eq!(res.index.values, vec![10, 40, 30, 20]);
+        assert_eq!(df.sort_index_kind(SortKind::Stable), df.sort_index());
+
+        let res = df.sort_values_kind(&keys, SortKind::Unstable);
+        assert_eq!(res.get(&"X"), Array::new(vec![1i64, 1, 2, 2]));
+        assert_eq!(df.sort_index_kind(SortKind::Unstable), df.sort_index());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_sort_kind_parallel() {
+        let df: DataFrame<i64, &str> = DataFrame::from_vec(
+            vec![Array::new(vec![2i64, 1, 2, 1]), Array::new(vec![1i64, 2, 3, 4])],
+            vec![30, 10, 20, 40],
+            vec!["X", "Y"],
+        );
+        let res = df.sort_values_kind(&[SortKey::new("X").descending()], SortKind::Parallel);
+        assert_eq!(res.get(&"X"), Array::new(vec![2i64, 2, 1, 1]));
+        assert_eq!(df.sort_index_kind(SortKind::Parallel), df.sort_index());
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        let cmp = |a: &str, b: &str| {
+            natural_cmp(&Scalar::from(a.to_string()), &Scalar::from(b.to_string()))
+        };
+        assert_eq!(cmp("file2", "file10"), Ordering::Less);
+        assert_eq!(cmp("file10", "file10"), Ordering::Equal);
+        assert_eq!(cmp("a1b2", "a1b10"), Ordering::Less);
+        assert_eq!(cmp("file", "file1"), Ordering::Less);
+        assert_eq!(cmp("b", "a100"), Ordering::Greater);
+        assert_eq!(cmp("x007", "x7"), Ordering::Greater);
+        assert_eq!(natural_cmp(&Scalar::i64(10), &Scalar::i64(9)), Ordering::Greater);
+    }
+}
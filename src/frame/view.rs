@@ -0,0 +1,134 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use traits::Slicer;
+
+/// `DataFrame` whose values, index and columns are borrowed for lifetime
+/// `'a`, created by `DataFrame::view`.
+///
+/// Any `DataFrame` can be a view as its components are `Cow`, use
+/// `DataFrame::to_owned` to get a `DataFrame` which doesn't borrow.
+pub type DataFrameView<'a, I, C> = DataFrame<'a, 'a, 'a, I, C>;
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Borrow values, index and columns without copying.
+    pub fn view<'a>(&'a self) -> DataFrameView<'a, I, C> {
+        let values: Vec<Cow<Array>> = self.values.iter().map(|x| Cow::Borrowed(&**x)).collect();
+        DataFrame::from_cow(
+            values,
+            Cow::Borrowed(&*self.index),
+            Cow::Borrowed(&*self.columns),
+        )
+    }
+
+    /// Whether any of values, index or columns is borrowed.
+    pub fn is_view(&self) -> bool {
+        self.values.iter().any(|x| matches!(*x, Cow::Borrowed(_))) ||
+            matches!(self.index, Cow::Borrowed(_)) ||
+            matches!(self.columns, Cow::Borrowed(_))
+    }
+
+    /// Copy borrowed values, index and columns, the result is independent
+    /// from the lifetimes of the original.
+    pub fn to_owned<'n>(&self) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        I: 'n,
+        C: 'n,
+    {
+        self.clone().into_owned()
+    }
+
+    /// Same as `to_owned`, but owned values, index and columns are moved.
+    pub fn into_owned<'n>(self) -> DataFrame<'n, 'n, 'n, I, C>
+    where
+        I: 'n,
+        C: 'n,
+    {
+        let values: Vec<Cow<Array>> = self.values
+            .into_iter()
+            .map(|x| Cow::Owned(x.into_owned()))
+            .collect();
+        DataFrame::from_cow(
+            values,
+            Cow::Owned(self.index.into_owned()),
+            Cow::Owned(self.columns.into_owned()),
+        )
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Split rows into before and after `row`.
+    pub fn split_at<'a>(
+        &'a self,
+        row: usize,
+    ) -> (DataFrameView<'a, I, C>, DataFrameView<'a, I, C>) {
+        let len = self.index.len();
+        assert!(row <= len, "row {} is out of bounds for length {}", row, len);
+        (self.irange(0, row), self.irange(row, len))
+    }
+
+    /// Every `n`-th row starting from `offset`, keeping their index labels.
+    ///
+    /// Columns are borrowed, useful to thin out long series cheaply before
+    /// plotting.
+    pub fn every_nth<'a>(&'a self, n: usize, offset: usize) -> DataFrameView<'a, I, C> {
+        assert!(n > 0, "n must be positive");
+        let locs: Vec<usize> = (offset..self.index.len()).step_by(n).collect();
+        self.irows(&locs)
+    }
+
+    /// Iterate over consecutive chunks of `size` rows, the last chunk can be
+    /// shorter.
+    ///
+    /// Columns of chunks are borrowed, and rows are copied only when each
+    /// chunk is yielded.
+    pub fn chunks<'a>(&'a self, size: usize) -> Chunks<'a, 'v, 'i, 'c, I, C> {
+        assert!(size > 0, "chunk size must be positive");
+        Chunks {
+            frame: self,
+            size,
+            start: 0,
+        }
+    }
+}
+
+/// Iterator over row chunks of `DataFrame`, created by `DataFrame::chunks`.
+pub struct Chunks<'a, 'v: 'a, 'i: 'a, 'c: 'a, I, C>
+where
+    I: 'i + Clone + Hash,
+    C: 'c + Clone + Hash,
+{
+    frame: &'a DataFrame<'v, 'i, 'c, I, C>,
+    size: usize,
+    start: usize,
+}
+
+impl<'a, 'v, 'i, 'c, I, C> Iterator for Chunks<'a, 'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    type Item = DataFrameView<'a, I, C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.frame.index.len();
+        if self.start >= len {
+            return None;
+        }
+        let end = usize::min(self.start + self.size, len);
+        let chunk = self.frame.irange(self.start, end);
+        self.start = end;
+        Some(chunk)
+    }
+}
@@ -1,28 +1,107 @@
+use std::borrow::Cow;
 use std::hash::Hash;
+use std::vec;
 
 use algos::grouper::{Grouper, HashGrouper};
+use series::Series;
 use traits::RowIndex;
 
-pub struct GroupBy<'a, D: 'a, G: Hash> {
+/// Sequence of group keys, such as `Vec`, array or slice.
+pub trait KeyColumn {
+    type Item: Clone + Eq + Hash + Ord;
+
+    fn keys(&self) -> &[Self::Item];
+}
+
+impl<T: Clone + Eq + Hash + Ord> KeyColumn for [T] {
+    type Item = T;
+
+    fn keys(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Clone + Eq + Hash + Ord> KeyColumn for Vec<T> {
+    type Item = T;
+
+    fn keys(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Clone + Eq + Hash + Ord, const N: usize> KeyColumn for [T; N] {
+    type Item = T;
+
+    fn keys(&self) -> &[T] {
+        self
+    }
+}
+
+impl<K: KeyColumn + ?Sized> KeyColumn for &K {
+    type Item = K::Item;
+
+    fn keys(&self) -> &[K::Item] {
+        (*self).keys()
+    }
+}
+
+/// Multiple key columns, whose groups are labelled by tuples.
+pub trait GroupKeys {
+    type Key: Clone + Eq + Hash + Ord;
+
+    /// Combine keys of each row, panics if lengths are different.
+    fn combine(&self) -> Vec<Self::Key>;
+}
+
+macro_rules! define_group_keys {
+    ($($t:ident $i:tt),*) => {
+        impl<$($t: KeyColumn),*> GroupKeys for ($($t,)*) {
+            type Key = ($($t::Item,)*);
+
+            fn combine(&self) -> Vec<Self::Key> {
+                let len = self.0.keys().len();
+                assert!(
+                    $(self.$i.keys().len() == len)&&*,
+                    "Length of keys are different"
+                );
+                (0..len).map(|x| ($(self.$i.keys()[x].clone(),)*)).collect()
+            }
+        }
+    }
+}
+
+define_group_keys!(A 0, B 1);
+define_group_keys!(A 0, B 1, C 2);
+define_group_keys!(A 0, B 1, C 2, D 3);
+
+pub struct GroupBy<'a, D: 'a + Clone, G: Hash> {
     /// Grouped Series
     /// D: grouped data
     /// V: type of Group indexer
-    pub data: &'a D,
+    ///
+    /// Borrowed from the source, or owned if derived from it such as grouped
+    /// columns of `DataFrame::groupby_cols`.
+    pub data: Cow<'a, D>,
     pub grouper: HashGrouper<G>,
 }
 
 impl<'a, D, G> GroupBy<'a, D, G>
 where
-    D: RowIndex<'a>,
+    D: Clone + RowIndex<'a>,
     G: Clone + Eq + Hash + Ord,
 {
     pub fn new(data: &'a D, indexer: &[G]) -> Self {
+        GroupBy::from_cow(Cow::Borrowed(data), indexer)
+    }
 
+    /// Group data which may be owned, such as data derived from other data.
+    ///
+    /// Length of `data` and `indexer` must be the same.
+    pub fn from_cow(data: Cow<'a, D>, indexer: &[G]) -> Self {
         assert!(
             data.len() == indexer.len(),
             "Series and Indexer length are different"
         );
-
         let grouper: HashGrouper<G> = HashGrouper::groupby(indexer);
 
         GroupBy {
@@ -31,7 +110,8 @@ where
         }
     }
 
-    pub fn get_group(&self, group: &G) -> D {
+    /// Borrows `self` for `'a`, as `data` may be owned by the `GroupBy`.
+    pub fn get_group(&'a self, group: &G) -> D {
         if let Some(locs) = self.grouper.get(group) {
             self.data.ilocs(locs)
         } else {
@@ -44,4 +124,64 @@ where
         keys.sort();
         keys
     }
+
+    /// Number of rows in each group.
+    pub fn size<'n>(&self) -> Series<'n, 'n, usize, G> {
+        let groups = self.groups();
+        let sizes: Vec<usize> = groups.iter().map(|g| self.grouper.get(g).unwrap().len()).collect();
+        Series::new(sizes, groups)
+    }
+
+    /// Iterate over pairs of group key and grouped data, in sorted key order.
+    pub fn iter(&'a self) -> GroupIter<'a, D, G> {
+        GroupIter {
+            groupby: self,
+            keys: self.groups().into_iter(),
+        }
+    }
+
+    /// Pass the `GroupBy` to `func`, to express group operations in a chain.
+    pub fn pipe<R, F>(&'a self, func: F) -> R
+    where
+        F: FnOnce(&'a Self) -> R,
+    {
+        func(self)
+    }
+}
+
+/// Iterator over pairs of group key and grouped data, created by `GroupBy::iter`.
+pub struct GroupIter<'a, D: 'a + Clone, G: 'a + Hash> {
+    groupby: &'a GroupBy<'a, D, G>,
+    keys: vec::IntoIter<G>,
+}
+
+impl<'a, D, G> Iterator for GroupIter<'a, D, G>
+where
+    D: Clone + RowIndex<'a>,
+    G: Clone + Eq + Hash + Ord,
+{
+    type Item = (G, D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        let data = self.groupby.get_group(&key);
+        Some((key, data))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<'a, D, G> IntoIterator for &'a GroupBy<'a, D, G>
+where
+    D: Clone + RowIndex<'a>,
+    G: Clone + Eq + Hash + Ord,
+{
+    type Item = (G, D);
+    type IntoIter = GroupIter<'a, D, G>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
@@ -1,7 +1,37 @@
 use std::borrow::Cow;
 use std::hash::Hash;
+use std::str::FromStr;
 
 use super::Indexer;
+use error::Error;
+
+impl<U> Indexer<U>
+where
+    U: Clone + Eq + Hash + ToString,
+{
+    /// Convert labels to other type by parsing their string representation.
+    pub fn astype<J>(&self) -> Indexer<J>
+    where
+        J: Clone + Eq + Hash + FromStr,
+    {
+        self.try_astype().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Convert labels to other type, or return `Error::ParseLabel`
+    pub fn try_astype<J>(&self) -> Result<Indexer<J>, Error>
+    where
+        J: Clone + Eq + Hash + FromStr,
+    {
+        let values = self.values
+            .iter()
+            .map(|x| {
+                let label = x.to_string();
+                label.parse().map_err(|_| Error::ParseLabel(format!("{:?}", label)))
+            })
+            .collect::<Result<Vec<J>, Error>>()?;
+        Ok(Indexer::new(values))
+    }
+}
 
 /// /////////////////////////////////////////////////////////////////////////////
 /// From / Into
@@ -33,6 +63,18 @@ impl<'a, T: Clone + Hash> Into<Cow<'a, Indexer<T>>> for Indexer<T> {
 mod tests {
 
     use super::super::Indexer;
+    use error::Error;
+
+    #[test]
+    fn test_indexer_astype() {
+        let idx: Indexer<&str> = Indexer::new(vec!["1", "2", "3"]);
+        let exp: Indexer<i64> = Indexer::new(vec![1, 2, 3]);
+        assert_eq!(idx.astype::<i64>(), exp);
+
+        let idx: Indexer<&str> = Indexer::new(vec!["1", "x"]);
+        let res = idx.try_astype::<i64>();
+        assert_eq!(res, Err(Error::ParseLabel("\"x\"".to_string())));
+    }
 
     #[test]
     fn test_i64_vec_to_indexer() {
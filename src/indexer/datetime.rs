@@ -0,0 +1,61 @@
+use std::ops::Range;
+
+use super::Indexer;
+use datetime::partial_datetime_range;
+
+impl Indexer<i64> {
+    /// Locations of labels from `start` until `end` (exclusive) by binary
+    /// search, labels must be sorted in ascending order.
+    pub fn slice_locs(&self, start: i64, end: i64) -> Range<usize> {
+        let lower = self.values.partition_point(|&x| x < start);
+        let upper = self.values.partition_point(|&x| x < end);
+        lower..usize::max(lower, upper)
+    }
+
+    /// Locations of labels within partial timestamp `value` such as
+    /// "2018-03", labels must be sorted nanoseconds since the UNIX epoch.
+    ///
+    /// See `partial_datetime_range` for the format, panics if `value` is
+    /// invalid.
+    pub fn partial_locs(&self, value: &str) -> Range<usize> {
+        let (start, end) = partial_datetime_range(value)
+            .unwrap_or_else(|| panic!("invalid partial timestamp: {:?}", value));
+        self.slice_locs(start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Indexer;
+
+    #[test]
+    fn test_index_slice_locs() {
+        let idx = Indexer::new(vec![1i64, 3, 3, 5, 8]);
+        assert_eq!(idx.slice_locs(3, 6), 1..4);
+        assert_eq!(idx.slice_locs(0, 2), 0..1);
+        assert_eq!(idx.slice_locs(9, 10), 5..5);
+        assert_eq!(idx.slice_locs(6, 2), 4..4);
+    }
+
+    #[test]
+    fn test_index_partial_locs() {
+        // 2018-01-31, 2018-02-01, 2018-02-28 and 2018-03-01 00:00 UTC
+        let idx = Indexer::new(vec![
+            1_517_356_800_000_000_000i64,
+            1_517_443_200_000_000_000,
+            1_519_776_000_000_000_000,
+            1_519_862_400_000_000_000,
+        ]);
+        assert_eq!(idx.partial_locs("2018-02"), 1..3);
+        assert_eq!(idx.partial_locs("2018"), 0..4);
+        assert_eq!(idx.partial_locs("2018-02-28"), 2..3);
+        assert_eq!(idx.partial_locs("2017"), 0..0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_partial_locs_invalid() {
+        Indexer::new(vec![0i64]).partial_locs("2018-02-30");
+    }
+}
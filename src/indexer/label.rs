@@ -0,0 +1,117 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+/// Index label whose type is determined at runtime, such as labels read from
+/// files.
+///
+/// Labels of different kinds are ordered as integers, dates and strings.
+///
+/// The `Date` variant only exists with the `chrono` feature, so matches out of
+/// this crate must have a wildcard arm.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum IndexLabel {
+    Int(i64),
+    #[cfg(feature = "chrono")]
+    Date(NaiveDate),
+    Str(String),
+}
+
+impl From<i64> for IndexLabel {
+    fn from(value: i64) -> IndexLabel {
+        IndexLabel::Int(value)
+    }
+}
+
+impl From<String> for IndexLabel {
+    fn from(value: String) -> IndexLabel {
+        IndexLabel::Str(value)
+    }
+}
+
+impl From<&str> for IndexLabel {
+    fn from(value: &str) -> IndexLabel {
+        IndexLabel::Str(value.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NaiveDate> for IndexLabel {
+    fn from(value: NaiveDate) -> IndexLabel {
+        IndexLabel::Date(value)
+    }
+}
+
+/// Parse as integer, date (`%Y-%m-%d`) or string in this order, never fails.
+impl FromStr for IndexLabel {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<IndexLabel, Infallible> {
+        if let Ok(v) = s.parse::<i64>() {
+            return Ok(IndexLabel::Int(v));
+        }
+        #[cfg(feature = "chrono")]
+        {
+            if let Ok(v) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+                return Ok(IndexLabel::Date(v));
+            }
+        }
+        Ok(IndexLabel::Str(s.to_string()))
+    }
+}
+
+impl fmt::Display for IndexLabel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IndexLabel::Int(v) => write!(f, "{}", v),
+            #[cfg(feature = "chrono")]
+            IndexLabel::Date(v) => write!(f, "{}", v),
+            IndexLabel::Str(ref v) => write!(f, "{}", v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::IndexLabel;
+
+    #[test]
+    fn test_index_label_parse() {
+        let labels: Vec<IndexLabel> = vec!["10", "-3", "a", "2018-01-01x"]
+            .iter()
+            .map(|x| x.parse().unwrap())
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                IndexLabel::Int(10),
+                IndexLabel::Int(-3),
+                IndexLabel::from("a"),
+                IndexLabel::from("2018-01-01x"),
+            ]
+        );
+        assert_eq!(labels[2].to_string(), "a");
+
+        let mut sorted = labels.clone();
+        sorted.sort();
+        assert_eq!(sorted[0], IndexLabel::Int(-3));
+        assert_eq!(sorted[3], IndexLabel::from("a"));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_index_label_parse_date() {
+        use chrono::NaiveDate;
+
+        let label: IndexLabel = "2018-01-31".parse().unwrap();
+        let date = NaiveDate::from_ymd_opt(2018, 1, 31).unwrap();
+        assert_eq!(label, IndexLabel::Date(date));
+        assert_eq!(label.to_string(), "2018-01-31");
+        assert!(IndexLabel::Int(1) < label && label < IndexLabel::from("a"));
+    }
+}
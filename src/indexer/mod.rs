@@ -1,17 +1,27 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
+use std::fmt::Debug;
 use std::hash::Hash;
 use std::iter::FromIterator;
 use std::slice;
 use std::vec;
 
 use nullvec::prelude::dev::algos::Indexing;
+use algos::factorize::factorize;
+use error::Error;
 use traits::{Slicer, IndexerIndex, Append};
 
+pub use self::label::IndexLabel;
+pub use self::multi::{MultiIndexer, MultiLabel};
+
 mod convert;
+#[cfg(feature = "chrono")]
+mod datetime;
 mod formatting;
 mod indexing;
+mod label;
+mod multi;
 mod ops;
 mod sort;
 
@@ -124,17 +134,87 @@ where
     }
 
     fn init_state(&self) {
-        // update htable
+        if self.build_htable().is_err() {
+            panic!("duplicates are not allowed");
+        }
+    }
+}
+
+impl<U> Indexer<U>
+where
+    U: Clone + Eq + Hash,
+{
+    /// Update htable, return the location of the duplicated label if exists
+    fn build_htable(&self) -> Result<(), usize> {
         let mut htable = self.htable.borrow_mut();
-        if htable.len() != 0 {
-            return;
+        if !htable.is_empty() {
+            return Ok(());
         }
         for (loc, label) in self.values.iter().enumerate() {
             match htable.entry(label.clone()) {
-                Entry::Occupied(_) => panic!("duplicates are not allowed"),
+                Entry::Occupied(_) => {
+                    htable.clear();
+                    return Err(loc);
+                }
                 Entry::Vacant(e) => e.insert(loc),
             };
         }
+        Ok(())
+    }
+
+    /// Whether all labels are unique.
+    pub fn is_unique(&self) -> bool {
+        self.build_htable().is_ok()
+    }
+
+    /// Encode labels as locations in unique labels, which are in order of
+    /// first appearance.
+    pub fn factorize(&self) -> (Vec<usize>, Indexer<U>) {
+        let (codes, uniques) = factorize(&self.values);
+        (codes, Indexer::new(uniques))
+    }
+}
+
+impl<U> Indexer<U>
+where
+    U: Clone + Eq + Hash + Debug,
+{
+    fn try_init_state(&self) -> Result<(), Error> {
+        self.build_htable().map_err(|loc| {
+            Error::DuplicateLabel(format!("{:?}", self.values[loc]))
+        })
+    }
+
+    /// Return label location, or `Error::KeyNotFound` if the label doesn't exist
+    pub fn try_get_loc(&self, label: &U) -> Result<usize, Error> {
+        self.try_init_state()?;
+        match self.htable.borrow().get(label) {
+            Some(loc) => Ok(*loc),
+            None => Err(Error::KeyNotFound(format!("{:?}", label))),
+        }
+    }
+
+    /// Return label locations, or `Error::KeyNotFound` if any label doesn't exist
+    pub fn try_get_locs(&self, labels: &[U]) -> Result<Vec<usize>, Error> {
+        labels.iter().map(|label| self.try_get_loc(label)).collect()
+    }
+
+    /// Add label, or return `Error::DuplicateLabel` if the label already exists
+    pub fn try_push(&mut self, label: U) -> Result<(), Error> {
+        self.try_init_state()?;
+        if self.contains(&label) {
+            return Err(Error::DuplicateLabel(format!("{:?}", label)));
+        }
+        self.push(label);
+        Ok(())
+    }
+
+    /// Return a new `Indexer` which has labels of both, or `Error::DuplicateLabel`
+    /// if any label is shared
+    pub fn try_append(&self, other: &Self) -> Result<Self, Error> {
+        let new = self.append(other);
+        new.try_init_state()?;
+        Ok(new)
     }
 }
 
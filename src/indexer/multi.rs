@@ -0,0 +1,179 @@
+use std::hash::Hash;
+
+use super::Indexer;
+
+/// Hierarchical index, whose labels are tuples such as `(A, B)`.
+///
+/// This is `Indexer` itself, level operations are available when its label
+/// implements `MultiLabel`.
+pub type MultiIndexer<T> = Indexer<T>;
+
+/// Label of hierarchical index, implemented for tuples of 2 to 4 levels.
+///
+/// Level based operations only work on the first level (head), and are named
+/// after it. Use `swap_first_two` to operate on the second level.
+pub trait MultiLabel: Clone + Eq + Hash {
+    /// The first level
+    type Head: Clone + Eq + Hash;
+    /// Remaining levels, a tuple if 2 or more levels remain
+    type Tail: Clone + Eq + Hash;
+    /// Label whose first and second levels are swapped
+    type Swapped: Clone + Eq + Hash;
+
+    fn head(&self) -> Self::Head;
+    fn tail(&self) -> Self::Tail;
+    fn swap(&self) -> Self::Swapped;
+}
+
+impl<A, B> MultiLabel for (A, B)
+where
+    A: Clone + Eq + Hash,
+    B: Clone + Eq + Hash,
+{
+    type Head = A;
+    type Tail = B;
+    type Swapped = (B, A);
+
+    fn head(&self) -> A {
+        self.0.clone()
+    }
+
+    fn tail(&self) -> B {
+        self.1.clone()
+    }
+
+    fn swap(&self) -> (B, A) {
+        (self.1.clone(), self.0.clone())
+    }
+}
+
+impl<A, B, C> MultiLabel for (A, B, C)
+where
+    A: Clone + Eq + Hash,
+    B: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    type Head = A;
+    type Tail = (B, C);
+    type Swapped = (B, A, C);
+
+    fn head(&self) -> A {
+        self.0.clone()
+    }
+
+    fn tail(&self) -> (B, C) {
+        (self.1.clone(), self.2.clone())
+    }
+
+    fn swap(&self) -> (B, A, C) {
+        (self.1.clone(), self.0.clone(), self.2.clone())
+    }
+}
+
+impl<A, B, C, D> MultiLabel for (A, B, C, D)
+where
+    A: Clone + Eq + Hash,
+    B: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+    D: Clone + Eq + Hash,
+{
+    type Head = A;
+    type Tail = (B, C, D);
+    type Swapped = (B, A, C, D);
+
+    fn head(&self) -> A {
+        self.0.clone()
+    }
+
+    fn tail(&self) -> (B, C, D) {
+        (self.1.clone(), self.2.clone(), self.3.clone())
+    }
+
+    fn swap(&self) -> (B, A, C, D) {
+        (self.1.clone(), self.0.clone(), self.2.clone(), self.3.clone())
+    }
+}
+
+impl<A, B> Indexer<(A, B)>
+where
+    A: Clone + Eq + Hash,
+    B: Clone + Eq + Hash,
+{
+    /// Create `MultiIndexer` from values of each level.
+    pub fn from_arrays(first: Vec<A>, second: Vec<B>) -> Self {
+        assert!(first.len() == second.len(), "Length mismatch!");
+        first.into_iter().zip(second).collect()
+    }
+
+    /// Create `MultiIndexer` which has all combinations of given levels.
+    pub fn from_product(first: &[A], second: &[B]) -> Self {
+        let mut values: Vec<(A, B)> = Vec::with_capacity(first.len() * second.len());
+        for a in first {
+            for b in second {
+                values.push((a.clone(), b.clone()));
+            }
+        }
+        Indexer::new(values)
+    }
+}
+
+impl<T> Indexer<T>
+where
+    T: MultiLabel,
+{
+    /// Values of the first level.
+    pub fn get_level_head(&self) -> Vec<T::Head> {
+        self.values.iter().map(|x| x.head()).collect()
+    }
+
+    /// Locations whose first level is equal to `head`.
+    pub fn xs_first_locs(&self, head: &T::Head) -> Vec<usize> {
+        self.values
+            .iter()
+            .enumerate()
+            .filter(|&(_, x)| x.head() == *head)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Remove the first level.
+    pub fn drop_first_level(&self) -> Indexer<T::Tail> {
+        self.values.iter().map(|x| x.tail()).collect()
+    }
+
+    /// Swap the first and second levels.
+    pub fn swap_first_two(&self) -> Indexer<T::Swapped> {
+        self.values.iter().map(|x| x.swap()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Indexer;
+
+    #[test]
+    fn test_multi_from_product() {
+        let idx = Indexer::<(&str, i64)>::from_product(&["a", "b"], &[1, 2]);
+        let exp = Indexer::new(vec![("a", 1), ("a", 2), ("b", 1), ("b", 2)]);
+        assert_eq!(idx, exp);
+
+        let idx = Indexer::from_arrays(vec!["a", "a", "b", "b"], vec![1, 2, 1, 2]);
+        assert_eq!(idx, exp);
+    }
+
+    #[test]
+    fn test_multi_levels() {
+        let idx = Indexer::new(vec![("a", 1, true), ("b", 2, false), ("a", 3, true)]);
+        assert_eq!(idx.get_level_head(), vec!["a", "b", "a"]);
+        assert_eq!(idx.xs_first_locs(&"a"), vec![0, 2]);
+        assert_eq!(
+            idx.drop_first_level(),
+            Indexer::new(vec![(1, true), (2, false), (3, true)])
+        );
+        assert_eq!(
+            idx.swap_first_two(),
+            Indexer::new(vec![(1, "a", true), (2, "b", false), (3, "a", true)])
+        );
+    }
+}
@@ -20,6 +20,26 @@ where
     pub fn sort(&self) -> Self {
         Indexer::new(Sorter::sort(&self.values))
     }
+
+    /// Whether labels are sorted in ascending order, allowing equal labels.
+    pub fn is_monotonic_increasing(&self) -> bool {
+        self.values.windows(2).all(|w| w[0] <= w[1])
+    }
+
+    /// Whether labels are sorted in descending order, allowing equal labels.
+    pub fn is_monotonic_decreasing(&self) -> bool {
+        self.values.windows(2).all(|w| w[0] >= w[1])
+    }
+
+    /// Minimum label, `None` if empty.
+    pub fn min(&self) -> Option<&U> {
+        self.values.iter().min()
+    }
+
+    /// Maximum label, `None` if empty.
+    pub fn max(&self) -> Option<&U> {
+        self.values.iter().max()
+    }
 }
 
 
@@ -28,6 +48,24 @@ mod tests {
 
     use super::super::Indexer;
 
+    #[test]
+    fn test_index_monotonic() {
+        let idx = Indexer::new(vec![1, 2, 2, 5]);
+        assert!(idx.is_monotonic_increasing());
+        assert!(!idx.is_monotonic_decreasing());
+        assert_eq!(idx.min(), Some(&1));
+        assert_eq!(idx.max(), Some(&5));
+
+        let idx = Indexer::new(vec!["c", "b", "a"]);
+        assert!(!idx.is_monotonic_increasing());
+        assert!(idx.is_monotonic_decreasing());
+
+        let idx: Indexer<i64> = Indexer::new(vec![]);
+        assert!(idx.is_monotonic_increasing());
+        assert!(idx.is_monotonic_decreasing());
+        assert_eq!(idx.min(), None);
+    }
+
     #[test]
     fn test_index_argsort_int() {
         let idx = Indexer::new(vec![5, 4, 3, 2, 1]);
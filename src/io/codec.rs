@@ -0,0 +1,96 @@
+use std::hash::Hash;
+use std::string::ToString;
+
+use nullvec::prelude::{Array, Scalar};
+
+use convert::infer_array;
+use frame::DataFrame;
+use indexer::Indexer;
+use traits::{Slicer, IndexerIndex, RowIndex};
+
+/// Encode typed rows into (column name, value) pairs, and decode them back.
+///
+/// Implement this for message types (such as protobuf or flatbuffers) to convert
+/// the message stream from / to `DataFrame` columns directly.
+pub trait RowCodec {
+    type Row;
+    type Error;
+
+    /// Encode a row to (column name, value) pairs.
+    fn encode(&self, row: &Self::Row) -> Result<Vec<(String, Scalar)>, Self::Error>;
+
+    /// Decode a row from values, `columns` are names of corresponding values.
+    fn decode(&self, columns: &[String], values: Vec<Scalar>) -> Result<Self::Row, Self::Error>;
+}
+
+/// Build `DataFrame` from rows of (column name, value) pairs.
+///
+/// Columns are ordered by its first appearance, missing keys are regarded as `Null`.
+pub fn records_to_frame<'a, T, R>(records: T, offset: usize) -> DataFrame<'a, 'a, 'a, usize, String>
+where
+    T: IntoIterator<Item = R>,
+    R: IntoIterator<Item = (String, Scalar)>,
+{
+    let mut columns: Indexer<String> = Indexer::new(vec![]);
+    let mut colvecs: Vec<Vec<Scalar>> = vec![];
+
+    for (i, record) in records.into_iter().enumerate() {
+        for (key, value) in record {
+            if !columns.contains(&key) {
+                columns.push(key.clone());
+                colvecs.push(vec![Scalar::Null; i]);
+            }
+            let loc = columns.get_loc(&key);
+            colvecs[loc].push(value);
+        }
+        for column in &mut colvecs {
+            if column.len() == i {
+                column.push(Scalar::Null);
+            }
+        }
+    }
+    let len = colvecs.first().map_or(0, |x| x.len());
+    let arrays: Vec<Array> = colvecs.into_iter().map(infer_array).collect();
+    let index: Indexer<usize> = (offset..offset + len).collect::<Vec<usize>>().into();
+    DataFrame::from_vec(arrays, index, columns)
+}
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Create `DataFrame` from typed rows using `RowCodec`.
+    pub fn from_rows<X, T>(
+        codec: &X,
+        rows: T,
+    ) -> Result<DataFrame<'a, 'a, 'a, usize, String>, X::Error>
+    where
+        X: RowCodec,
+        T: IntoIterator<Item = X::Row>,
+    {
+        let mut records: Vec<Vec<(String, Scalar)>> = vec![];
+        for row in rows {
+            records.push(codec.encode(&row)?);
+        }
+        Ok(records_to_frame(records, 0))
+    }
+}
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + ToString,
+{
+    /// Convert each row to typed value using `RowCodec`.
+    pub fn to_rows<X: RowCodec>(&self, codec: &X) -> Result<Vec<X::Row>, X::Error> {
+        let columns: Vec<String> = self.columns.values.iter().map(|x| x.to_string()).collect();
+
+        let mut rows: Vec<X::Row> = Vec::with_capacity(self.len());
+        for i in 0..self.len() {
+            let values: Vec<Scalar> = self.values.iter().map(|x| x.iloc(&i)).collect();
+            rows.push(codec.decode(&columns, values)?);
+        }
+        Ok(rows)
+    }
+}
@@ -0,0 +1,209 @@
+use csv;
+use std::hash::Hash;
+use std::io::Read;
+
+use chrono::FixedOffset;
+
+use nullvec::prelude::{Array, Scalar, Nullable, NullVec};
+
+use convert::array_to_scalars;
+use datetime::parse_datetime;
+use frame::DataFrame;
+use indexer::Indexer;
+
+/// Datetime columns and timezone used in `DataFrame::read_csv_dates`.
+#[derive(Clone, Debug)]
+pub struct DateOptions {
+    formats: Vec<(String, String)>,
+    timezone: FixedOffset,
+}
+
+impl Default for DateOptions {
+    fn default() -> Self {
+        DateOptions {
+            formats: vec![],
+            timezone: FixedOffset::east_opt(0).unwrap(),
+        }
+    }
+}
+
+impl DateOptions {
+    pub fn new() -> Self {
+        DateOptions::default()
+    }
+
+    /// Parse the column as datetime using chrono format string.
+    pub fn column(mut self, name: &str, format: &str) -> Self {
+        self.formats.push((name.to_string(), format.to_string()));
+        self
+    }
+
+    /// Timezone assumed for values without offset, default is UTC.
+    pub fn timezone(mut self, timezone: FixedOffset) -> Self {
+        self.timezone = timezone;
+        self
+    }
+
+    fn format(&self, column: &str) -> Option<&str> {
+        self.formats
+            .iter()
+            .find(|&(name, _)| name == column)
+            .map(|(_, format)| format.as_ref())
+    }
+}
+
+/// Value which couldn't be parsed as datetime.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DateParseFailure {
+    pub column: String,
+    pub row: usize,
+    pub value: String,
+}
+
+/// Summary of parse failures, failed values are stored as nulls.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DateParseSummary {
+    pub failures: Vec<DateParseFailure>,
+}
+
+impl DateParseSummary {
+    pub fn is_empty(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Number of failures in the column.
+    pub fn count(&self, column: &str) -> usize {
+        self.failures.iter().filter(|x| x.column == column).count()
+    }
+}
+
+/// Parse `values` of the column `name` as `i64` nanoseconds, recording
+/// failures in `summary`. `None` values are nulls.
+fn parse_column<T>(
+    name: &str,
+    values: T,
+    format: &str,
+    timezone: &FixedOffset,
+    summary: &mut DateParseSummary,
+) -> Array
+where
+    T: Iterator<Item = Option<String>>,
+{
+    let mut parsed: Vec<Nullable<i64>> = vec![];
+    for (row, value) in values.enumerate() {
+        let value = match value {
+            Some(value) => value,
+            None => {
+                parsed.push(Nullable::Null);
+                continue;
+            }
+        };
+        match parse_datetime(&value, format, timezone) {
+            Some(v) => parsed.push(Nullable::Value(v)),
+            None => {
+                parsed.push(Nullable::Null);
+                summary.failures.push(DateParseFailure {
+                    column: name.to_string(),
+                    row,
+                    value,
+                });
+            }
+        }
+    }
+    Array::Int64Array(parsed.into_iter().collect::<NullVec<i64>>())
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + ToString,
+{
+    /// Parse string columns specified in `options` as datetime, such as
+    /// columns read by `read_ndjson`. Other columns are kept.
+    ///
+    /// Same as `read_csv_dates`, parsed columns are `i64` nanoseconds since
+    /// the UNIX epoch in UTC and unparseable values are nulls reported in the
+    /// returned summary.
+    pub fn parse_dates<'n>(
+        &self,
+        options: &DateOptions,
+    ) -> (DataFrame<'n, 'n, 'n, I, C>, DateParseSummary)
+    where
+        I: 'n,
+        C: 'n,
+    {
+        let mut summary = DateParseSummary::default();
+        let mut arrays: Vec<Array> = Vec::with_capacity(self.values.len());
+        for (column, array) in self.columns.values.iter().zip(&self.values) {
+            let name = column.to_string();
+            let array = match options.format(&name) {
+                Some(format) if array.dtype() == "str" => {
+                    let values = array_to_scalars(array).into_iter().map(|x| match x {
+                        Scalar::String(s) => Some(s),
+                        _ => None,
+                    });
+                    parse_column(&name, values, format, &options.timezone, &mut summary)
+                }
+                _ => (**array).clone(),
+            };
+            arrays.push(array);
+        }
+        let frame = DataFrame::from_vec(
+            arrays,
+            self.index.clone().into_owned(),
+            self.columns.clone().into_owned(),
+        );
+        (frame, summary)
+    }
+}
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Read CSV, parsing columns specified in `options` as datetime.
+    ///
+    /// Datetime columns are `i64` nanoseconds since the UNIX epoch in UTC.
+    /// Empty values are nulls, and unparseable values are nulls reported in
+    /// the returned summary.
+    pub fn read_csv_dates<R: Read>(
+        mut reader: csv::Reader<R>,
+        options: &DateOptions,
+    ) -> Result<(DataFrame<'a, 'a, 'a, usize, String>, DateParseSummary), csv::Error> {
+        let header = reader.headers()?.clone();
+        let columns: Vec<String> = if reader.has_headers() {
+            header.iter().map(|s| s.to_string()).collect()
+        } else {
+            super::default_columns(header.len())
+        };
+
+        let mut colvecs: Vec<Vec<String>> = vec![vec![]; columns.len()];
+        for record in reader.records() {
+            for (column, value) in colvecs.iter_mut().zip(record?.iter()) {
+                column.push(value.to_string());
+            }
+        }
+        let len = colvecs.first().map_or(0, |x| x.len());
+
+        let mut summary = DateParseSummary::default();
+        let mut arrays: Vec<Array> = Vec::with_capacity(columns.len());
+        for (name, values) in columns.iter().zip(colvecs) {
+            let array: Array = match options.format(name) {
+                Some(format) => {
+                    let values = values
+                        .into_iter()
+                        .map(|x| if x.is_empty() { None } else { Some(x) });
+                    parse_column(name, values, format, &options.timezone, &mut summary)
+                }
+                None => {
+                    let scalars: Vec<Scalar> = values.iter().map(|s| s.as_str().into()).collect();
+                    scalars.into()
+                }
+            };
+            arrays.push(array);
+        }
+        let index: Indexer<usize> = Indexer::<usize>::from_len(len);
+        Ok((DataFrame::from_vec(arrays, index, columns), summary))
+    }
+}
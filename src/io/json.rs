@@ -0,0 +1,166 @@
+use serde_json;
+use serde_json::{Map, Number, Value};
+use std::hash::Hash;
+use std::io::{BufRead, Write};
+use std::string::ToString;
+
+use nullvec::prelude::Scalar;
+
+use frame::DataFrame;
+use traits::{Slicer, RowIndex};
+use super::codec::records_to_frame;
+
+fn value_to_scalar(value: Value) -> Scalar {
+    match value {
+        Value::Null => Scalar::Null,
+        Value::Bool(b) => Scalar::bool(b),
+        Value::Number(n) => {
+            match n.as_i64() {
+                Some(i) => Scalar::i64(i),
+                None => Scalar::f64(n.as_f64().unwrap_or(f64::NAN)),
+            }
+        }
+        Value::String(s) => Scalar::String(s),
+        // nested values are kept as its JSON representation
+        other => Scalar::String(other.to_string()),
+    }
+}
+
+fn scalar_to_value(value: Scalar) -> Value {
+    match value {
+        Scalar::Null => Value::Null,
+        Scalar::bool(b) => Value::Bool(b),
+        Scalar::String(s) => Value::String(s),
+        Scalar::f64(f) => Number::from_f64(f).map_or(Value::Null, Value::Number),
+        Scalar::f32(f) => Number::from_f64(f as f64).map_or(Value::Null, Value::Number),
+        Scalar::u64(u) => Value::Number(u.into()),
+        Scalar::usize(u) => Value::Number((u as u64).into()),
+        Scalar::u32(u) => Value::Number(u.into()),
+        Scalar::u16(u) => Value::Number(u.into()),
+        Scalar::u8(u) => Value::Number(u.into()),
+        other => Value::Number(i64::from(other.as_i64()).into()),
+    }
+}
+
+fn to_frame<'a>(
+    records: Vec<Map<String, Value>>,
+    offset: usize,
+) -> DataFrame<'a, 'a, 'a, usize, String> {
+    let records = records.into_iter().map(|record| {
+        record.into_iter().map(|(k, v)| (k, value_to_scalar(v)))
+    });
+    records_to_frame(records, offset)
+}
+
+/// Read newline-delimited JSON records, at most `limit` records if specified.
+///
+/// Returns `None` when the stream has reached to the end.
+fn read_records<R: BufRead>(
+    reader: &mut R,
+    limit: Option<usize>,
+) -> Result<Option<Vec<Map<String, Value>>>, serde_json::Error> {
+    let mut records: Vec<Map<String, Value>> = vec![];
+    let mut line = String::new();
+    loop {
+        if let Some(n) = limit {
+            if records.len() >= n {
+                break;
+            }
+        }
+        line.clear();
+        let read = reader.read_line(&mut line).map_err(serde_json::Error::io)?;
+        if read == 0 {
+            if records.is_empty() {
+                return Ok(None);
+            }
+            break;
+        }
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        records.push(serde_json::from_str(trimmed)?);
+    }
+    Ok(Some(records))
+}
+
+/// Iterator which reads newline-delimited JSON stream as `DataFrame` batches.
+///
+/// Index continues over batches, thus the n-th record is labeled as n.
+pub struct NdjsonReader<R: BufRead> {
+    reader: R,
+    batch_size: usize,
+    offset: usize,
+}
+
+impl<R: BufRead> NdjsonReader<R> {
+    pub fn new(reader: R, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "batch_size must be positive");
+        NdjsonReader {
+            reader,
+            batch_size,
+            offset: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for NdjsonReader<R> {
+    type Item = Result<DataFrame<'static, 'static, 'static, usize, String>, serde_json::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match read_records(&mut self.reader, Some(self.batch_size)) {
+            Ok(Some(records)) => {
+                let df = to_frame(records, self.offset);
+                self.offset += df.len();
+                Some(Ok(df))
+            }
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Read whole newline-delimited JSON stream into single `DataFrame`.
+    pub fn read_ndjson<R: BufRead>(
+        mut reader: R,
+    ) -> Result<DataFrame<'a, 'a, 'a, usize, String>, serde_json::Error> {
+        let records = read_records(&mut reader, None)?.unwrap_or_default();
+        Ok(to_frame(records, 0))
+    }
+
+    /// Read newline-delimited JSON stream as `DataFrame` batches of `batch_size` rows.
+    pub fn read_ndjson_batches<R: BufRead>(reader: R, batch_size: usize) -> NdjsonReader<R> {
+        NdjsonReader::new(reader, batch_size)
+    }
+}
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + ToString,
+{
+    /// Write rows as newline-delimited JSON. Index is not written.
+    pub fn write_ndjson<W: Write>(&self, writer: &mut W) -> Result<(), serde_json::Error> {
+        let columns: Vec<String> = self.columns.values.iter().map(|x| x.to_string()).collect();
+
+        for i in 0..self.len() {
+            // write manually to keep column order
+            writer.write_all(b"{").map_err(serde_json::Error::io)?;
+            for (j, (column, values)) in columns.iter().zip(&self.values).enumerate() {
+                if j > 0 {
+                    writer.write_all(b",").map_err(serde_json::Error::io)?;
+                }
+                serde_json::to_writer(&mut *writer, column)?;
+                writer.write_all(b":").map_err(serde_json::Error::io)?;
+                serde_json::to_writer(&mut *writer, &scalar_to_value(values.iloc(&i)))?;
+            }
+            writer.write_all(b"}\n").map_err(serde_json::Error::io)?;
+        }
+        Ok(())
+    }
+}
@@ -9,6 +9,35 @@ use frame::DataFrame;
 use indexer::Indexer;
 use traits::{Slicer, RowIndex};
 
+mod codec;
+pub use self::codec::RowCodec;
+mod sql;
+pub use self::sql::{SqlResult, SqlSource};
+#[cfg(feature = "chrono")]
+mod csv_dates;
+#[cfg(feature = "chrono")]
+pub use self::csv_dates::{DateOptions, DateParseFailure, DateParseSummary};
+#[cfg(feature = "serde")]
+mod serde_codec;
+#[cfg(feature = "serde")]
+pub use self::serde_codec::{SerdeCodec, CodecError};
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+pub use self::json::NdjsonReader;
+#[cfg(feature = "parquet")]
+mod parquet;
+#[cfg(feature = "parquet")]
+pub use self::parquet::ParquetChunks;
+#[cfg(feature = "parquet")]
+mod stats;
+#[cfg(feature = "parquet")]
+pub use self::stats::{ColumnStats, Predicate};
+#[cfg(feature = "parquet")]
+mod table;
+#[cfg(feature = "parquet")]
+pub use self::table::Table;
+
 fn default_columns(n: usize) -> Vec<String> {
     let columns: Vec<usize> = (0..n).collect();
     columns.into_iter().map(|x| x.to_string()).collect()
@@ -0,0 +1,378 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::io::Write;
+use std::iter;
+use std::string::ToString;
+use std::sync::Arc;
+
+use parquet::basic::{ConvertedType, Repetition, Type as PhysicalType};
+use parquet::column::writer::ColumnWriterImpl;
+use parquet::data_type::{BoolType, ByteArray, ByteArrayType, DataType, DoubleType, FloatType,
+                         Int32Type, Int64Type};
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::{ChunkReader, FileReader, SerializedFileReader};
+use parquet::file::writer::SerializedFileWriter;
+use parquet::record::{Field, Row};
+use parquet::schema::types::{ColumnDescriptor, Type, TypePtr};
+
+use nullvec::prelude::{Array, Scalar, Nullable};
+
+use convert::scalars_to_array;
+use frame::DataFrame;
+use indexer::Indexer;
+use traits::{Slicer, RowIndex};
+
+use super::stats::{ColumnStats, Predicate, parse_stats_metadata, stats_metadata};
+
+const DEFAULT_ROW_GROUP_SIZE: usize = 1024 * 1024;
+
+// /////////////////////////////////////////////////////////////////////////////
+// Read
+// /////////////////////////////////////////////////////////////////////////////
+
+/// dtype of Parquet column. `isize` and `usize` columns are written as
+/// 64-bit integers, so they are read as `i64` and `u64`.
+fn column_dtype(column: &ColumnDescriptor) -> &'static str {
+    match (column.physical_type(), column.converted_type()) {
+        (PhysicalType::BOOLEAN, _) => "bool",
+        (PhysicalType::INT32, ConvertedType::INT_8) => "i8",
+        (PhysicalType::INT32, ConvertedType::INT_16) => "i16",
+        (PhysicalType::INT32, ConvertedType::UINT_8) => "u8",
+        (PhysicalType::INT32, ConvertedType::UINT_16) => "u16",
+        (PhysicalType::INT32, ConvertedType::UINT_32) => "u32",
+        (PhysicalType::INT32, _) => "i32",
+        (PhysicalType::INT64, ConvertedType::UINT_64) => "u64",
+        (PhysicalType::INT64, _) => "i64",
+        (PhysicalType::FLOAT, _) => "f32",
+        (PhysicalType::DOUBLE, _) => "f64",
+        _ => "str",
+    }
+}
+
+fn field_to_scalar(field: &Field) -> Scalar {
+    match *field {
+        Field::Null => Scalar::Null,
+        Field::Bool(v) => Scalar::bool(v),
+        Field::Byte(v) => Scalar::i8(v),
+        Field::Short(v) => Scalar::i16(v),
+        Field::Int(v) | Field::Date(v) => Scalar::i32(v),
+        Field::Long(v) |
+        Field::TimestampMillis(v) |
+        Field::TimestampMicros(v) => Scalar::i64(v),
+        Field::UByte(v) => Scalar::u8(v),
+        Field::UShort(v) => Scalar::u16(v),
+        Field::UInt(v) => Scalar::u32(v),
+        Field::ULong(v) => Scalar::u64(v),
+        Field::Float(v) => Scalar::f32(v),
+        Field::Double(v) => Scalar::f64(v),
+        Field::Str(ref v) => Scalar::String(v.clone()),
+        ref other => Scalar::String(other.to_string()),
+    }
+}
+
+/// Projected schema and resulting column names / dtypes.
+struct Projection {
+    schema: Option<Type>,
+    columns: Vec<String>,
+    dtypes: Vec<&'static str>,
+}
+
+fn projection<R: ChunkReader + 'static>(
+    reader: &SerializedFileReader<R>,
+    columns: Option<&[&str]>,
+) -> Result<Projection, ParquetError> {
+    let descr = reader.metadata().file_metadata().schema_descr();
+    let root_fields = descr.root_schema().get_fields();
+    // leaf columns correspond to top-level fields only if the schema is flat
+    if root_fields.iter().any(|x| x.is_group()) {
+        return Err(ParquetError::General("nested schema is not supported".to_string()));
+    }
+    let names: Vec<String> = match columns {
+        Some(cols) => cols.iter().map(|x| x.to_string()).collect(),
+        None => descr.columns().iter().map(|x| x.name().to_string()).collect(),
+    };
+
+    let mut fields: Vec<TypePtr> = Vec::with_capacity(names.len());
+    let mut dtypes: Vec<&'static str> = Vec::with_capacity(names.len());
+    for name in &names {
+        let loc = root_fields
+            .iter()
+            .position(|x| x.name() == name)
+            .ok_or_else(|| ParquetError::General(format!("column not found: {}", name)))?;
+        fields.push(root_fields[loc].clone());
+        dtypes.push(column_dtype(&descr.column(loc)));
+    }
+    let schema = match columns {
+        Some(_) => Some(Type::group_type_builder(descr.root_schema().name())
+            .with_fields(fields)
+            .build()?),
+        None => None,
+    };
+    Ok(Projection {
+        schema,
+        columns: names,
+        dtypes,
+    })
+}
+
+fn rows_to_frame<'a, T>(
+    rows: T,
+    proj: &Projection,
+    offset: usize,
+) -> Result<DataFrame<'a, 'a, 'a, usize, String>, ParquetError>
+where
+    T: Iterator<Item = Result<Row, ParquetError>>,
+{
+    let mut colvecs: Vec<Vec<Scalar>> = vec![vec![]; proj.columns.len()];
+    for row in rows {
+        let row = row?;
+        // fields are ordered as the projected schema
+        for (column, (_, field)) in colvecs.iter_mut().zip(row.get_column_iter()) {
+            column.push(field_to_scalar(field));
+        }
+    }
+    let len = colvecs.first().map_or(0, |x| x.len());
+    let arrays: Vec<Array> = colvecs
+        .into_iter()
+        .zip(&proj.dtypes)
+        .map(|(values, dtype)| scalars_to_array(values, dtype))
+        .collect();
+    let index: Indexer<usize> = (offset..offset + len).collect::<Vec<usize>>().into();
+    Ok(DataFrame::from_vec(arrays, index, proj.columns.clone()))
+}
+
+/// Iterator which reads Parquet file as `DataFrame` per row group.
+///
+/// Index continues over row groups, thus the n-th row is labeled as n.
+pub struct ParquetChunks<R: ChunkReader + 'static> {
+    reader: SerializedFileReader<R>,
+    projection: Projection,
+    row_group: usize,
+    offset: usize,
+}
+
+impl<R: ChunkReader + 'static> Iterator for ParquetChunks<R> {
+    type Item = Result<DataFrame<'static, 'static, 'static, usize, String>, ParquetError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row_group >= self.reader.num_row_groups() {
+            return None;
+        }
+        let res = self.reader.get_row_group(self.row_group).and_then(|rg| {
+            let rows = rg.get_row_iter(self.projection.schema.clone())?;
+            rows_to_frame(rows, &self.projection, self.offset)
+        });
+        self.row_group += 1;
+        if let Ok(ref df) = res {
+            self.offset += df.len();
+        }
+        Some(res)
+    }
+}
+
+fn file_stats<R: ChunkReader + 'static>(
+    reader: &SerializedFileReader<R>,
+    proj: &Projection,
+) -> HashMap<String, ColumnStats> {
+    let dtypes: HashMap<String, &'static str> = proj.columns
+        .iter()
+        .cloned()
+        .zip(proj.dtypes.iter().cloned())
+        .collect();
+    match reader.metadata().file_metadata().key_value_metadata() {
+        Some(kvs) => parse_stats_metadata(kvs, &dtypes),
+        None => HashMap::new(),
+    }
+}
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Read Parquet file into single `DataFrame`.
+    ///
+    /// If `columns` is specified, only the given columns are read in the given order.
+    /// Files having nested (group) columns are not supported.
+    pub fn read_parquet<R: ChunkReader + 'static>(
+        reader: R,
+        columns: Option<&[&str]>,
+    ) -> Result<DataFrame<'a, 'a, 'a, usize, String>, ParquetError> {
+        let reader = SerializedFileReader::new(reader)?;
+        let proj = projection(&reader, columns)?;
+        let rows = reader.get_row_iter(proj.schema.clone())?;
+        rows_to_frame(rows, &proj, 0)
+    }
+
+    /// Read Parquet file, only rows which satisfy `predicate` are returned.
+    ///
+    /// If column statistics in the footer exclude the predicate, rows are not read.
+    pub fn read_parquet_filter<R: ChunkReader + 'static>(
+        reader: R,
+        predicate: &Predicate,
+    ) -> Result<DataFrame<'a, 'a, 'a, usize, String>, ParquetError> {
+        let reader = SerializedFileReader::new(reader)?;
+        let proj = projection(&reader, None)?;
+        if !predicate.may_match(&file_stats(&reader, &proj)) {
+            return rows_to_frame(iter::empty(), &proj, 0);
+        }
+        let rows = reader.get_row_iter(None)?;
+        let df = rows_to_frame(rows, &proj, 0)?;
+        Ok(predicate.filter(&df))
+    }
+
+    /// Read column statistics stored in the footer of Parquet file.
+    pub fn read_parquet_stats<R: ChunkReader + 'static>(
+        reader: R,
+    ) -> Result<HashMap<String, ColumnStats>, ParquetError> {
+        let reader = SerializedFileReader::new(reader)?;
+        let proj = projection(&reader, None)?;
+        Ok(file_stats(&reader, &proj))
+    }
+
+    /// Read Parquet file as `DataFrame` chunks, one per row group.
+    pub fn read_parquet_chunks<R: ChunkReader + 'static>(
+        reader: R,
+        columns: Option<&[&str]>,
+    ) -> Result<ParquetChunks<R>, ParquetError> {
+        let reader = SerializedFileReader::new(reader)?;
+        let proj = projection(&reader, columns)?;
+        Ok(ParquetChunks {
+            reader,
+            projection: proj,
+            row_group: 0,
+            offset: 0,
+        })
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Write
+// /////////////////////////////////////////////////////////////////////////////
+
+fn column_type(name: &str, array: &Array) -> Result<Type, ParquetError> {
+    let (physical, converted) = match *array {
+        Array::Int64Array(_) | Array::IsizeArray(_) => (PhysicalType::INT64, ConvertedType::NONE),
+        Array::Int32Array(_) => (PhysicalType::INT32, ConvertedType::NONE),
+        Array::Int16Array(_) => (PhysicalType::INT32, ConvertedType::INT_16),
+        Array::Int8Array(_) => (PhysicalType::INT32, ConvertedType::INT_8),
+        Array::UInt64Array(_) |
+        Array::UsizeArray(_) => (PhysicalType::INT64, ConvertedType::UINT_64),
+        Array::UInt32Array(_) => (PhysicalType::INT32, ConvertedType::UINT_32),
+        Array::UInt16Array(_) => (PhysicalType::INT32, ConvertedType::UINT_16),
+        Array::UInt8Array(_) => (PhysicalType::INT32, ConvertedType::UINT_8),
+        Array::Float64Array(_) => (PhysicalType::DOUBLE, ConvertedType::NONE),
+        Array::Float32Array(_) => (PhysicalType::FLOAT, ConvertedType::NONE),
+        Array::BoolArray(_) => (PhysicalType::BOOLEAN, ConvertedType::NONE),
+        Array::StringArray(_) => (PhysicalType::BYTE_ARRAY, ConvertedType::UTF8),
+    };
+    Type::primitive_type_builder(name, physical)
+        .with_repetition(Repetition::OPTIONAL)
+        .with_converted_type(converted)
+        .build()
+}
+
+fn write_nullable<T, D, F>(
+    writer: &mut ColumnWriterImpl<D>,
+    values: Vec<Nullable<T>>,
+    conv: F,
+) -> Result<(), ParquetError>
+where
+    T: ::nullvec::prelude::dev::NullStorable,
+    D: DataType,
+    F: Fn(T) -> D::T,
+{
+    let mut not_null: Vec<D::T> = Vec::with_capacity(values.len());
+    let mut levels: Vec<i16> = Vec::with_capacity(values.len());
+    for value in values {
+        match value {
+            Nullable::Value(v) => {
+                not_null.push(conv(v));
+                levels.push(1);
+            }
+            Nullable::Null => levels.push(0),
+        }
+    }
+    writer.write_batch(&not_null, Some(&levels), None)?;
+    Ok(())
+}
+
+macro_rules! write_array {
+    ($column:ident, $array:ident, $($variant:ident => $dt:ty, $conv:expr),*) => {
+        match $array {
+            $(Array::$variant(vals) => write_nullable($column.typed::<$dt>(), vals.into(), $conv)?,)*
+        }
+    }
+}
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + ToString,
+{
+    /// Write as Parquet file. Index is not written.
+    ///
+    /// `isize` and `usize` columns are stored as 64-bit integers, and are read
+    /// back as `i64` and `u64`.
+    pub fn to_parquet<W: Write + Send>(&self, writer: W) -> Result<(), ParquetError> {
+        self.to_parquet_chunked(writer, DEFAULT_ROW_GROUP_SIZE)
+    }
+
+    /// Write as Parquet file, splitting rows into row groups of `row_group_size`.
+    ///
+    /// Statistics of each column are stored in the footer, see `ColumnStats`.
+    pub fn to_parquet_chunked<W: Write + Send>(
+        &self,
+        writer: W,
+        row_group_size: usize,
+    ) -> Result<(), ParquetError> {
+        assert!(row_group_size > 0, "row_group_size must be positive");
+
+        let mut fields: Vec<TypePtr> = Vec::with_capacity(self.values.len());
+        for (name, array) in self.columns.values.iter().zip(&self.values) {
+            fields.push(Arc::new(column_type(&name.to_string(), array)?));
+        }
+        let schema = Type::group_type_builder("schema").with_fields(fields).build()?;
+        let props = WriterProperties::builder()
+            .set_key_value_metadata(Some(stats_metadata(self)))
+            .build();
+        let mut writer = SerializedFileWriter::new(writer, Arc::new(schema), Arc::new(props))?;
+
+        let mut start = 0;
+        while start < self.len() {
+            let end = cmp::min(start + row_group_size, self.len());
+            let locs: Vec<usize> = (start..end).collect();
+
+            let mut rg = writer.next_row_group()?;
+            for array in &self.values {
+                let mut column = match rg.next_column()? {
+                    Some(column) => column,
+                    None => return Err(ParquetError::General("schema mismatch".to_string())),
+                };
+                let chunk = array.ilocs(&locs);
+                write_array!(column, chunk,
+                             Int64Array => Int64Type, |x| x,
+                             Int32Array => Int32Type, |x| x,
+                             Int16Array => Int32Type, |x| x as i32,
+                             Int8Array => Int32Type, |x| x as i32,
+                             IsizeArray => Int64Type, |x| x as i64,
+                             UInt64Array => Int64Type, |x| x as i64,
+                             UInt32Array => Int32Type, |x| x as i32,
+                             UInt16Array => Int32Type, |x| x as i32,
+                             UInt8Array => Int32Type, |x| x as i32,
+                             UsizeArray => Int64Type, |x| x as i64,
+                             Float64Array => DoubleType, |x| x,
+                             Float32Array => FloatType, |x| x,
+                             BoolArray => BoolType, |x| x,
+                             StringArray => ByteArrayType, |x: String| ByteArray::from(x.into_bytes()));
+                column.close()?;
+            }
+            rg.close()?;
+            start = end;
+        }
+        writer.close()?;
+        Ok(())
+    }
+}
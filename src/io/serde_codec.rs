@@ -0,0 +1,410 @@
+use std::error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::de::value::MapDeserializer;
+use serde::ser::{self, Impossible, Serialize, SerializeMap, SerializeStruct};
+
+use nullvec::prelude::Scalar;
+
+use super::codec::RowCodec;
+
+/// Error raised in `SerdeCodec`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CodecError {
+    message: String,
+}
+
+impl CodecError {
+    fn new<T: fmt::Display>(message: T) -> Self {
+        CodecError { message: message.to_string() }
+    }
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for CodecError {}
+
+impl ser::Error for CodecError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        CodecError::new(msg)
+    }
+}
+
+impl de::Error for CodecError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        CodecError::new(msg)
+    }
+}
+
+/// `RowCodec` for flat structs (or maps) implementing serde traits.
+///
+/// Each field is mapped to a column, nested values are not supported.
+/// `Option` fields are encoded as `Null` if `None`.
+pub struct SerdeCodec<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> SerdeCodec<T> {
+    pub fn new() -> Self {
+        SerdeCodec { phantom: PhantomData }
+    }
+}
+
+impl<T> Default for SerdeCodec<T> {
+    fn default() -> Self {
+        SerdeCodec::new()
+    }
+}
+
+impl<T> RowCodec for SerdeCodec<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    type Row = T;
+    type Error = CodecError;
+
+    fn encode(&self, row: &T) -> Result<Vec<(String, Scalar)>, CodecError> {
+        row.serialize(RowSerializer)
+    }
+
+    fn decode(&self, columns: &[String], values: Vec<Scalar>) -> Result<T, CodecError> {
+        let pairs = columns.iter().cloned().zip(values.into_iter().map(ScalarDeserializer));
+        T::deserialize(MapDeserializer::new(pairs))
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Serialize
+// /////////////////////////////////////////////////////////////////////////////
+
+macro_rules! unsupported {
+    ($($method:ident($($arg:ty),*) -> $ret:ty;)*) => {
+        $(fn $method(self, $(_: $arg),*) -> Result<$ret, CodecError> {
+            Err(CodecError::new(concat!("unsupported value: ", stringify!($method))))
+        })*
+    }
+}
+
+/// Serialize a field value to `Scalar`.
+struct ScalarSerializer;
+
+impl ser::Serializer for ScalarSerializer {
+    type Ok = Scalar;
+    type Error = CodecError;
+    type SerializeSeq = Impossible<Scalar, CodecError>;
+    type SerializeTuple = Impossible<Scalar, CodecError>;
+    type SerializeTupleStruct = Impossible<Scalar, CodecError>;
+    type SerializeTupleVariant = Impossible<Scalar, CodecError>;
+    type SerializeMap = Impossible<Scalar, CodecError>;
+    type SerializeStruct = Impossible<Scalar, CodecError>;
+    type SerializeStructVariant = Impossible<Scalar, CodecError>;
+
+    fn serialize_bool(self, v: bool) -> Result<Scalar, CodecError> {
+        Ok(Scalar::bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Scalar, CodecError> {
+        Ok(Scalar::i8(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Scalar, CodecError> {
+        Ok(Scalar::i16(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Scalar, CodecError> {
+        Ok(Scalar::i32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Scalar, CodecError> {
+        Ok(Scalar::i64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Scalar, CodecError> {
+        Ok(Scalar::u8(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Scalar, CodecError> {
+        Ok(Scalar::u16(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Scalar, CodecError> {
+        Ok(Scalar::u32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Scalar, CodecError> {
+        Ok(Scalar::u64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Scalar, CodecError> {
+        Ok(Scalar::f32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Scalar, CodecError> {
+        Ok(Scalar::f64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Scalar, CodecError> {
+        Ok(Scalar::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Scalar, CodecError> {
+        Ok(Scalar::String(v.to_string()))
+    }
+
+    fn serialize_none(self) -> Result<Scalar, CodecError> {
+        Ok(Scalar::Null)
+    }
+
+    fn serialize_some<V: ?Sized + Serialize>(self, value: &V) -> Result<Scalar, CodecError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Scalar, CodecError> {
+        Ok(Scalar::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Scalar, CodecError> {
+        Ok(Scalar::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Scalar, CodecError> {
+        Ok(Scalar::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<V: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &V,
+    ) -> Result<Scalar, CodecError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<V: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &V,
+    ) -> Result<Scalar, CodecError> {
+        Err(CodecError::new("unsupported value: newtype variant"))
+    }
+
+    unsupported! {
+        serialize_bytes(&[u8]) -> Scalar;
+        serialize_seq(Option<usize>) -> Self::SerializeSeq;
+        serialize_tuple(usize) -> Self::SerializeTuple;
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+        serialize_tuple_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeTupleVariant;
+        serialize_map(Option<usize>) -> Self::SerializeMap;
+        serialize_struct(&'static str, usize) -> Self::SerializeStruct;
+        serialize_struct_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeStructVariant;
+    }
+}
+
+/// Serialize a struct or map to (column name, value) pairs.
+struct RowSerializer;
+
+struct RowFields {
+    fields: Vec<(String, Scalar)>,
+    key: Option<String>,
+}
+
+impl ser::Serializer for RowSerializer {
+    type Ok = Vec<(String, Scalar)>;
+    type Error = CodecError;
+    type SerializeSeq = Impossible<Self::Ok, CodecError>;
+    type SerializeTuple = Impossible<Self::Ok, CodecError>;
+    type SerializeTupleStruct = Impossible<Self::Ok, CodecError>;
+    type SerializeTupleVariant = Impossible<Self::Ok, CodecError>;
+    type SerializeMap = RowFields;
+    type SerializeStruct = RowFields;
+    type SerializeStructVariant = Impossible<Self::Ok, CodecError>;
+
+    fn serialize_map(self, len: Option<usize>) -> Result<RowFields, CodecError> {
+        Ok(RowFields {
+            fields: Vec::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<RowFields, CodecError> {
+        Ok(RowFields {
+            fields: Vec::with_capacity(len),
+            key: None,
+        })
+    }
+
+    fn serialize_some<V: ?Sized + Serialize>(self, _value: &V) -> Result<Self::Ok, CodecError> {
+        Err(CodecError::new("row must be a struct or map"))
+    }
+
+    fn serialize_newtype_struct<V: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &V,
+    ) -> Result<Self::Ok, CodecError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<V: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &V,
+    ) -> Result<Self::Ok, CodecError> {
+        Err(CodecError::new("row must be a struct or map"))
+    }
+
+    unsupported! {
+        serialize_bool(bool) -> Self::Ok;
+        serialize_i8(i8) -> Self::Ok;
+        serialize_i16(i16) -> Self::Ok;
+        serialize_i32(i32) -> Self::Ok;
+        serialize_i64(i64) -> Self::Ok;
+        serialize_u8(u8) -> Self::Ok;
+        serialize_u16(u16) -> Self::Ok;
+        serialize_u32(u32) -> Self::Ok;
+        serialize_u64(u64) -> Self::Ok;
+        serialize_f32(f32) -> Self::Ok;
+        serialize_f64(f64) -> Self::Ok;
+        serialize_char(char) -> Self::Ok;
+        serialize_str(&str) -> Self::Ok;
+        serialize_bytes(&[u8]) -> Self::Ok;
+        serialize_none() -> Self::Ok;
+        serialize_unit() -> Self::Ok;
+        serialize_unit_struct(&'static str) -> Self::Ok;
+        serialize_unit_variant(&'static str, u32, &'static str) -> Self::Ok;
+        serialize_seq(Option<usize>) -> Self::SerializeSeq;
+        serialize_tuple(usize) -> Self::SerializeTuple;
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+        serialize_tuple_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeTupleVariant;
+        serialize_struct_variant(&'static str, u32, &'static str, usize)
+            -> Self::SerializeStructVariant;
+    }
+}
+
+impl SerializeStruct for RowFields {
+    type Ok = Vec<(String, Scalar)>;
+    type Error = CodecError;
+
+    fn serialize_field<V: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<(), CodecError> {
+        let value = value.serialize(ScalarSerializer)?;
+        self.fields.push((key.to_string(), value));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, CodecError> {
+        Ok(self.fields)
+    }
+}
+
+impl SerializeMap for RowFields {
+    type Ok = Vec<(String, Scalar)>;
+    type Error = CodecError;
+
+    fn serialize_key<K: ?Sized + Serialize>(&mut self, key: &K) -> Result<(), CodecError> {
+        let key: String = match key.serialize(ScalarSerializer)? {
+            Scalar::String(s) => s,
+            other => other.to_string(),
+        };
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<V: ?Sized + Serialize>(&mut self, value: &V) -> Result<(), CodecError> {
+        let key = self.key.take().ok_or_else(
+            || CodecError::new("value is serialized before key"),
+        )?;
+        let value = value.serialize(ScalarSerializer)?;
+        self.fields.push((key, value));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, CodecError> {
+        Ok(self.fields)
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Deserialize
+// /////////////////////////////////////////////////////////////////////////////
+
+/// Deserialize a field value from `Scalar`.
+struct ScalarDeserializer(Scalar);
+
+impl<'de> IntoDeserializer<'de, CodecError> for ScalarDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ScalarDeserializer {
+    type Error = CodecError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CodecError> {
+        match self.0 {
+            Scalar::Null => visitor.visit_unit(),
+            Scalar::bool(v) => visitor.visit_bool(v),
+            Scalar::i8(v) => visitor.visit_i8(v),
+            Scalar::i16(v) => visitor.visit_i16(v),
+            Scalar::i32(v) => visitor.visit_i32(v),
+            Scalar::i64(v) => visitor.visit_i64(v),
+            Scalar::isize(v) => visitor.visit_i64(v as i64),
+            Scalar::u8(v) => visitor.visit_u8(v),
+            Scalar::u16(v) => visitor.visit_u16(v),
+            Scalar::u32(v) => visitor.visit_u32(v),
+            Scalar::u64(v) => visitor.visit_u64(v),
+            Scalar::usize(v) => visitor.visit_u64(v as u64),
+            Scalar::f32(v) => visitor.visit_f32(v),
+            Scalar::f64(v) => visitor.visit_f64(v),
+            Scalar::String(v) => visitor.visit_string(v),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, CodecError> {
+        match self.0 {
+            Scalar::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, CodecError> {
+        match self.0 {
+            // unit variant
+            Scalar::String(v) => visitor.visit_enum(v.into_deserializer()),
+            other => Err(CodecError::new(format!("unable to deserialize {} as enum", other))),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
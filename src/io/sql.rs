@@ -0,0 +1,223 @@
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+
+use convert::{infer_array, scalars_to_array};
+use frame::DataFrame;
+use indexer::Indexer;
+
+/// Result of SQL query, which is column names, dtypes and rows.
+///
+/// dtype is `None` if it can't be determined from the column type,
+/// then it is inferred from values.
+pub struct SqlResult {
+    pub columns: Vec<String>,
+    pub dtypes: Vec<Option<&'static str>>,
+    pub rows: Vec<Vec<Scalar>>,
+}
+
+/// Database connection which can be used in `DataFrame::from_sql`.
+pub trait SqlSource {
+    type Error;
+
+    fn query_scalars(&mut self, query: &str) -> Result<SqlResult, Self::Error>;
+}
+
+/// Whether `value` can be stored as `dtype` without loss.
+///
+/// Declared column type may not restrict stored values, e.g. SQLite only
+/// sets type affinity, then the column dtype is inferred from values.
+fn fits_dtype(value: &Scalar, dtype: &str) -> bool {
+    match *value {
+        Scalar::Null => true,
+        Scalar::bool(_) => dtype == "bool",
+        Scalar::String(_) => dtype == "str",
+        Scalar::f64(_) | Scalar::f32(_) => dtype == "f64" || dtype == "f32",
+        _ => dtype != "bool" && dtype != "str",
+    }
+}
+
+impl<'a, I, C> DataFrame<'a, 'a, 'a, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Execute SQL query and load its result as `DataFrame`.
+    pub fn from_sql<X: SqlSource>(
+        query: &str,
+        conn: &mut X,
+    ) -> Result<DataFrame<'a, 'a, 'a, usize, String>, X::Error> {
+        let result = conn.query_scalars(query)?;
+
+        let nrows = result.rows.len();
+        let mut colvecs: Vec<Vec<Scalar>> = vec![Vec::with_capacity(nrows); result.columns.len()];
+        for row in result.rows {
+            for (column, value) in colvecs.iter_mut().zip(row) {
+                column.push(value);
+            }
+        }
+        let arrays: Vec<Array> = colvecs
+            .into_iter()
+            .zip(&result.dtypes)
+            .map(|(values, dtype)| match *dtype {
+                Some(dtype) if values.iter().all(|x| fits_dtype(x, dtype)) => {
+                    scalars_to_array(values, dtype)
+                }
+                _ => infer_array(values),
+            })
+            .collect();
+        let index: Indexer<usize> = Indexer::<usize>::from_len(nrows);
+        Ok(DataFrame::from_vec(arrays, index, result.columns))
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+mod sqlite {
+
+    use rusqlite::{Connection, Error};
+    use rusqlite::types::ValueRef;
+
+    use nullvec::prelude::Scalar;
+
+    use super::{SqlResult, SqlSource};
+
+    /// dtype from declared column type, based on SQLite type affinity.
+    fn decl_dtype(decl: Option<&str>) -> Option<&'static str> {
+        let decl = match decl {
+            Some(decl) => decl.to_uppercase(),
+            None => return None,
+        };
+        if decl.contains("BOOL") {
+            Some("bool")
+        } else if decl.contains("INT") {
+            Some("i64")
+        } else if decl.contains("CHAR") || decl.contains("CLOB") || decl.contains("TEXT") {
+            Some("str")
+        } else if decl.contains("REAL") || decl.contains("FLOA") || decl.contains("DOUB") {
+            Some("f64")
+        } else {
+            None
+        }
+    }
+
+    fn to_scalar(value: ValueRef, dtype: Option<&str>) -> Scalar {
+        match value {
+            ValueRef::Null => Scalar::Null,
+            ValueRef::Integer(v) if dtype == Some("bool") => Scalar::bool(v != 0),
+            ValueRef::Integer(v) => Scalar::i64(v),
+            ValueRef::Real(v) => Scalar::f64(v),
+            ValueRef::Text(v) | ValueRef::Blob(v) => {
+                Scalar::String(String::from_utf8_lossy(v).into_owned())
+            }
+        }
+    }
+
+    impl SqlSource for Connection {
+        type Error = Error;
+
+        fn query_scalars(&mut self, query: &str) -> Result<SqlResult, Error> {
+            let mut stmt = self.prepare(query)?;
+            let columns: Vec<String> = stmt.column_names().iter().map(|x| x.to_string()).collect();
+            let dtypes: Vec<Option<&'static str>> = stmt.columns()
+                .iter()
+                .map(|x| decl_dtype(x.decl_type()))
+                .collect();
+
+            let mut rows: Vec<Vec<Scalar>> = vec![];
+            let mut cursor = stmt.query([])?;
+            while let Some(row) = cursor.next()? {
+                let mut values: Vec<Scalar> = Vec::with_capacity(columns.len());
+                for (i, dtype) in dtypes.iter().enumerate() {
+                    values.push(to_scalar(row.get_ref(i)?, *dtype));
+                }
+                rows.push(values);
+            }
+            Ok(SqlResult {
+                columns,
+                dtypes,
+                rows,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+mod postgresql {
+
+    use postgres::{Client, Error, Row};
+    use postgres::types::Type;
+
+    use nullvec::prelude::{Scalar, Nullable};
+
+    use super::{SqlResult, SqlSource};
+
+    fn type_dtype(ty: &Type) -> Option<&'static str> {
+        match *ty {
+            Type::BOOL => Some("bool"),
+            Type::CHAR => Some("i8"),
+            Type::INT2 => Some("i16"),
+            Type::INT4 => Some("i32"),
+            Type::INT8 => Some("i64"),
+            Type::OID => Some("u32"),
+            Type::FLOAT4 => Some("f32"),
+            Type::FLOAT8 => Some("f64"),
+            Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME => Some("str"),
+            _ => None,
+        }
+    }
+
+    fn get<T>(row: &Row, i: usize) -> Result<Scalar, Error>
+    where
+        T: for<'a> ::postgres::types::FromSql<'a> + ::nullvec::prelude::dev::NullStorable,
+        Scalar: From<Nullable<T>>,
+    {
+        let value: Option<T> = row.try_get(i)?;
+        Ok(match value {
+            Some(v) => Scalar::from(Nullable::Value(v)),
+            None => Scalar::Null,
+        })
+    }
+
+    /// Columns of other than bool, integer, float and text types must be cast
+    /// to text in the query, e.g. `SELECT price::text`, otherwise an error is
+    /// returned.
+    impl SqlSource for Client {
+        type Error = Error;
+
+        fn query_scalars(&mut self, query: &str) -> Result<SqlResult, Error> {
+            let stmt = self.prepare(query)?;
+            let columns: Vec<String> = stmt.columns().iter().map(|x| x.name().to_string()).collect();
+            let dtypes: Vec<Option<&'static str>> = stmt.columns()
+                .iter()
+                .map(|x| type_dtype(x.type_()))
+                .collect();
+
+            let mut rows: Vec<Vec<Scalar>> = vec![];
+            for row in self.query(&stmt, &[])? {
+                let mut values: Vec<Scalar> = Vec::with_capacity(columns.len());
+                for (i, dtype) in dtypes.iter().enumerate() {
+                    let value = match *dtype {
+                        Some("bool") => get::<bool>(&row, i)?,
+                        Some("i8") => get::<i8>(&row, i)?,
+                        Some("i16") => get::<i16>(&row, i)?,
+                        Some("i32") => get::<i32>(&row, i)?,
+                        Some("i64") => get::<i64>(&row, i)?,
+                        Some("u32") => get::<u32>(&row, i)?,
+                        Some("f32") => get::<f32>(&row, i)?,
+                        Some("f64") => get::<f64>(&row, i)?,
+                        // other types are read as text, an error is returned if
+                        // they can't be, e.g. NUMERIC or TIMESTAMP
+                        _ => get::<String>(&row, i)?,
+                    };
+                    values.push(value);
+                }
+                rows.push(values);
+            }
+            Ok(SqlResult {
+                columns,
+                dtypes,
+                rows,
+            })
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use parquet::file::metadata::KeyValue;
+
+use nullvec::prelude::{Array, Scalar, Nullable};
+
+use frame::DataFrame;
+use indexer::Indexer;
+use traits::{Slicer, RowIndex};
+
+const STATS_PREFIX: &str = "brassfibre.stats.";
+
+/// Statistics of a column, stored in the footer of Parquet files.
+///
+/// `min` and `max` are `Null` if the column has no comparable values, and
+/// `count` is the number of non-null values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnStats {
+    pub min: Scalar,
+    pub max: Scalar,
+    pub null_count: usize,
+    pub count: usize,
+}
+
+/// Compare scalars, numerics are compared across dtypes.
+///
+/// Returns `None` for `Null`, NaN or incomparable dtypes.
+fn compare(left: &Scalar, right: &Scalar) -> Option<Ordering> {
+    match (left, right) {
+        (Scalar::Null, _) | (_, Scalar::Null) => None,
+        (Scalar::String(l), Scalar::String(r)) => Some(l.cmp(r)),
+        (Scalar::bool(l), Scalar::bool(r)) => Some(l.cmp(r)),
+        (Scalar::String(_), _) | (_, Scalar::String(_)) |
+        (Scalar::bool(_), _) | (_, Scalar::bool(_)) => None,
+        _ => {
+            match (as_integer(left), as_integer(right)) {
+                (Some(l), Some(r)) => Some(l.cmp(&r)),
+                _ => {
+                    match (left.as_f64(), right.as_f64()) {
+                        (Nullable::Value(l), Nullable::Value(r)) => l.partial_cmp(&r),
+                        _ => None,
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn as_integer(value: &Scalar) -> Option<i128> {
+    match *value {
+        Scalar::i64(v) => Some(i128::from(v)),
+        Scalar::i32(v) => Some(i128::from(v)),
+        Scalar::i16(v) => Some(i128::from(v)),
+        Scalar::i8(v) => Some(i128::from(v)),
+        Scalar::isize(v) => Some(v as i128),
+        Scalar::u64(v) => Some(i128::from(v)),
+        Scalar::u32(v) => Some(i128::from(v)),
+        Scalar::u16(v) => Some(i128::from(v)),
+        Scalar::u8(v) => Some(i128::from(v)),
+        Scalar::usize(v) => Some(v as i128),
+        _ => None,
+    }
+}
+
+impl ColumnStats {
+    /// Compute statistics of the array.
+    pub fn from_array(array: &Array) -> ColumnStats {
+        let mut min = Scalar::Null;
+        let mut max = Scalar::Null;
+        let mut null_count = 0;
+        for i in 0..array.len() {
+            let value = array.iloc(&i);
+            if value == Scalar::Null {
+                null_count += 1;
+                continue;
+            }
+            // NaN is excluded from min / max
+            if compare(&value, &value).is_none() {
+                continue;
+            }
+            if min == Scalar::Null || compare(&value, &min) == Some(Ordering::Less) {
+                min = value.clone();
+            }
+            if max == Scalar::Null || compare(&value, &max) == Some(Ordering::Greater) {
+                max = value;
+            }
+        }
+        ColumnStats {
+            min,
+            max,
+            null_count,
+            count: array.len() - null_count,
+        }
+    }
+
+    fn to_key_values(&self, column: &str) -> Vec<KeyValue> {
+        let key = |name: &str| format!("{}{}.{}", STATS_PREFIX, column, name);
+        let mut kvs = vec![
+            KeyValue::new(key("null_count"), self.null_count.to_string()),
+            KeyValue::new(key("count"), self.count.to_string()),
+        ];
+        if self.min != Scalar::Null {
+            kvs.push(KeyValue::new(key("min"), self.min.to_string()));
+            kvs.push(KeyValue::new(key("max"), self.max.to_string()));
+        }
+        kvs
+    }
+}
+
+/// Footer metadata storing statistics of each column.
+pub fn stats_metadata<I, C>(df: &DataFrame<I, C>) -> Vec<KeyValue>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + ToString,
+{
+    let mut kvs: Vec<KeyValue> = vec![];
+    for (column, array) in df.columns.values.iter().zip(&df.values) {
+        kvs.extend(ColumnStats::from_array(array).to_key_values(&column.to_string()));
+    }
+    kvs
+}
+
+/// Parse statistics from footer metadata, `dtypes` are dtype of each column.
+pub fn parse_stats_metadata(
+    kvs: &[KeyValue],
+    dtypes: &HashMap<String, &'static str>,
+) -> HashMap<String, ColumnStats> {
+    let mut stats: HashMap<String, ColumnStats> = HashMap::new();
+    for kv in kvs {
+        let (rest, value) = match (kv.key.strip_prefix(STATS_PREFIX), kv.value.as_ref()) {
+            (Some(rest), Some(value)) => (rest, value),
+            _ => continue,
+        };
+        // column name may contain ".", the last part is statistic name
+        let (column, name) = match rest.rfind('.') {
+            Some(pos) => (&rest[..pos], &rest[pos + 1..]),
+            None => continue,
+        };
+        let dtype = match dtypes.get(column) {
+            Some(dtype) => dtype,
+            None => continue,
+        };
+        let entry = stats.entry(column.to_string()).or_insert(ColumnStats {
+            min: Scalar::Null,
+            max: Scalar::Null,
+            null_count: 0,
+            count: 0,
+        });
+        match name {
+            "null_count" => entry.null_count = value.parse().unwrap_or(0),
+            "count" => entry.count = value.parse().unwrap_or(0),
+            "min" => entry.min = parse_scalar(value, dtype),
+            "max" => entry.max = parse_scalar(value, dtype),
+            _ => {}
+        }
+    }
+    stats
+}
+
+/// Parse `value` written by `Display` of `dtype`. Floats are parsed as their
+/// own dtype, as a shortest `f32` representation differs from the `f64` one.
+fn parse_scalar(value: &str, dtype: &str) -> Scalar {
+    let parsed = match dtype {
+        "str" => return Scalar::String(value.to_string()),
+        "bool" => value.parse::<bool>().ok().map(Scalar::bool),
+        "f64" => value.parse::<f64>().ok().map(Scalar::f64),
+        "f32" => value.parse::<f32>().ok().map(Scalar::f32),
+        "u64" | "u32" | "u16" | "u8" | "usize" => value.parse::<u64>().ok().map(Scalar::u64),
+        _ => value.parse::<i64>().ok().map(Scalar::i64),
+    };
+    parsed.unwrap_or(Scalar::Null)
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Predicate
+// /////////////////////////////////////////////////////////////////////////////
+
+/// Row filter which can be pushed down to files using column statistics.
+///
+/// Comparisons against `Null` values are regarded as `false`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Predicate {
+    Eq(String, Scalar),
+    Lt(String, Scalar),
+    Le(String, Scalar),
+    Gt(String, Scalar),
+    Ge(String, Scalar),
+    IsNull(String),
+    NotNull(String),
+    And(Vec<Predicate>),
+    Or(Vec<Predicate>),
+}
+
+impl Predicate {
+    /// Whether any row may satisfy the predicate, based on the statistics.
+    ///
+    /// Columns without statistics are regarded as possibly matching.
+    pub fn may_match(&self, stats: &HashMap<String, ColumnStats>) -> bool {
+        let lookup = |column: &String| stats.get(column);
+        // whether (min, max) is possibly in the range, None if stats are missing
+        let range = |column: &String, f: &dyn Fn(&ColumnStats) -> bool| match lookup(column) {
+            Some(s) if s.min == Scalar::Null => false,
+            Some(s) => f(s),
+            None => true,
+        };
+        let is = |l: &Scalar, r: &Scalar, ords: &[Ordering]| {
+            compare(l, r).is_none_or(|o| ords.contains(&o))
+        };
+        match *self {
+            Predicate::Eq(ref c, ref v) => {
+                range(c, &|s| {
+                    is(&s.min, v, &[Ordering::Less, Ordering::Equal]) &&
+                        is(&s.max, v, &[Ordering::Greater, Ordering::Equal])
+                })
+            }
+            Predicate::Lt(ref c, ref v) => range(c, &|s| is(&s.min, v, &[Ordering::Less])),
+            Predicate::Le(ref c, ref v) => {
+                range(c, &|s| is(&s.min, v, &[Ordering::Less, Ordering::Equal]))
+            }
+            Predicate::Gt(ref c, ref v) => range(c, &|s| is(&s.max, v, &[Ordering::Greater])),
+            Predicate::Ge(ref c, ref v) => {
+                range(c, &|s| is(&s.max, v, &[Ordering::Greater, Ordering::Equal]))
+            }
+            Predicate::IsNull(ref c) => lookup(c).is_none_or(|s| s.null_count > 0),
+            Predicate::NotNull(ref c) => lookup(c).is_none_or(|s| s.count > 0),
+            Predicate::And(ref ps) => ps.iter().all(|p| p.may_match(stats)),
+            Predicate::Or(ref ps) => ps.iter().any(|p| p.may_match(stats)),
+        }
+    }
+
+    /// Evaluate the predicate on the row, `get` returns the value of the column.
+    fn eval<F: Fn(&str) -> Scalar>(&self, get: &F) -> bool {
+        let cmp = |c: &str, v: &Scalar, ords: &[Ordering]| {
+            compare(&get(c), v).is_some_and(|o| ords.contains(&o))
+        };
+        match *self {
+            Predicate::Eq(ref c, ref v) => cmp(c, v, &[Ordering::Equal]),
+            Predicate::Lt(ref c, ref v) => cmp(c, v, &[Ordering::Less]),
+            Predicate::Le(ref c, ref v) => cmp(c, v, &[Ordering::Less, Ordering::Equal]),
+            Predicate::Gt(ref c, ref v) => cmp(c, v, &[Ordering::Greater]),
+            Predicate::Ge(ref c, ref v) => cmp(c, v, &[Ordering::Greater, Ordering::Equal]),
+            Predicate::IsNull(ref c) => get(c) == Scalar::Null,
+            Predicate::NotNull(ref c) => get(c) != Scalar::Null,
+            Predicate::And(ref ps) => ps.iter().all(|p| p.eval(get)),
+            Predicate::Or(ref ps) => ps.iter().any(|p| p.eval(get)),
+        }
+    }
+
+    /// Select rows satisfying the predicate, index is reset.
+    ///
+    /// Columns which don't exist in the `DataFrame` are regarded as `Null`.
+    pub fn filter<'a>(
+        &self,
+        df: &DataFrame<usize, String>,
+    ) -> DataFrame<'a, 'a, 'a, usize, String> {
+        let mut locs: Vec<usize> = vec![];
+        for i in 0..df.len() {
+            let get = |column: &str| match df.columns.values.iter().position(|x| x == column) {
+                Some(loc) => df.values[loc].iloc(&i),
+                None => Scalar::Null,
+            };
+            if self.eval(&get) {
+                locs.push(i);
+            }
+        }
+        let values: Vec<Array> = df.values.iter().map(|x| x.ilocs(&locs)).collect();
+        let columns: Vec<String> = df.columns.values.clone();
+        DataFrame::from_vec(values, Indexer::<usize>::from_len(locs.len()), columns)
+    }
+}
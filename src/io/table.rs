@@ -0,0 +1,159 @@
+use std::fs::{self, File};
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+use std::string::ToString;
+
+use parquet::errors::ParquetError;
+
+use nullvec::prelude::{Array, Scalar};
+use nullvec::prelude::Append as NAppend;
+
+use convert::scalars_to_array;
+use frame::DataFrame;
+use indexer::Indexer;
+use traits::{Slicer, IndexerIndex, RowIndex};
+
+use super::stats::Predicate;
+
+const SEGMENT_PREFIX: &str = "part-";
+const SEGMENT_SUFFIX: &str = ".parquet";
+
+/// Append-only table stored as a directory of Parquet segments.
+///
+/// Each `append` writes a new segment and increments the table version.
+/// Columns added in later segments are read as `Null` for older segments.
+pub struct Table {
+    path: PathBuf,
+}
+
+impl Table {
+    /// Open the table directory, it is created if not exists.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Table, ParquetError> {
+        let path = path.as_ref().to_path_buf();
+        fs::create_dir_all(&path)?;
+        Ok(Table { path })
+    }
+
+    /// Sorted segment file paths.
+    fn segments(&self) -> Result<Vec<PathBuf>, ParquetError> {
+        let mut segments: Vec<PathBuf> = vec![];
+        for entry in fs::read_dir(&self.path)? {
+            let path = entry?.path();
+            let is_segment = path.file_name().and_then(|x| x.to_str()).is_some_and(|x| {
+                x.starts_with(SEGMENT_PREFIX) && x.ends_with(SEGMENT_SUFFIX)
+            });
+            if is_segment {
+                segments.push(path);
+            }
+        }
+        segments.sort();
+        Ok(segments)
+    }
+
+    /// Current version, which is the number of appended segments.
+    pub fn version(&self) -> Result<usize, ParquetError> {
+        Ok(self.segments()?.len())
+    }
+
+    /// Append `DataFrame` as a new segment and return the new version.
+    ///
+    /// Index is not stored.
+    pub fn append<I, C>(&self, df: &DataFrame<I, C>) -> Result<usize, ParquetError>
+    where
+        I: Clone + Eq + Hash,
+        C: Clone + Eq + Hash + ToString,
+    {
+        let version = self.version()?;
+        let name = format!("{}{:08}{}", SEGMENT_PREFIX, version, SEGMENT_SUFFIX);
+        // write to temp file first not to expose incomplete segment
+        let tmp = self.path.join(format!(".{}", name));
+        df.to_parquet(File::create(&tmp)?)?;
+        fs::rename(&tmp, self.path.join(name))?;
+        Ok(version + 1)
+    }
+
+    /// Read all segments as single `DataFrame`.
+    pub fn read<'a>(&self) -> Result<DataFrame<'a, 'a, 'a, usize, String>, ParquetError> {
+        let version = self.version()?;
+        self.read_version(version)
+    }
+
+    /// Read segments up to the specified version.
+    pub fn read_version<'a>(
+        &self,
+        version: usize,
+    ) -> Result<DataFrame<'a, 'a, 'a, usize, String>, ParquetError> {
+        let segments = self.segments()?;
+        if version > segments.len() {
+            return Err(ParquetError::General(
+                format!("version {} is not found, latest is {}", version, segments.len()),
+            ));
+        }
+        let mut frames: Vec<DataFrame<usize, String>> = Vec::with_capacity(version);
+        for segment in &segments[..version] {
+            frames.push(DataFrame::<usize, String>::read_parquet(File::open(segment)?, None)?);
+        }
+        concat_segments(frames)
+    }
+
+    /// Read rows which satisfy `predicate` from all segments.
+    ///
+    /// Segments whose column statistics exclude the predicate are skipped.
+    pub fn read_filter<'a>(
+        &self,
+        predicate: &Predicate,
+    ) -> Result<DataFrame<'a, 'a, 'a, usize, String>, ParquetError> {
+        let mut frames: Vec<DataFrame<usize, String>> = vec![];
+        for segment in self.segments()? {
+            frames.push(DataFrame::<usize, String>::read_parquet_filter(
+                File::open(segment)?,
+                predicate,
+            )?);
+        }
+        concat_segments(frames)
+    }
+}
+
+/// Concatenate segments, filling columns missing in a segment with `Null`.
+fn concat_segments<'a>(
+    frames: Vec<DataFrame<usize, String>>,
+) -> Result<DataFrame<'a, 'a, 'a, usize, String>, ParquetError> {
+    // union of columns, ordered by first appearance
+    let mut columns: Indexer<String> = Indexer::new(vec![]);
+    let mut dtypes: Vec<String> = vec![];
+    for df in &frames {
+        for (column, dtype) in df.columns.values.iter().zip(df.dtypes()) {
+            if !columns.contains(column) {
+                columns.push(column.clone());
+                dtypes.push(dtype);
+            } else if dtypes[columns.get_loc(column)] != dtype {
+                return Err(ParquetError::General(format!(
+                    "dtype of column {} is changed from {} to {}",
+                    column,
+                    dtypes[columns.get_loc(column)],
+                    dtype
+                )));
+            }
+        }
+    }
+
+    let mut values: Vec<Option<Array>> = vec![None; columns.len()];
+    let mut len = 0;
+    for df in &frames {
+        for (loc, column) in columns.values.iter().enumerate() {
+            let array: Array = if df.columns.contains(column) {
+                df.values[df.columns.get_loc(column)].clone().into_owned()
+            } else {
+                scalars_to_array(vec![Scalar::Null; df.len()], &dtypes[loc])
+            };
+            values[loc] = Some(match values[loc].take() {
+                Some(current) => current.append(&array),
+                None => array,
+            });
+        }
+        len += df.len();
+    }
+    let values: Vec<Array> = values.into_iter().map(|x| x.unwrap()).collect();
+    let index: Indexer<usize> = Indexer::<usize>::from_len(len);
+    Ok(DataFrame::from_vec(values, index, columns))
+}
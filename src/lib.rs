@@ -1,9 +1,40 @@
 extern crate csv;
 extern crate num;
-// extern crate rayon;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 extern crate nullvec;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[cfg(feature = "json")]
+extern crate serde_json;
+#[cfg(feature = "parquet")]
+extern crate parquet;
+#[cfg(feature = "rusqlite")]
+extern crate rusqlite;
+#[cfg(feature = "postgres")]
+extern crate postgres;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+/// Timezone database, whose `Tz` can be passed to `tz_localize` and
+/// `tz_convert` of `DatetimeMethods`.
+#[cfg(feature = "chrono-tz")]
+pub extern crate chrono_tz;
+#[cfg(feature = "rustfft")]
+extern crate rustfft;
+#[cfg(feature = "regex")]
+extern crate regex;
 
 mod algos;
+#[cfg(feature = "chrono")]
+mod cohort;
+mod conditional;
+mod convert;
+#[cfg(feature = "chrono")]
+mod datetime;
+mod encoders;
+mod error;
+mod expectations;
 mod formatting;
 mod frame;
 mod groupby;
@@ -11,7 +42,12 @@ mod indexer;
 mod io;
 #[macro_use]
 mod macros;
+mod metrics;
+#[cfg(feature = "chrono")]
+mod period;
 mod series;
 mod traits;
 
 pub mod prelude;
+
+pub use error::Error;
@@ -0,0 +1,167 @@
+//! Evaluation of classification results given as label `Series`.
+//!
+//! Actual and predicted labels are compared by their locations.
+
+use std::collections::{BTreeSet, HashMap};
+use std::f64;
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use frame::DataFrame;
+use series::Series;
+
+/// Pairs of actual and predicted labels.
+fn label_pairs<'a, V, I>(
+    actual: &'a Series<V, I>,
+    predicted: &'a Series<V, I>,
+) -> Vec<(&'a V, &'a V)>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    assert!(actual.values.len() == predicted.values.len(), "Length mismatch!");
+    actual.values.iter().zip(predicted.values.iter()).collect()
+}
+
+/// Number of (true positives, false positives, false negatives) of
+/// `positive` label.
+fn positive_counts<V, I>(
+    actual: &Series<V, I>,
+    predicted: &Series<V, I>,
+    positive: &V,
+) -> (usize, usize, usize)
+where
+    V: Clone + Eq,
+    I: Clone + Eq + Hash,
+{
+    let mut counts = (0, 0, 0);
+    for (a, p) in label_pairs(actual, predicted) {
+        match (a == positive, p == positive) {
+            (true, true) => counts.0 += 1,
+            (false, true) => counts.1 += 1,
+            (true, false) => counts.2 += 1,
+            (false, false) => {}
+        }
+    }
+    counts
+}
+
+/// Counts of each pair of labels, index is actual labels and columns are
+/// predicted labels. Both axes have all labels in either in sorted order.
+pub fn confusion_matrix<'n, V, I>(
+    actual: &Series<V, I>,
+    predicted: &Series<V, I>,
+) -> DataFrame<'n, 'n, 'n, V, V>
+where
+    V: 'n + Clone + Eq + Hash + Ord,
+    I: Clone + Eq + Hash,
+{
+    let pairs = label_pairs(actual, predicted);
+    let labels: Vec<V> = pairs
+        .iter()
+        .flat_map(|&(a, p)| vec![a, p])
+        .collect::<BTreeSet<&V>>()
+        .into_iter()
+        .cloned()
+        .collect();
+    let locs: HashMap<&V, usize> = labels.iter().enumerate().map(|(i, x)| (x, i)).collect();
+
+    let mut counts: Vec<Vec<usize>> = vec![vec![0; labels.len()]; labels.len()];
+    for (a, p) in pairs {
+        counts[locs[p]][locs[a]] += 1;
+    }
+    let new_values: Vec<Array> = counts.into_iter().map(Array::new).collect();
+    DataFrame::from_vec(new_values, labels.clone(), labels)
+}
+
+/// Ratio of labels predicted correctly, NaN if empty.
+pub fn accuracy<V, I>(actual: &Series<V, I>, predicted: &Series<V, I>) -> f64
+where
+    V: Clone + Eq,
+    I: Clone + Eq + Hash,
+{
+    let pairs = label_pairs(actual, predicted);
+    let correct = pairs.iter().filter(|&&(a, p)| a == p).count();
+    correct as f64 / pairs.len() as f64
+}
+
+/// Ratio of actual `positive` among predicted `positive`, NaN if nothing is
+/// predicted as `positive`.
+pub fn precision<V, I>(actual: &Series<V, I>, predicted: &Series<V, I>, positive: &V) -> f64
+where
+    V: Clone + Eq,
+    I: Clone + Eq + Hash,
+{
+    let (tp, fp, _) = positive_counts(actual, predicted, positive);
+    tp as f64 / (tp + fp) as f64
+}
+
+/// Ratio of predicted `positive` among actual `positive`, NaN if nothing is
+/// actually `positive`.
+pub fn recall<V, I>(actual: &Series<V, I>, predicted: &Series<V, I>, positive: &V) -> f64
+where
+    V: Clone + Eq,
+    I: Clone + Eq + Hash,
+{
+    let (tp, _, fn_) = positive_counts(actual, predicted, positive);
+    tp as f64 / (tp + fn_) as f64
+}
+
+/// Harmonic mean of precision and recall of `positive`, NaN if either is
+/// NaN or both are 0.
+pub fn f1_score<V, I>(actual: &Series<V, I>, predicted: &Series<V, I>, positive: &V) -> f64
+where
+    V: Clone + Eq,
+    I: Clone + Eq + Hash,
+{
+    let p = precision(actual, predicted, positive);
+    let r = recall(actual, predicted, positive);
+    if p + r == 0. {
+        return f64::NAN;
+    }
+    2. * p * r / (p + r)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::Array;
+
+    use frame::DataFrame;
+    use series::Series;
+    use super::{confusion_matrix, accuracy, precision, recall, f1_score};
+
+    #[test]
+    fn test_confusion_matrix() {
+        let actual = Series::<&str, usize>::from_vec(vec!["cat", "dog", "cat", "bird", "dog"]);
+        let predicted = Series::<&str, usize>::from_vec(vec!["cat", "cat", "cat", "dog", "dog"]);
+        let exp: DataFrame<&str, &str> = DataFrame::from_vec(
+            vec![
+                Array::new(vec![0usize, 0, 0]),
+                Array::new(vec![0usize, 2, 1]),
+                Array::new(vec![1usize, 0, 1]),
+            ],
+            vec!["bird", "cat", "dog"],
+            vec!["bird", "cat", "dog"],
+        );
+        assert_eq!(confusion_matrix(&actual, &predicted), exp);
+    }
+
+    #[test]
+    fn test_classification_metrics() {
+        let actual = Series::<i64, usize>::from_vec(vec![1, 1, 1, 0, 0, 0, 1, 0]);
+        let predicted = Series::<i64, usize>::from_vec(vec![1, 0, 1, 1, 0, 0, 1, 0]);
+        assert_eq!(accuracy(&actual, &predicted), 0.75);
+        // 3 true positives, 1 false positive, 1 false negative
+        assert_eq!(precision(&actual, &predicted, &1), 0.75);
+        assert_eq!(recall(&actual, &predicted, &1), 0.75);
+        assert_eq!(f1_score(&actual, &predicted, &1), 0.75);
+        assert_eq!(recall(&actual, &predicted, &0), 0.75);
+
+        let predicted = Series::<i64, usize>::from_vec(vec![0; 8]);
+        assert!(precision(&actual, &predicted, &1).is_nan());
+        assert_eq!(recall(&actual, &predicted, &1), 0.);
+        assert!(f1_score(&actual, &predicted, &1).is_nan());
+    }
+}
@@ -0,0 +1,286 @@
+use std::fmt;
+use std::ops::{Add, Sub};
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use datetime::DatetimeLike;
+
+/// Frequency of `Period`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Freq {
+    /// ISO 8601 week
+    Week,
+    Month,
+    Quarter,
+    /// Fiscal quarter, holds the start month of fiscal year
+    FiscalQuarter(u32),
+}
+
+/// Calendar period, usable as index label and group key.
+///
+/// Periods of the same kind are ordered chronologically.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Period {
+    /// ISO 8601 week, `year` is the ISO week-numbering year
+    Week { year: i32, week: u32 },
+    /// Calendar month, `month` is 1 to 12
+    Month { year: i32, month: u32 },
+    /// Calendar quarter, `quarter` is 1 to 4
+    Quarter { year: i32, quarter: u32 },
+    /// Quarter of the fiscal year starting at `start_month`, `year` is the
+    /// calendar year in which the fiscal year ends
+    FiscalQuarter { start_month: u32, year: i32, quarter: u32 },
+}
+
+impl Period {
+    /// Period of the frequency containing the date.
+    pub fn from_date(date: &NaiveDate, freq: Freq) -> Period {
+        match freq {
+            Freq::Week => Period::iso_week(date),
+            Freq::Month => Period::month(date),
+            Freq::Quarter => Period::quarter(date),
+            Freq::FiscalQuarter(start_month) => Period::fiscal_quarter(date, start_month),
+        }
+    }
+
+    /// Period of the frequency containing the datetime in its wall clock
+    /// time.
+    pub fn from_datetime<T: DatetimeLike>(value: &T, freq: Freq) -> Period {
+        Period::from_date(&value.to_naive_local().date(), freq)
+    }
+
+    /// ISO week containing the date.
+    pub fn iso_week(date: &NaiveDate) -> Period {
+        let week = date.iso_week();
+        Period::Week {
+            year: week.year(),
+            week: week.week(),
+        }
+    }
+
+    /// Calendar month containing the date.
+    pub fn month(date: &NaiveDate) -> Period {
+        Period::Month {
+            year: date.year(),
+            month: date.month(),
+        }
+    }
+
+    /// Calendar quarter containing the date.
+    pub fn quarter(date: &NaiveDate) -> Period {
+        Period::Quarter {
+            year: date.year(),
+            quarter: date.month0() / 3 + 1,
+        }
+    }
+
+    /// Fiscal quarter containing the date, `start_month` is 1 to 12.
+    pub fn fiscal_quarter(date: &NaiveDate, start_month: u32) -> Period {
+        assert!(
+            (1..=12).contains(&start_month),
+            "start_month must be 1 to 12"
+        );
+        let offset = (date.month() + 12 - start_month) % 12;
+        // fiscal year starting in January is the calendar year
+        let year = if start_month > 1 && date.month() >= start_month {
+            date.year() + 1
+        } else {
+            date.year()
+        };
+        Period::FiscalQuarter {
+            start_month,
+            year,
+            quarter: offset / 3 + 1,
+        }
+    }
+
+    /// Frequency of the period.
+    pub fn freq(&self) -> Freq {
+        match *self {
+            Period::Week { .. } => Freq::Week,
+            Period::Month { .. } => Freq::Month,
+            Period::Quarter { .. } => Freq::Quarter,
+            Period::FiscalQuarter { start_month, .. } => Freq::FiscalQuarter(start_month),
+        }
+    }
+
+    /// Number of periods since the epoch of the frequency.
+    fn ordinal(&self) -> i64 {
+        match *self {
+            Period::Week { year, week } => {
+                let monday = NaiveDate::from_isoywd_opt(year, week, Weekday::Mon)
+                    .expect("invalid ISO week");
+                // 0001-01-01 is Monday
+                i64::from(monday.num_days_from_ce() - 1) / 7
+            }
+            Period::Month { year, month } => i64::from(year) * 12 + i64::from(month) - 1,
+            Period::Quarter { year, quarter } |
+            Period::FiscalQuarter { year, quarter, .. } => {
+                i64::from(year) * 4 + i64::from(quarter) - 1
+            }
+        }
+    }
+
+    fn from_ordinal(ordinal: i64, freq: Freq) -> Period {
+        let (year, rem) = (ordinal.div_euclid(4) as i32, ordinal.rem_euclid(4) as u32);
+        match freq {
+            Freq::Week => {
+                let days = (ordinal * 7 + 1) as i32;
+                Period::iso_week(&NaiveDate::from_num_days_from_ce_opt(days)
+                    .expect("period out of range"))
+            }
+            Freq::Month => {
+                Period::Month {
+                    year: ordinal.div_euclid(12) as i32,
+                    month: ordinal.rem_euclid(12) as u32 + 1,
+                }
+            }
+            Freq::Quarter => {
+                Period::Quarter {
+                    year,
+                    quarter: rem + 1,
+                }
+            }
+            Freq::FiscalQuarter(start_month) => {
+                Period::FiscalQuarter {
+                    start_month,
+                    year,
+                    quarter: rem + 1,
+                }
+            }
+        }
+    }
+
+    /// First day of the period.
+    pub fn start_date(&self) -> NaiveDate {
+        match *self {
+            Period::Week { year, week } => {
+                NaiveDate::from_isoywd_opt(year, week, Weekday::Mon).expect("invalid ISO week")
+            }
+            Period::Month { year, month } => {
+                NaiveDate::from_ymd_opt(year, month, 1).expect("invalid month")
+            }
+            Period::Quarter { year, quarter } => {
+                NaiveDate::from_ymd_opt(year, quarter * 3 - 2, 1).expect("invalid quarter")
+            }
+            Period::FiscalQuarter {
+                start_month,
+                year,
+                quarter,
+            } => {
+                // months since January of the calendar year before the fiscal year ends
+                let months = start_month - 1 + (quarter - 1) * 3;
+                let year = if start_month > 1 { year - 1 } else { year };
+                NaiveDate::from_ymd_opt(year + (months / 12) as i32, months % 12 + 1, 1)
+                    .expect("invalid fiscal quarter")
+            }
+        }
+    }
+
+    /// Last day of the period.
+    pub fn end_date(&self) -> NaiveDate {
+        (*self + 1).start_date().pred_opt().expect("period out of range")
+    }
+}
+
+/// Shift the period by the number of periods.
+impl Add<i64> for Period {
+    type Output = Period;
+
+    fn add(self, rhs: i64) -> Period {
+        Period::from_ordinal(self.ordinal() + rhs, self.freq())
+    }
+}
+
+impl Sub<i64> for Period {
+    type Output = Period;
+
+    fn sub(self, rhs: i64) -> Period {
+        Period::from_ordinal(self.ordinal() - rhs, self.freq())
+    }
+}
+
+/// Number of periods between periods, panics if frequencies are different.
+impl Sub<Period> for Period {
+    type Output = i64;
+
+    fn sub(self, rhs: Period) -> i64 {
+        assert!(self.freq() == rhs.freq(), "frequencies must be the same");
+        self.ordinal() - rhs.ordinal()
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Period::Week { year, week } => write!(f, "{}-W{:02}", year, week),
+            Period::Month { year, month } => write!(f, "{}-{:02}", year, month),
+            Period::Quarter { year, quarter } => write!(f, "{}Q{}", year, quarter),
+            Period::FiscalQuarter { year, quarter, .. } => write!(f, "FY{}Q{}", year, quarter),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::NaiveDate;
+
+    use super::{Freq, Period};
+
+    #[test]
+    fn test_iso_week() {
+        // 2018-12-31 belongs to the 1st week of 2019
+        let d = NaiveDate::from_ymd_opt(2018, 12, 31).unwrap();
+        assert_eq!(Period::iso_week(&d), Period::Week { year: 2019, week: 1 });
+        let d = NaiveDate::from_ymd_opt(2021, 1, 3).unwrap();
+        assert_eq!(Period::iso_week(&d), Period::Week { year: 2020, week: 53 });
+        assert_eq!(Period::iso_week(&d).to_string(), "2020-W53");
+    }
+
+    #[test]
+    fn test_fiscal_quarter() {
+        let d = NaiveDate::from_ymd_opt(2018, 3, 31).unwrap();
+        assert_eq!(Period::fiscal_quarter(&d, 4).to_string(), "FY2018Q4");
+        let d = NaiveDate::from_ymd_opt(2018, 4, 1).unwrap();
+        assert_eq!(Period::fiscal_quarter(&d, 4).to_string(), "FY2019Q1");
+        let d = NaiveDate::from_ymd_opt(2018, 12, 1).unwrap();
+        assert_eq!(Period::fiscal_quarter(&d, 10).to_string(), "FY2019Q1");
+        assert_eq!(Period::fiscal_quarter(&d, 1), Period::FiscalQuarter {
+            start_month: 1,
+            year: 2018,
+            quarter: 4,
+        });
+        assert_eq!(Period::quarter(&d).to_string(), "2018Q4");
+    }
+
+    #[test]
+    fn test_period_arithmetic() {
+        let d = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let p = Period::from_date(&d, Freq::Month);
+        assert_eq!(p.to_string(), "2024-03");
+        assert_eq!((p + 10).to_string(), "2025-01");
+        assert_eq!((p - 3).to_string(), "2023-12");
+        assert_eq!((p + 10) - p, 10);
+        assert_eq!(p.start_date(), NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        assert_eq!(p.end_date(), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+
+        let p = Period::from_date(&d, Freq::Quarter);
+        assert_eq!((p + 3).to_string(), "2024Q4");
+        assert_eq!((p - 1).to_string(), "2023Q4");
+        assert_eq!(p.end_date(), NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+
+        let p = Period::from_date(&d, Freq::Week);
+        assert_eq!(p.to_string(), "2024-W11");
+        assert_eq!((p - 11).to_string(), "2023-W52");
+        assert_eq!((p + 42).to_string(), "2025-W01");
+        assert_eq!(p.start_date(), NaiveDate::from_ymd_opt(2024, 3, 11).unwrap());
+
+        let p = Period::from_date(&d, Freq::FiscalQuarter(4));
+        assert_eq!(p.to_string(), "FY2024Q4");
+        assert_eq!((p + 1).to_string(), "FY2025Q1");
+        assert_eq!(p.start_date(), NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert_eq!((p + 1).start_date(), NaiveDate::from_ymd_opt(2024, 4, 1).unwrap());
+        assert_eq!((p + 1).end_date(), NaiveDate::from_ymd_opt(2024, 6, 30).unwrap());
+    }
+}
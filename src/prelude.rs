@@ -6,8 +6,38 @@ pub use nullvec::prelude::BasicAggregation as NBasicAggregation;
 pub use nullvec::prelude::NumericAggregation as NNumericAggregation;
 pub use nullvec::prelude::ComparisonAggregation as NComparisonAggregation;
 
-pub use frame::DataFrame;
-pub use indexer::Indexer;
-pub use series::Series;
-pub use traits::{Slicer, IndexerIndex, RowIndex, ColIndex, Append, Concatenation, Join, Apply,
-                 BasicAggregation, NumericAggregation, ComparisonAggregation, Description};
+#[cfg(feature = "chrono")]
+pub use datetime::{date_range, partial_datetime_range, DatetimeLike};
+pub use algos::key::ScalarKey;
+#[cfg(feature = "chrono")]
+pub use cohort::cohort_retention;
+pub use conditional::{when, select_branches, When, Then};
+pub use error::Error;
+pub use expectations::{expect, Expectations, ExpectationResult, Validation};
+pub use frame::{natural_cmp, schema_diff, Chunks, DataFrame, DataFrameView, RowView, SchemaDiff,
+                SortKey};
+pub use groupby::{GroupBy, GroupIter, GroupKeys, KeyColumn};
+pub use indexer::{Indexer, IndexLabel, MultiIndexer, MultiLabel};
+pub use io::{RowCodec, SqlResult, SqlSource};
+pub use metrics::{confusion_matrix, accuracy, precision, recall, f1_score};
+pub use encoders::{LabelEncoder, OneHotEncoder, StandardScaler, QuantileBinner};
+#[cfg(feature = "chrono")]
+pub use io::{DateOptions, DateParseFailure, DateParseSummary};
+#[cfg(feature = "serde")]
+pub use io::{SerdeCodec, CodecError};
+#[cfg(feature = "json")]
+pub use io::NdjsonReader;
+#[cfg(feature = "parquet")]
+pub use io::{ColumnStats, ParquetChunks, Predicate, Table};
+#[cfg(feature = "chrono")]
+pub use period::{Freq, Period};
+pub use series::{Series, SeriesView, Interval, ListMethods, StringMethods, Upsampler};
+#[cfg(feature = "chrono")]
+pub use series::DatetimeMethods;
+pub use traits::{Slicer, IndexerIndex, NullValue, RowIndex, ColIndex, Append, Concatenation,
+                 Join, How, DropHow, JoinMethod, Direction, NullEquality, NullOrder, SortKind,
+                 Overflow, CheckedInt, Summation, Interpolation, Detrend, Metric, DType, AsType,
+                 Agg, Apply, BasicAggregation, NumericAggregation, ComparisonAggregation,
+                 SelectionAggregation, Description};
+#[cfg(feature = "rayon")]
+pub use traits::Reduction;
@@ -5,7 +5,13 @@ use std::ops::{Add, Sub, Div};
 use super::Series;
 use algos::computation::{Aggregation, NanMinMax};
 use algos::counter::Counter;
-use traits::{Apply, BasicAggregation, NumericAggregation, ComparisonAggregation, Description};
+use algos::factorize::factorize;
+#[cfg(feature = "rayon")]
+use algos::parallel::par_float_sum;
+use traits::{Agg, Apply, BasicAggregation, NumericAggregation, ComparisonAggregation,
+             SelectionAggregation, Description, Summation};
+#[cfg(feature = "rayon")]
+use traits::Reduction;
 
 
 impl<'v, 'i, V, I> BasicAggregation<'i> for Series<'v, 'i, V, I>
@@ -59,6 +65,68 @@ where
     }
 }
 
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    /// Sum of values as `f64` by `method`.
+    pub fn sum_by(&self, method: Summation) -> f64 {
+        Aggregation::float_sum(&self.to_f64_vec(), method)
+    }
+
+    /// Mean of values using sum by `method`.
+    pub fn mean_by(&self, method: Summation) -> f64 {
+        self.sum_by(method) / self.values.len() as f64
+    }
+
+    /// Sum of values as `f64` by `method` using rayon. Result doesn't depend
+    /// on the number of threads with `Reduction::Deterministic`.
+    #[cfg(feature = "rayon")]
+    pub fn par_sum_by(&self, method: Summation, reduction: Reduction) -> f64 {
+        par_float_sum(&self.to_f64_vec(), method, reduction)
+    }
+
+    /// Mean of values using parallel sum by `method` and `reduction`.
+    #[cfg(feature = "rayon")]
+    pub fn par_mean_by(&self, method: Summation, reduction: Reduction) -> f64 {
+        self.par_sum_by(method, reduction) / self.values.len() as f64
+    }
+
+    /// Aggregate values as `f64` by `agg` regarding NaN as null.
+    ///
+    /// NaN are skipped if `skipna`, otherwise any NaN results in NaN. Result
+    /// is also NaN if less than `min_count` valid values exist, and
+    /// `Agg::Count` always counts valid values.
+    pub fn aggregate_with(&self, agg: Agg, skipna: bool, min_count: usize) -> f64 {
+        let values = self.to_f64_vec();
+        let valid: Vec<f64> = values.iter().cloned().filter(|x| !x.is_nan()).collect();
+        if agg == Agg::Count {
+            return valid.len() as f64;
+        }
+        if (!skipna && valid.len() < values.len()) || valid.len() < min_count {
+            return f64::NAN;
+        }
+        match agg {
+            Agg::Sum => Aggregation::vec_sum(&valid),
+            Agg::Count => valid.len() as f64,
+            Agg::Mean => Aggregation::vec_mean(&valid),
+            Agg::Var => Aggregation::vec_var(&valid),
+            Agg::Std => Aggregation::vec_std(&valid),
+            Agg::Min => valid.iter().cloned().fold(f64::NAN, f64::min),
+            Agg::Max => valid.iter().cloned().fold(f64::NAN, f64::max),
+        }
+    }
+
+    /// Population variance of values using sums by `method`.
+    pub fn var_by(&self, method: Summation) -> f64 {
+        let values = self.to_f64_vec();
+        let mean = Aggregation::float_sum(&values, method) / values.len() as f64;
+        let squares: Vec<f64> = values.iter().map(|x| (x - mean) * (x - mean)).collect();
+        Aggregation::float_sum(&squares, method) / values.len() as f64
+    }
+}
+
 impl<'v, 'i, V, I> ComparisonAggregation<'i> for Series<'v, 'i, V, I>
 where
     V: Clone + NanMinMax<V>,
@@ -100,6 +168,26 @@ where
     }
 }
 
+impl<'v, 'i, V, I> SelectionAggregation<'i> for Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    type Selected = Option<V>;
+
+    fn first(&'i self) -> Self::Selected {
+        self.nth(0)
+    }
+
+    fn last(&'i self) -> Self::Selected {
+        self.values.last().cloned()
+    }
+
+    fn nth(&'i self, n: usize) -> Self::Selected {
+        self.values.get(n).cloned()
+    }
+}
+
 // Other
 
 impl<'v, 'i, V, I> Series<'v, 'i, V, I>
@@ -112,4 +200,39 @@ where
         let (keys, counts) = c.get_results();
         Series::new(counts, keys)
     }
+
+    /// Counts of each value sorted by descending count then by value, as
+    /// percentages of the total if `percent` and accumulated in that order if
+    /// `cumulative`, e.g. for Pareto tables.
+    pub fn value_counts_with<'a>(&self, percent: bool, cumulative: bool) -> Series<'a, 'a, f64, V> {
+        let (keys, counts) = Counter::new(&self.values).get_results();
+        let mut pairs: Vec<(V, usize)> = keys.into_iter().zip(counts).collect();
+        pairs.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let total = self.values.len() as f64;
+        let mut acc = 0.;
+        let mut new_values: Vec<f64> = Vec::with_capacity(pairs.len());
+        for &(_, count) in &pairs {
+            let mut value = count as f64;
+            if cumulative {
+                acc += value;
+                value = acc;
+            }
+            new_values.push(if percent { value * 100. / total } else { value });
+        }
+        let keys: Vec<V> = pairs.into_iter().map(|x| x.0).collect();
+        Series::new(new_values, keys)
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + Eq + Hash,
+    I: Clone + Eq + Hash,
+{
+    /// Encode values as locations in unique values, which are in order of
+    /// first appearance.
+    pub fn factorize(&self) -> (Vec<usize>, Vec<V>) {
+        factorize(&self.values)
+    }
 }
@@ -0,0 +1,105 @@
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub, Div};
+
+use super::Series;
+use algos::join::{JoinOp, HashJoin};
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    /// Align both `Series` on the union of their index.
+    ///
+    /// Labels of `self` come first, followed by labels only in `other`. Values
+    /// missing in either side are `None`. Index must be unique.
+    pub fn align<'n>(
+        &self,
+        other: &Series<V, I>,
+    ) -> (Series<'n, 'n, Option<V>, I>, Series<'n, 'n, Option<V>, I>)
+    where
+        I: 'n,
+    {
+        let (new_index, lindexer, rindexer) =
+            HashJoin::outer(&self.index.values, &other.index.values);
+        let lvalues: Vec<Option<V>> =
+            lindexer.iter().map(|x| self.values.get(*x).cloned()).collect();
+        let rvalues: Vec<Option<V>> =
+            rindexer.iter().map(|x| other.values.get(*x).cloned()).collect();
+        (Series::new(lvalues, new_index.clone()), Series::new(rvalues, new_index))
+    }
+
+    fn aligned_op<'n, O, F>(&self, other: &Series<V, I>, func: F) -> Series<'n, 'n, Option<O>, I>
+    where
+        I: 'n,
+        O: Clone,
+        F: Fn(V, V) -> O,
+    {
+        let (left, right) = self.align(other);
+        let new_values: Vec<Option<O>> = left.values
+            .iter()
+            .zip(right.values.iter())
+            .map(|(x, y)| match (x, y) {
+                (Some(x), Some(y)) => Some(func(x.clone(), y.clone())),
+                _ => None,
+            })
+            .collect();
+        Series::new(new_values, left.index.into_owned())
+    }
+}
+
+macro_rules! define_aligned_op {
+    ($t:ident, $m:ident, $aligned_m:ident) => {
+
+        impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+        where
+            V: Clone,
+            I: Clone + Eq + Hash,
+        {
+            /// Element-wise operation on labels of the union of both index,
+            /// result is `None` for labels missing in either side.
+            pub fn $aligned_m<'n, O>(&self, other: &Series<V, I>) -> Series<'n, 'n, Option<O>, I>
+            where
+                I: 'n,
+                V: $t<Output = O>,
+                O: Clone,
+            {
+                self.aligned_op(other, |x, y| x.$m(y))
+            }
+        }
+    }
+}
+
+define_aligned_op!(Add, add, add_aligned);
+define_aligned_op!(Sub, sub, sub_aligned);
+define_aligned_op!(Mul, mul, mul_aligned);
+define_aligned_op!(Div, div, div_aligned);
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Series;
+
+    #[test]
+    fn test_series_align() {
+        let l = Series::<i64, &str>::new(vec![1, 2, 3], vec!["a", "b", "c"]);
+        let r = Series::<i64, &str>::new(vec![10, 20], vec!["d", "b"]);
+
+        let (la, ra) = l.align(&r);
+        let exp = Series::new(vec![Some(1), Some(2), Some(3), None], vec!["a", "b", "c", "d"]);
+        assert!(la == exp);
+        let exp = Series::new(vec![None, Some(20), None, Some(10)], vec!["a", "b", "c", "d"]);
+        assert!(ra == exp);
+
+        let exp = Series::new(vec![None, Some(22), None, None], vec!["a", "b", "c", "d"]);
+        assert!(l.add_aligned(&r) == exp);
+        let exp = Series::new(vec![None, Some(-18), None, None], vec!["a", "b", "c", "d"]);
+        assert!(l.sub_aligned(&r) == exp);
+        let exp = Series::new(vec![None, Some(40), None, None], vec!["a", "b", "c", "d"]);
+        assert!(l.mul_aligned(&r) == exp);
+
+        // same index keeps the order
+        let exp = Series::new(vec![Some(1), Some(1), Some(1)], vec!["a", "b", "c"]);
+        assert!(l.div_aligned(&l) == exp);
+    }
+}
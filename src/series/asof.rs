@@ -0,0 +1,59 @@
+use std::hash::Hash;
+use std::slice;
+
+use super::Series;
+use traits::NullValue;
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + NullValue,
+    I: Clone + Eq + Hash + Ord,
+{
+    /// Last valid value whose label is less than or equal to `label`.
+    ///
+    /// Returns `None` if no such value exists.
+    pub fn asof(&self, label: &I) -> Option<V> {
+        self.asofs(slice::from_ref(label)).pop().unwrap()
+    }
+
+    /// Vectorized `asof`, index doesn't need to be sorted.
+    pub fn asofs(&self, labels: &[I]) -> Vec<Option<V>> {
+        let mut valid: Vec<usize> = (0..self.values.len())
+            .filter(|&i| !self.values[i].is_null())
+            .collect();
+        let index = &self.index.values;
+        if !self.index.is_monotonic_increasing() {
+            valid.sort_by(|&a, &b| index[a].cmp(&index[b]));
+        }
+
+        labels
+            .iter()
+            .map(|label| match valid.partition_point(|&i| index[i] <= *label) {
+                0 => None,
+                n => Some(self.values[valid[n - 1]].clone()),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use super::super::Series;
+
+    #[test]
+    fn test_series_asof() {
+        let s: Series<f64, i64> = Series::new(vec![1., f64::NAN, 3., 4.], vec![10, 20, 30, 40]);
+        assert_eq!(s.asof(&5), None);
+        assert_eq!(s.asof(&10), Some(1.));
+        // NaN is skipped
+        assert_eq!(s.asof(&25), Some(1.));
+        assert_eq!(s.asof(&35), Some(3.));
+        assert_eq!(s.asof(&100), Some(4.));
+
+        let s: Series<i64, i64> = Series::new(vec![4, 3, 1], vec![40, 30, 10]);
+        assert_eq!(s.asofs(&[5, 10, 25, 35, 45]), vec![None, Some(1), Some(1), Some(3), Some(4)]);
+    }
+}
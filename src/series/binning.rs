@@ -0,0 +1,174 @@
+use num::ToPrimitive;
+use std::cmp::Ordering;
+use std::f64;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use super::Series;
+
+/// Right-closed interval `(left, right]`, label of bins created by
+/// `Series::cut` and `Series::qcut`.
+///
+/// Intervals are ordered by `left`, then `right`.
+#[derive(Clone, Copy, Debug)]
+pub struct Interval {
+    pub left: f64,
+    pub right: f64,
+}
+
+impl Interval {
+    pub fn new(left: f64, right: f64) -> Interval {
+        Interval { left, right }
+    }
+
+    /// Whether the interval contains `value`.
+    pub fn contains(&self, value: f64) -> bool {
+        self.left < value && value <= self.right
+    }
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Interval) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Interval {}
+
+impl Hash for Interval {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.left.to_bits().hash(state);
+        self.right.to_bits().hash(state);
+    }
+}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Interval) -> Ordering {
+        self.left
+            .total_cmp(&other.left)
+            .then(self.right.total_cmp(&other.right))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}]", self.left, self.right)
+    }
+}
+
+/// Interval of each value, `None` for values out of `edges` or NaN. The
+/// first interval contains its left edge if `include_lowest` is `true`.
+fn bin_values(values: &[f64], edges: &[f64], include_lowest: bool) -> Vec<Option<Interval>> {
+    values
+        .iter()
+        .map(|&x| {
+            if edges.len() < 2 || x.is_nan() {
+                return None;
+            }
+            if include_lowest && x == edges[0] {
+                return Some(Interval::new(edges[0], edges[1]));
+            }
+            // number of edges less than the value
+            match edges.partition_point(|&e| e < x) {
+                0 => None,
+                n if n == edges.len() => None,
+                n => Some(Interval::new(edges[n - 1], edges[n])),
+            }
+        })
+        .collect()
+}
+
+/// Quantile of sorted values, linearly interpolated.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f64)
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    /// Discretize values into intervals `(edges[i], edges[i + 1]]`.
+    ///
+    /// `edges` must be increasing. Values out of the edges or NaN are `None`.
+    /// Returns intervals of each value and the edges.
+    pub fn cut<'n>(&self, edges: &[f64]) -> (Series<'n, 'n, Option<Interval>, I>, Vec<f64>)
+    where
+        I: 'n,
+    {
+        assert!(
+            edges.windows(2).all(|x| x[0] < x[1]),
+            "edges must increase monotonically"
+        );
+        let bins = bin_values(&self.to_f64_vec(), edges, false);
+        (Series::new(bins, self.index.clone().into_owned()), edges.to_vec())
+    }
+
+    /// Discretize values into `q` intervals containing the same number of
+    /// values, edges are quantiles of values.
+    ///
+    /// The first interval contains the minimum value, and duplicated edges
+    /// are dropped. NaN values are `None`. Returns intervals of each value
+    /// and the edges.
+    pub fn qcut<'n>(&self, q: usize) -> (Series<'n, 'n, Option<Interval>, I>, Vec<f64>)
+    where
+        I: 'n,
+    {
+        assert!(q > 0, "q must be positive");
+        let values = self.to_f64_vec();
+        let mut sorted: Vec<f64> = values.iter().cloned().filter(|x| !x.is_nan()).collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let mut edges: Vec<f64> = vec![];
+        if !sorted.is_empty() {
+            edges = (0..q + 1)
+                .map(|i| quantile(&sorted, i as f64 / q as f64))
+                .collect();
+            edges.dedup();
+        }
+        let bins = bin_values(&values, &edges, true);
+        (Series::new(bins, self.index.clone().into_owned()), edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{Interval, bin_values, quantile};
+
+    #[test]
+    fn test_bin_values() {
+        let edges = vec![0., 1., 2.];
+        let res = bin_values(&[0., 0.5, 1., 1.5, 2.5], &edges, false);
+        assert_eq!(
+            res,
+            vec![
+                None,
+                Some(Interval::new(0., 1.)),
+                Some(Interval::new(0., 1.)),
+                Some(Interval::new(1., 2.)),
+                None,
+            ]
+        );
+        let res = bin_values(&[0.], &edges, true);
+        assert_eq!(res, vec![Some(Interval::new(0., 1.))]);
+        assert_eq!(Interval::new(0., 1.).to_string(), "(0, 1]");
+    }
+
+    #[test]
+    fn test_quantile() {
+        let values = vec![1., 2., 3., 4.];
+        assert_eq!(quantile(&values, 0.), 1.);
+        assert_eq!(quantile(&values, 0.5), 2.5);
+        assert_eq!(quantile(&values, 1.), 4.);
+    }
+}
@@ -0,0 +1,111 @@
+use std::hash::Hash;
+
+use super::Series;
+use error::Error;
+use traits::{CheckedInt, Overflow};
+
+macro_rules! define_checked_series_op {
+    ($m:ident, $by:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Returns `Error::IndexMismatch` if index are different, and
+        /// `Error::Overflow` if any value overflows under `Overflow::Error`.
+        pub fn $m<'n>(
+            &self,
+            other: &Series<V, I>,
+            overflow: Overflow,
+        ) -> Result<Series<'n, 'n, V, I>, Error>
+        where
+            I: 'n,
+        {
+            self.check_binop(other)?;
+            let mut new_values: Vec<V> = Vec::with_capacity(self.values.len());
+            for (i, (x, y)) in self.values.iter().zip(other.values.iter()).enumerate() {
+                match x.$by(*y, overflow) {
+                    Some(v) => new_values.push(v),
+                    None => return Err(Error::Overflow { location: i }),
+                }
+            }
+            Ok(Series::new(new_values, self.index.clone().into_owned()))
+        }
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + CheckedInt,
+    I: Clone + Eq + Hash,
+{
+    define_checked_series_op!(add_checked, add_by, "Element-wise addition by `overflow`.");
+    define_checked_series_op!(sub_checked, sub_by, "Element-wise subtraction by `overflow`.");
+    define_checked_series_op!(mul_checked, mul_by, "Element-wise multiplication by `overflow`.");
+
+    /// Sum of values by `overflow` policy, or `Error::Overflow` at the
+    /// location where the running sum overflows under `Overflow::Error`.
+    pub fn sum_checked(&self, overflow: Overflow) -> Result<V, Error>
+    where
+        V: Default,
+    {
+        let mut acc = V::default();
+        for (i, v) in self.values.iter().enumerate() {
+            acc = acc.add_by(*v, overflow).ok_or(Error::Overflow { location: i })?;
+        }
+        Ok(acc)
+    }
+
+    /// Sum of values in `i128`, which doesn't overflow for practical lengths.
+    pub fn sum_wide(&self) -> i128 {
+        self.values.iter().map(|x| x.widen()).sum()
+    }
+
+    /// Promote values to `i128`, so that following operations don't overflow.
+    pub fn widen<'n>(&self) -> Series<'n, 'n, i128, I>
+    where
+        I: 'n,
+    {
+        let new_values: Vec<i128> = self.values.iter().map(|x| x.widen()).collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Series;
+    use error::Error;
+    use traits::Overflow;
+
+    #[test]
+    fn test_series_checked_ops() {
+        let l = Series::<i8, i64>::new(vec![1, 100, -100], vec![10, 20, 30]);
+        let r = Series::<i8, i64>::new(vec![2, 100, -100], vec![10, 20, 30]);
+
+        let exp = Series::<i8, i64>::new(vec![3, -56, 56], vec![10, 20, 30]);
+        assert_eq!(l.add_checked(&r, Overflow::Wrap), Ok(exp));
+        let exp = Series::<i8, i64>::new(vec![3, 127, -128], vec![10, 20, 30]);
+        assert_eq!(l.add_checked(&r, Overflow::Saturate), Ok(exp));
+        assert_eq!(l.add_checked(&r, Overflow::Error), Err(Error::Overflow { location: 1 }));
+
+        let exp = Series::<i8, i64>::new(vec![2, 127, 127], vec![10, 20, 30]);
+        assert_eq!(l.mul_checked(&r, Overflow::Saturate), Ok(exp));
+        let exp = Series::<i8, i64>::new(vec![-1, 0, 0], vec![10, 20, 30]);
+        assert_eq!(l.sub_checked(&r, Overflow::Error), Ok(exp));
+
+        let exp = Series::<i128, i64>::new(vec![3, 200, -200], vec![10, 20, 30]);
+        assert_eq!(l.widen() + r.widen(), exp);
+
+        let r = Series::<i8, i64>::new(vec![2, 100, -100], vec![10, 20, 40]);
+        assert_eq!(l.add_checked(&r, Overflow::Wrap), Err(Error::IndexMismatch));
+    }
+
+    #[test]
+    fn test_series_sum_checked() {
+        let s = Series::<u64, i64>::new(vec![u64::MAX, 1, 2], vec![10, 20, 30]);
+        assert_eq!(s.sum_checked(Overflow::Wrap), Ok(2));
+        assert_eq!(s.sum_checked(Overflow::Saturate), Ok(u64::MAX));
+        assert_eq!(s.sum_checked(Overflow::Error), Err(Error::Overflow { location: 1 }));
+        assert_eq!(s.sum_wide(), u64::MAX as i128 + 3);
+        let s = Series::<u64, i64>::new(vec![], vec![]);
+        assert_eq!(s.sum_checked(Overflow::Error), Ok(0));
+    }
+}
@@ -1,6 +1,12 @@
+use std::f32;
+use std::f64;
 use std::hash::Hash;
 
+use nullvec::prelude::{Array, Scalar};
+
 use super::Series;
+use convert::cast_scalars;
+use error::Error;
 
 impl<'v, 'i, V: Clone> From<Vec<V>> for Series<'v, 'i, V, usize> {
     fn from(values: Vec<V>) -> Self {
@@ -18,10 +24,63 @@ where
     }
 }
 
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + Into<Scalar>,
+    I: Clone + Eq + Hash,
+{
+    /// Convert values to `W`, or return `Error::Cast` for the first value
+    /// which can't be converted without loss.
+    ///
+    /// Such values become NaN instead if `coerce` and `W` is a float. Other
+    /// types can't represent null, so that errors are returned regardless of
+    /// `coerce`.
+    pub fn try_astype<'n, W>(&self, coerce: bool) -> Result<Series<'n, 'n, W, I>, Error>
+    where
+        I: 'n,
+        W: Clone + From<Scalar>,
+        Vec<W>: Into<Array>,
+    {
+        let dtype = Vec::<W>::new().into().dtype();
+        let values: Vec<Scalar> = self.values.iter().map(|x| x.clone().into()).collect();
+        let casted = cast_scalars(&values, &dtype, coerce)?;
+        let mut new_values: Vec<W> = Vec::with_capacity(casted.len());
+        for (i, value) in casted.into_iter().enumerate() {
+            let value = match (value, dtype.as_str()) {
+                (Scalar::Null, "f64") => Scalar::f64(f64::NAN),
+                (Scalar::Null, "f32") => Scalar::f32(f32::NAN),
+                (Scalar::Null, _) => {
+                    return Err(Error::Cast {
+                        location: i,
+                        value: values[i].to_string(),
+                        dtype: dtype.clone(),
+                    })
+                }
+                (v, _) => v,
+            };
+            new_values.push(W::from(value));
+        }
+        Ok(Series::new(new_values, self.index.clone().into_owned()))
+    }
+
+    /// Convert values to `W`, panic where `try_astype` returns an error.
+    pub fn astype<'n, W>(&self, coerce: bool) -> Series<'n, 'n, W, I>
+    where
+        I: 'n,
+        W: Clone + From<Scalar>,
+        Vec<W>: Into<Array>,
+    {
+        self.try_astype(coerce).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
+    use std::f64;
+
     use super::super::Series;
+    use error::Error;
 
     #[test]
     fn test_from_vec_int() {
@@ -50,4 +109,38 @@ mod tests {
         let conv: Vec<&str> = s.into();
         assert_eq!(conv, vec!["a", "b", "c"]);
     }
+
+    #[test]
+    fn test_series_astype() {
+        let s = Series::<i64, &str>::new(vec![1, 0, 3], vec!["a", "b", "c"]);
+        let res: Series<f64, &str> = s.astype(false);
+        assert_eq!(res, Series::new(vec![1., 0., 3.], vec!["a", "b", "c"]));
+        let res: Series<String, &str> = s.astype(false);
+        let exp: Vec<String> = vec!["1".to_string(), "0".to_string(), "3".to_string()];
+        assert_eq!(res.values.to_vec(), exp);
+
+        let res = s.try_astype::<bool>(false);
+        let exp = Error::Cast {
+            location: 2,
+            value: "3".to_string(),
+            dtype: "bool".to_string(),
+        };
+        assert_eq!(res, Err(exp));
+        // bool can't be null
+        assert!(s.try_astype::<bool>(true).is_err());
+
+        let s = Series::<f64, usize>::from_vec(vec![1., 2.5, f64::NAN]);
+        assert!(s.try_astype::<i64>(false).is_err());
+        let res: Series<f32, usize> = s.astype(true);
+        assert_eq!(res.values[..2], [1., 2.5]);
+        assert!(res.values[2].is_nan());
+
+        let s = Series::<String, usize>::from_vec(vec!["1".to_string(), " 2 ".to_string()]);
+        let res: Series<i64, usize> = s.astype(false);
+        assert_eq!(res.values.to_vec(), vec![1, 2]);
+        let s = Series::<String, usize>::from_vec(vec!["1.5".to_string(), "x".to_string()]);
+        let res: Series<f64, usize> = s.astype(true);
+        assert_eq!(res.values[0], 1.5);
+        assert!(res.values[1].is_nan());
+    }
 }
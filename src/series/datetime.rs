@@ -0,0 +1,171 @@
+use std::hash::Hash;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone};
+
+use super::Series;
+use datetime::DatetimeLike;
+use period::{Freq, Period};
+
+/// Datetime accessor of `Series`, created by `Series::dt`.
+pub struct DatetimeMethods<'s, 'v: 's, 'i: 's, V, I>
+where
+    V: 'v + Clone,
+    I: 'i + Clone + Hash,
+{
+    series: &'s Series<'v, 'i, V, I>,
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + DatetimeLike,
+    I: Clone + Eq + Hash,
+{
+    /// Access datetime properties of values.
+    pub fn dt<'s>(&'s self) -> DatetimeMethods<'s, 'v, 'i, V, I> {
+        DatetimeMethods { series: self }
+    }
+}
+
+impl<'v, 'i, V> Series<'v, 'i, V, i64>
+where
+    V: Clone,
+{
+    /// Values whose label is within partial timestamp `value` such as
+    /// "2018-03", index must be sorted nanoseconds since the UNIX epoch.
+    ///
+    /// See `Indexer::partial_locs`.
+    pub fn loc_partial<'n>(&self, value: &str) -> Series<'n, 'n, V, i64>
+    where
+        V: 'n,
+    {
+        let locs = self.index.partial_locs(value);
+        Series::new(self.values[locs.clone()].to_vec(), self.index.values[locs].to_vec())
+    }
+}
+
+impl<'s, 'v, 'i, V, I> DatetimeMethods<'s, 'v, 'i, V, I>
+where
+    V: Clone + DatetimeLike,
+    I: Clone + Eq + Hash,
+{
+    fn map_date<'n, F>(&self, func: F) -> Series<'n, 'n, Period, I>
+    where
+        I: 'n,
+        F: Fn(&NaiveDate) -> Period,
+    {
+        let values: Vec<Period> = self.series
+            .values
+            .iter()
+            .map(|x| func(&x.to_naive_local().date()))
+            .collect();
+        Series::new(values, self.series.index.clone().into_owned())
+    }
+
+    fn map_datetime<'n, Z, F>(&self, func: F) -> Series<'n, 'n, DateTime<Z>, I>
+    where
+        I: 'n,
+        Z: TimeZone,
+        F: Fn(&V) -> DateTime<Z>,
+    {
+        let values: Vec<DateTime<Z>> = self.series.values.iter().map(func).collect();
+        Series::new(values, self.series.index.clone().into_owned())
+    }
+
+    /// Values as chrono `NaiveDateTime` in UTC.
+    pub fn to_datetime<'n>(&self) -> Series<'n, 'n, NaiveDateTime, I> {
+        let values: Vec<NaiveDateTime> = self.series
+            .values
+            .iter()
+            .map(|x| x.to_naive_utc())
+            .collect();
+        Series::new(values, self.series.index.clone().into_owned())
+    }
+
+    /// Values as `i64` nanoseconds since the UNIX epoch, which is the
+    /// representation in `Array`. Panics if out of the range of `i64`.
+    pub fn to_nanos<'n>(&self) -> Series<'n, 'n, i64, I> {
+        let values: Vec<i64> = self.series.values.iter().map(|x| x.to_nanos()).collect();
+        Series::new(values, self.series.index.clone().into_owned())
+    }
+
+    /// Duration from each value of `other` to each value, index must be the
+    /// same.
+    pub fn duration_since<'n, W>(&self, other: &Series<W, I>) -> Series<'n, 'n, Duration, I>
+    where
+        W: Clone + DatetimeLike,
+    {
+        assert!(self.series.index == other.index, "index must be the same!");
+        let values: Vec<Duration> = self.series
+            .values
+            .iter()
+            .zip(other.values.iter())
+            .map(|(x, y)| x.to_naive_utc().signed_duration_since(y.to_naive_utc()))
+            .collect();
+        Series::new(values, self.series.index.clone().into_owned())
+    }
+
+    /// Format wall clock time of each value using chrono `format`.
+    pub fn strftime<'n>(&self, format: &str) -> Series<'n, 'n, String, I> {
+        let values: Vec<String> = self.series
+            .values
+            .iter()
+            .map(|x| x.format_local(format))
+            .collect();
+        Series::new(values, self.series.index.clone().into_owned())
+    }
+
+    /// Regard wall clock time of each value as local time of `tz`, such as
+    /// values parsed without offset.
+    ///
+    /// `tz` is a chrono `TimeZone` such as `FixedOffset`, or `chrono_tz::Tz`
+    /// whose offset follows daylight saving time. Wall clock time repeated when
+    /// the offset goes back is regarded as the earlier instant. Panics if wall
+    /// clock time is skipped when the offset goes forward.
+    pub fn tz_localize<'n, Z>(&self, tz: &Z) -> Series<'n, 'n, DateTime<Z>, I>
+    where
+        Z: TimeZone,
+    {
+        self.map_datetime(|x| {
+            let local = x.to_naive_local();
+            tz.from_local_datetime(&local)
+                .earliest()
+                .unwrap_or_else(|| panic!("{} doesn't exist in the timezone", local))
+        })
+    }
+
+    /// Same instants as datetime in `tz`, so that periods and formatting
+    /// follow local time of `tz`.
+    ///
+    /// `tz` is a chrono `TimeZone`, see `tz_localize`.
+    pub fn tz_convert<'n, Z>(&self, tz: &Z) -> Series<'n, 'n, DateTime<Z>, I>
+    where
+        Z: TimeZone,
+    {
+        self.map_datetime(|x| tz.from_utc_datetime(&x.to_naive_utc()))
+    }
+
+    /// Period of the frequency containing each value.
+    pub fn to_period<'n>(&self, freq: Freq) -> Series<'n, 'n, Period, I> {
+        self.map_date(|x| Period::from_date(x, freq))
+    }
+
+    /// ISO 8601 week of each value.
+    pub fn iso_week<'n>(&self) -> Series<'n, 'n, Period, I> {
+        self.map_date(Period::iso_week)
+    }
+
+    /// Calendar month of each value.
+    pub fn month<'n>(&self) -> Series<'n, 'n, Period, I> {
+        self.map_date(Period::month)
+    }
+
+    /// Calendar quarter of each value.
+    pub fn quarter<'n>(&self) -> Series<'n, 'n, Period, I> {
+        self.map_date(Period::quarter)
+    }
+
+    /// Fiscal quarter of each value, fiscal year starts at `start_month`.
+    pub fn fiscal_period<'n>(&self, start_month: u32) -> Series<'n, 'n, Period, I> {
+        self.map_date(|x| Period::fiscal_quarter(x, start_month))
+    }
+}
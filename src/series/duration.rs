@@ -0,0 +1,88 @@
+use std::hash::Hash;
+
+use chrono::Duration;
+
+use super::Series;
+
+impl<'v, 'i, I> Series<'v, 'i, Duration, I>
+where
+    I: Clone + Eq + Hash,
+{
+    /// Each duration in seconds including the fraction.
+    pub fn total_seconds<'n>(&self) -> Series<'n, 'n, f64, I>
+    where
+        I: 'n,
+    {
+        self.map(|x| x.num_seconds() as f64 + f64::from(x.subsec_nanos()) / 1e9)
+    }
+
+    /// Each duration in whole milliseconds, truncated toward zero.
+    pub fn total_millis<'n>(&self) -> Series<'n, 'n, i64, I>
+    where
+        I: 'n,
+    {
+        self.map(|x| x.num_milliseconds())
+    }
+
+    /// Total of durations, panics on overflow.
+    pub fn sum(&self) -> Duration {
+        self.values.iter().fold(Duration::zero(), |acc, x| {
+            acc.checked_add(x).expect("duration overflow")
+        })
+    }
+
+    /// Mean of durations in nanoseconds precision, `None` if empty.
+    pub fn mean(&self) -> Option<Duration> {
+        if self.values.is_empty() {
+            return None;
+        }
+        let total: i128 = self.values
+            .iter()
+            .map(|x| {
+                i128::from(x.num_seconds()) * 1_000_000_000 + i128::from(x.subsec_nanos())
+            })
+            .sum();
+        let mean = total / self.values.len() as i128;
+        let seconds = mean.div_euclid(1_000_000_000) as i64;
+        let nanos = mean.rem_euclid(1_000_000_000) as i64;
+        Some(Duration::seconds(seconds) + Duration::nanoseconds(nanos))
+    }
+
+    /// Minimum duration, `None` if empty.
+    pub fn min(&self) -> Option<Duration> {
+        self.values.iter().min().cloned()
+    }
+
+    /// Maximum duration, `None` if empty.
+    pub fn max(&self) -> Option<Duration> {
+        self.values.iter().max().cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::Duration;
+
+    use super::super::Series;
+
+    #[test]
+    fn test_series_duration() {
+        let s: Series<Duration, &str> = Series::new(
+            vec![Duration::milliseconds(1500), Duration::milliseconds(-2500), Duration::minutes(1)],
+            vec!["a", "b", "c"],
+        );
+        assert_eq!(s.total_seconds(), Series::new(vec![1.5, -2.5, 60.], vec!["a", "b", "c"]));
+        assert_eq!(s.total_millis().values.to_vec(), vec![1500, -2500, 60000]);
+
+        assert_eq!(s.sum(), Duration::milliseconds(59_000));
+        assert_eq!(s.mean(), Some(Duration::nanoseconds(19_666_666_666)));
+        assert_eq!(s.min(), Some(Duration::milliseconds(-2500)));
+        assert_eq!(s.max(), Some(Duration::minutes(1)));
+
+        let s = Series::<Duration, usize>::from_vec(vec![]);
+        assert_eq!(s.sum(), Duration::zero());
+        assert_eq!(s.mean(), None);
+        assert_eq!(s.max(), None);
+    }
+}
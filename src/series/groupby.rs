@@ -3,12 +3,17 @@ use num::{Zero, ToPrimitive};
 use std::cmp::Ord;
 use std::ops::{Add, Sub, Div};
 use std::hash::Hash;
+use std::borrow::Cow;
+
+use nullvec::prelude::Array;
 
 use super::Series;
 use algos::computation::NanMinMax;
 use algos::grouper::Grouper;
+use frame::DataFrame;
 use groupby::GroupBy;
-use traits::{Apply, BasicAggregation, NumericAggregation, ComparisonAggregation};
+use traits::{Agg, Apply, BasicAggregation, NumericAggregation, ComparisonAggregation,
+             SelectionAggregation};
 
 /// /////////////////////////////////////////////////////////////////////////////
 /// Apply
@@ -44,6 +49,71 @@ where
     }
 }
 
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+where
+    V: 'v + Clone,
+    I: Clone + Eq + Hash,
+    G: 'i + Clone + Eq + Hash + Ord,
+{
+    /// Apply passed function to each group, and put its results back to the
+    /// locations of the group. Result has the same index as the original.
+    ///
+    /// The function must return the same number of values as the group.
+    pub fn transform<'n, W, F>(&'i self, func: F) -> Series<'n, 'n, W, I>
+    where
+        W: 'n + Clone,
+        I: 'n,
+        G: 'n,
+        F: Fn(&Series<'v, 'i, V, I>) -> Vec<W>,
+    {
+        self.transform_groups(func).data.into_owned()
+    }
+
+    /// Ordinal of each row within its group, starting from 0. Result has the
+    /// same index as the original.
+    pub fn cumcount<'n>(&self) -> Series<'n, 'n, usize, I>
+    where
+        I: 'n,
+    {
+        let mut new_values: Vec<usize> = vec![0; self.data.values.len()];
+        for locs in self.grouper.groups.values() {
+            for (i, loc) in locs.iter().enumerate() {
+                new_values[*loc] = i;
+            }
+        }
+        Series::new(new_values, self.data.index.clone().into_owned())
+    }
+
+    /// Same as `transform`, but keeps the grouping so that further
+    /// transforms can be chained.
+    pub fn transform_groups<'n, W, F>(&'i self, func: F) -> GroupBy<'n, Series<'n, 'n, W, I>, G>
+    where
+        W: 'n + Clone,
+        I: 'n,
+        G: 'n,
+        F: Fn(&Series<'v, 'i, V, I>) -> Vec<W>,
+    {
+        let mut new_values: Vec<Option<W>> = vec![None; self.data.values.len()];
+        for g in &self.groups() {
+            let locs = self.grouper.get(g).unwrap();
+            let values = func(&self.get_group(g));
+            assert!(
+                values.len() == locs.len(),
+                "Function must return the same length as the group"
+            );
+            for (loc, value) in locs.iter().zip(values) {
+                new_values[*loc] = Some(value);
+            }
+        }
+        let new_values: Vec<W> = new_values.into_iter().map(|x| x.unwrap()).collect();
+        let data = Series::new(new_values, self.data.index.clone().into_owned());
+        GroupBy {
+            data: Cow::Owned(data),
+            grouper: self.grouper.clone(),
+        }
+    }
+}
+
 /// /////////////////////////////////////////////////////////////////////////////
 /// Aggregation
 /// /////////////////////////////////////////////////////////////////////////////
@@ -115,6 +185,73 @@ impl<'v, 'i, V, I, G> ComparisonAggregation<'i> for GroupBy<'i, Series<'v, 'i, V
     }
 }
 
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: Clone,
+          I: Clone + Eq + Hash,
+          G: 'i + Clone + Eq + Hash + Ord
+{
+    /// Select a value of each group by its locations, groups which select
+    /// nothing are dropped.
+    fn select<F>(&self, func: F) -> Series<'i, 'i, V, G>
+        where F: Fn(&[usize]) -> Option<usize>
+    {
+        let mut new_values: Vec<V> = vec![];
+        let mut new_index: Vec<G> = vec![];
+        for g in self.groups() {
+            if let Some(loc) = func(self.grouper.get(&g).unwrap()) {
+                new_values.push(self.data.values[loc].clone());
+                new_index.push(g);
+            }
+        }
+        Series::new(new_values, new_index)
+    }
+}
+
+impl<'v, 'i, V, I, G> SelectionAggregation<'i> for GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: Clone,
+          I: Clone + Eq + Hash,
+          G: 'i + Clone + Eq + Hash + Ord
+{
+    // groups which don't have the value are dropped
+    type Selected = Series<'i, 'i, V, G>;
+
+    fn first(&'i self) -> Self::Selected {
+        self.nth(0)
+    }
+
+    fn last(&'i self) -> Self::Selected {
+        self.select(|x| x.last().cloned())
+    }
+
+    fn nth(&'i self, n: usize) -> Self::Selected {
+        self.select(|x| x.get(n).cloned())
+    }
+}
+
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+    where V: Clone + Zero + Add + Sub + Div + ToPrimitive + NanMinMax<V>,
+          Vec<V>: Into<Array>,
+          I: Clone + Eq + Hash,
+          G: 'i + Clone + Eq + Hash + Ord
+{
+    /// Apply multiple aggregations to each group, result has a column for
+    /// each aggregation.
+    pub fn agg(&'i self, aggs: &[Agg]) -> DataFrame<'i, 'i, 'i, G, Agg> {
+        let new_values: Vec<Array> = aggs.iter()
+            .map(|agg| match *agg {
+                Agg::Sum => self.sum().values.into_owned().into(),
+                Agg::Count => self.count().values.into_owned().into(),
+                Agg::Mean => self.mean().values.into_owned().into(),
+                Agg::Var => self.var().values.into_owned().into(),
+                Agg::Std => self.std().values.into_owned().into(),
+                Agg::Min => self.min().values.into_owned().into(),
+                Agg::Max => self.max().values.into_owned().into(),
+            })
+            .collect();
+        DataFrame::from_vec(new_values, self.groups(), aggs.to_vec())
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,71 @@
+use num::{Zero, ToPrimitive};
+use std::hash::Hash;
+use std::ops::Add;
+
+use super::Series;
+use algos::computation::Aggregation;
+
+/// List accessor of `Series` whose values are `Vec`, created by `Series::list`.
+pub struct ListMethods<'s, 'v: 's, 'i: 's, V, I>
+where
+    V: 'v + Clone,
+    I: 'i + Clone + Hash,
+{
+    series: &'s Series<'v, 'i, Vec<V>, I>,
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, Vec<V>, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    /// Access elementwise properties of list values.
+    pub fn list<'s>(&'s self) -> ListMethods<'s, 'v, 'i, V, I> {
+        ListMethods { series: self }
+    }
+}
+
+impl<'s, 'v, 'i, V, I> ListMethods<'s, 'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    fn map_list<'n, W, F>(&self, func: F) -> Series<'n, 'n, W, I>
+    where
+        W: Clone,
+        I: 'n,
+        F: Fn(&Vec<V>) -> W,
+    {
+        let values: Vec<W> = self.series.values.iter().map(func).collect();
+        Series::new(values, self.series.index.clone().into_owned())
+    }
+
+    /// Length of each list.
+    pub fn len<'n>(&self) -> Series<'n, 'n, usize, I> {
+        self.map_list(Vec::len)
+    }
+
+    /// Whether each list contains `value`.
+    pub fn contains<'n>(&self, value: &V) -> Series<'n, 'n, bool, I>
+    where
+        V: PartialEq,
+    {
+        self.map_list(|x| x.contains(value))
+    }
+
+    /// Sum of each list, empty list results in zero.
+    pub fn sum<'n>(&self) -> Series<'n, 'n, V, I>
+    where
+        V: Zero + Add,
+    {
+        self.map_list(Aggregation::vec_sum)
+    }
+
+    /// Mean of each list, empty list results in NaN.
+    pub fn mean<'n>(&self) -> Series<'n, 'n, f64, I>
+    where
+        V: Zero + Add + ToPrimitive,
+    {
+        self.map_list(Aggregation::vec_mean)
+    }
+}
@@ -0,0 +1,168 @@
+use std::hash::Hash;
+
+use num::{Float, ToPrimitive};
+
+use super::Series;
+
+macro_rules! define_float_fn {
+    ($m:ident, $doc:expr) => {
+        #[doc = $doc]
+        pub fn $m<'n>(&self) -> Series<'n, 'n, V, I>
+        where
+            I: 'n,
+        {
+            self.map_float(|x| x.$m())
+        }
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Float,
+    I: Clone + Eq + Hash,
+{
+    fn map_float<'n, F>(&self, func: F) -> Series<'n, 'n, V, I>
+    where
+        I: 'n,
+        F: Fn(V) -> V,
+    {
+        let new_values: Vec<V> = self.values.iter().map(|&x| func(x)).collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    define_float_fn!(exp, "Exponential of each value.");
+    define_float_fn!(ln, "Natural logarithm of each value.");
+    define_float_fn!(log10, "Base 10 logarithm of each value.");
+    define_float_fn!(sqrt, "Square root of each value.");
+    define_float_fn!(round, "Round each value to the nearest integer, away from zero on halves.");
+    define_float_fn!(floor, "Largest integer less than or equal to each value.");
+    define_float_fn!(ceil, "Smallest integer greater than or equal to each value.");
+
+    /// Raise each value to integer power `n`.
+    pub fn powi<'n>(&self, n: i32) -> Series<'n, 'n, V, I>
+    where
+        I: 'n,
+    {
+        self.map_float(|x| x.powi(n))
+    }
+
+    /// Raise each value to float power `n`.
+    pub fn powf<'n>(&self, n: V) -> Series<'n, 'n, V, I>
+    where
+        I: 'n,
+    {
+        self.map_float(|x| x.powf(n))
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    /// Shift values by `periods` keeping the index, forward if positive and
+    /// backward if negative. Locations without value are `None`.
+    pub fn shift<'n>(&self, periods: isize) -> Series<'n, 'n, Option<V>, I>
+    where
+        I: 'n,
+    {
+        let len = self.values.len() as isize;
+        let new_values: Vec<Option<V>> = (0..len)
+            .map(|i| {
+                let src = i - periods;
+                if src >= 0 && src < len {
+                    Some(self.values[src as usize].clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    /// Apply `func` to each value and the value `periods` before as `f64`,
+    /// NaN where the latter doesn't exist.
+    fn map_shifted<'n, F>(&self, periods: isize, func: F) -> Series<'n, 'n, f64, I>
+    where
+        I: 'n,
+        F: Fn(f64, f64) -> f64,
+    {
+        let shifted = self.shift(periods);
+        let new_values: Vec<f64> = self.to_f64_vec()
+            .into_iter()
+            .zip(shifted.values.iter())
+            .map(|(x, prev)| match *prev {
+                Some(ref p) => func(x, p.to_f64().unwrap_or(f64::NAN)),
+                None => f64::NAN,
+            })
+            .collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Difference from the value `periods` before, NaN where it doesn't
+    /// exist.
+    pub fn diff<'n>(&self, periods: isize) -> Series<'n, 'n, f64, I>
+    where
+        I: 'n,
+    {
+        self.map_shifted(periods, |x, prev| x - prev)
+    }
+
+    /// Fractional change from the value `periods` before, NaN where it
+    /// doesn't exist.
+    pub fn pct_change<'n>(&self, periods: isize) -> Series<'n, 'n, f64, I>
+    where
+        I: 'n,
+    {
+        self.map_shifted(periods, |x, prev| x / prev - 1.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Series;
+
+    #[test]
+    fn test_series_math() {
+        let s: Series<f64, &str> = Series::new(vec![1., 4., 100.], vec!["a", "b", "c"]);
+        assert_eq!(s.sqrt(), Series::new(vec![1., 2., 10.], vec!["a", "b", "c"]));
+        assert_eq!(s.log10(), Series::new(vec![0., 4f64.log10(), 2.], vec!["a", "b", "c"]));
+        assert_eq!(s.ln().exp().round(), s);
+        assert_eq!(s.powi(2), Series::new(vec![1., 16., 10000.], vec!["a", "b", "c"]));
+        assert_eq!(s.powf(0.5), s.sqrt());
+
+        let s: Series<f32, i64> = Series::new(vec![-1.5, 1.5, 2.2], vec![1, 2, 3]);
+        assert_eq!(s.round(), Series::new(vec![-2., 2., 2.], vec![1, 2, 3]));
+        assert_eq!(s.floor(), Series::new(vec![-2., 1., 2.], vec![1, 2, 3]));
+        assert_eq!(s.ceil(), Series::new(vec![-1., 2., 3.], vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_series_shift_diff() {
+        let s: Series<i64, &str> = Series::new(vec![2, 3, 6, 3], vec!["a", "b", "c", "d"]);
+        let exp = Series::new(vec![None, Some(2), Some(3), Some(6)], vec!["a", "b", "c", "d"]);
+        assert!(s.shift(1) == exp);
+        let exp = Series::new(vec![Some(6), Some(3), None, None], vec!["a", "b", "c", "d"]);
+        assert!(s.shift(-2) == exp);
+        assert!(s.shift(0).values.iter().all(|x| x.is_some()));
+
+        let res = s.diff(1);
+        assert!(res.values[0].is_nan());
+        assert_eq!(&res.values[1..], &[1., 3., -3.]);
+        let res = s.diff(-1);
+        assert_eq!(&res.values[..3], &[-1., -3., 3.]);
+        assert!(res.values[3].is_nan());
+
+        let res = s.pct_change(2);
+        assert!(res.values[0].is_nan() && res.values[1].is_nan());
+        assert_eq!(&res.values[2..], &[2., 0.]);
+        assert_eq!(res.index, s.index);
+    }
+}
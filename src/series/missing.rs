@@ -0,0 +1,128 @@
+use std::hash::Hash;
+
+use super::Series;
+use traits::{RowIndex, NullValue};
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + NullValue,
+    I: Clone + Eq + Hash,
+{
+    /// Replace nulls with `value`.
+    pub fn fillna<'n>(&self, value: V) -> Series<'n, 'n, V, I>
+    where
+        I: 'n,
+    {
+        let new_values: Vec<V> = self.values
+            .iter()
+            .map(|x| if x.is_null() { value.clone() } else { x.clone() })
+            .collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Replace nulls with the previous valid value, leading nulls are kept.
+    pub fn ffill<'n>(&self) -> Series<'n, 'n, V, I>
+    where
+        I: 'n,
+    {
+        let mut new_values: Vec<V> = self.values.to_vec();
+        for i in 1..new_values.len() {
+            if new_values[i].is_null() {
+                new_values[i] = new_values[i - 1].clone();
+            }
+        }
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Replace nulls with the next valid value, trailing nulls are kept.
+    pub fn bfill<'n>(&self) -> Series<'n, 'n, V, I>
+    where
+        I: 'n,
+    {
+        let mut new_values: Vec<V> = self.values.to_vec();
+        for i in (0..new_values.len().saturating_sub(1)).rev() {
+            if new_values[i].is_null() {
+                new_values[i] = new_values[i + 1].clone();
+            }
+        }
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Whether each value is null. Result has the same index as the original.
+    pub fn isnull<'n>(&self) -> Series<'n, 'n, bool, I>
+    where
+        I: 'n,
+    {
+        let new_values: Vec<bool> = self.values.iter().map(|x| x.is_null()).collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Whether each value is not null. Result has the same index as the
+    /// original.
+    pub fn notnull<'n>(&self) -> Series<'n, 'n, bool, I>
+    where
+        I: 'n,
+    {
+        let new_values: Vec<bool> = self.values.iter().map(|x| !x.is_null()).collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Number of nulls.
+    pub fn null_count(&self) -> usize {
+        self.values.iter().filter(|x| x.is_null()).count()
+    }
+
+    /// Remove nulls with their labels.
+    pub fn dropna(&'i self) -> Self {
+        let flags: Vec<bool> = self.values.iter().map(|x| !x.is_null()).collect();
+        self.blocs(&flags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use super::super::Series;
+
+    #[test]
+    fn test_series_fillna() {
+        let nan = f64::NAN;
+        let values = vec![nan, 1., nan, nan, 4., nan];
+        let s: Series<f64, i64> = Series::new(values, vec![1, 2, 3, 4, 5, 6]);
+        let res = s.fillna(0.);
+        assert_eq!(res.values.to_vec(), vec![0., 1., 0., 0., 4., 0.]);
+        assert_eq!(res.index, s.index);
+
+        let res = s.ffill();
+        assert!(res.values[0].is_nan());
+        assert_eq!(res.values[1..], [1., 1., 1., 4., 4.]);
+
+        let res = s.bfill();
+        assert_eq!(res.values[..5], [1., 1., 4., 4., 4.]);
+        assert!(res.values[5].is_nan());
+    }
+
+    #[test]
+    fn test_series_isnull() {
+        let nan = f64::NAN;
+        let s: Series<f64, i64> = Series::new(vec![nan, 1., nan, 3.], vec![1, 2, 3, 4]);
+        let exp: Series<bool, i64> = Series::new(vec![true, false, true, false], vec![1, 2, 3, 4]);
+        assert_eq!(s.isnull(), exp);
+        let exp: Series<bool, i64> = Series::new(vec![false, true, false, true], vec![1, 2, 3, 4]);
+        assert_eq!(s.notnull(), exp);
+        assert_eq!(s.null_count(), 2);
+    }
+
+    #[test]
+    fn test_series_dropna() {
+        let nan = f64::NAN;
+        let s: Series<f64, i64> = Series::new(vec![nan, 1., nan, 3.], vec![1, 2, 3, 4]);
+        let exp: Series<f64, i64> = Series::new(vec![1., 3.], vec![2, 4]);
+        assert_eq!(s.dropna(), exp);
+
+        let s: Series<i64, i64> = Series::new(vec![1, 2], vec![1, 2]);
+        assert_eq!(s.dropna(), s);
+    }
+}
@@ -1,20 +1,50 @@
 use std::borrow::Cow;
+use std::fmt::Debug;
 use std::hash::Hash;
 use std::iter::FromIterator;
 use std::slice;
+use std::str::FromStr;
 use std::vec;
 
+use num::ToPrimitive;
 use nullvec::prelude::dev::algos::Indexing;
+use error::Error;
 use indexer::Indexer;
-use groupby::GroupBy;
+use groupby::{GroupBy, GroupKeys};
 use traits::{Slicer, IndexerIndex, RowIndex, Append, Apply};
 
 mod aggregation;
+mod align;
+mod asof;
+mod binning;
+mod checked;
 mod convert;
+#[cfg(feature = "chrono")]
+mod datetime;
+#[cfg(feature = "chrono")]
+mod duration;
 mod formatting;
 mod groupby;
+mod list;
+mod math;
+mod missing;
+mod multi;
 mod ops;
+mod resample;
+mod signal;
 mod sort;
+#[cfg(feature = "rustfft")]
+mod spectrum;
+mod string;
+mod view;
+
+#[cfg(feature = "chrono")]
+pub use self::datetime::DatetimeMethods;
+pub use self::binning::Interval;
+pub use self::list::ListMethods;
+pub use self::resample::Upsampler;
+pub use self::string::StringMethods;
+pub use self::view::SeriesView;
 
 #[derive(Clone)]
 pub struct Series<'v, 'i, V, I>
@@ -73,6 +103,46 @@ where
     }
 }
 
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash + Debug,
+{
+    /// Get a single value corresponding to given label, or `Error::KeyNotFound`
+    pub fn try_loc(&self, label: &I) -> Result<V, Error> {
+        let loc = self.index.try_get_loc(label)?;
+        Ok(self.iloc(&loc))
+    }
+
+    /// Get a single value corresponding to given location, or `Error::OutOfBounds`
+    pub fn try_iloc(&self, location: &usize) -> Result<V, Error> {
+        match self.values.get(*location) {
+            Some(value) => Ok(value.clone()),
+            None => Err(Error::OutOfBounds {
+                location: *location,
+                len: self.len(),
+            }),
+        }
+    }
+
+    /// Slice using given labels, or return `Error::KeyNotFound`
+    pub fn try_locs(&'i self, labels: &[I]) -> Result<Self, Error> {
+        let locations = self.index.try_get_locs(labels)?;
+        Ok(self.reindex_by_index(&locations))
+    }
+
+    /// Slice using given locations, or return `Error::OutOfBounds`
+    pub fn try_ilocs(&'i self, locations: &[usize]) -> Result<Self, Error> {
+        if let Some(&location) = locations.iter().find(|&&x| x >= self.len()) {
+            return Err(Error::OutOfBounds {
+                location,
+                len: self.len(),
+            });
+        }
+        Ok(self.reindex_by_index(locations))
+    }
+}
+
 /// /////////////////////////////////////////////////////////////////////////////
 /// Misc
 /// /////////////////////////////////////////////////////////////////////////////
@@ -92,18 +162,31 @@ where
     }
 
     pub fn new<X>(values: Vec<V>, index: X) -> Self
+    where
+        X: Into<Indexer<I>>,
+    {
+        Series::try_new(values, index).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Create `Series`, or return `Error::LengthMismatch` if lengths are different
+    pub fn try_new<X>(values: Vec<V>, index: X) -> Result<Self, Error>
     where
         X: Into<Indexer<I>>,
     {
 
         let index: Indexer<I> = index.into();
 
-        assert!(values.len() == index.len(), "Length mismatch!");
+        if values.len() != index.len() {
+            return Err(Error::LengthMismatch {
+                expected: index.len(),
+                actual: values.len(),
+            });
+        }
 
-        Series {
+        Ok(Series {
             values: Cow::Owned(values),
             index: Cow::Owned(index),
-        }
+        })
     }
 
     pub fn from_cow(values: Cow<'v, Vec<V>>, index: Cow<'i, Indexer<I>>) -> Self {
@@ -116,16 +199,50 @@ where
         }
     }
 
+    /// Convert index labels to other type by parsing their string representation.
+    pub fn index_astype<'n, J>(&self) -> Series<'v, 'n, V, J>
+    where
+        I: ToString,
+        J: 'n + Clone + Eq + Hash + FromStr,
+    {
+        self.try_index_astype().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Convert index labels to other type, or return `Error::ParseLabel`
+    pub fn try_index_astype<'n, J>(&self) -> Result<Series<'v, 'n, V, J>, Error>
+    where
+        I: ToString,
+        J: 'n + Clone + Eq + Hash + FromStr,
+    {
+        let index = self.index.try_astype()?;
+        Ok(Series::from_cow(self.values.clone(), Cow::Owned(index)))
+    }
+
     fn assert_binop(&self, other: &Self) {
         assert!(self.index == other.index, "index must be the same!");
     }
 
+    fn check_binop(&self, other: &Self) -> Result<(), Error> {
+        if self.index != other.index {
+            return Err(Error::IndexMismatch);
+        }
+        Ok(())
+    }
+
     pub fn groupby<G>(&self, other: &[G]) -> GroupBy<Series<V, I>, G>
     where
         G: Clone + Eq + Hash + Ord,
     {
         GroupBy::new(self, other)
     }
+
+    /// Group by multiple keys, such as `(&keys1, &keys2)`, labelled by tuples.
+    pub fn groupby_multi<'s, K>(&'s self, keys: K) -> GroupBy<'s, Series<'s, 's, V, I>, K::Key>
+    where
+        K: GroupKeys,
+    {
+        GroupBy::new(self, &keys.combine())
+    }
 }
 
 /// /////////////////////////////////////////////////////////////////////////////
@@ -165,6 +282,47 @@ where
     }
 }
 
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    /// Apply `func` to each value, result has the same index.
+    pub fn map<'n, W, F>(&self, func: F) -> Series<'n, 'n, W, I>
+    where
+        I: 'n,
+        W: Clone,
+        F: FnMut(&V) -> W,
+    {
+        let new_values: Vec<W> = self.values.iter().map(func).collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Apply `func` to each value in place. Borrowed values are copied once,
+    /// and index is kept as it is.
+    pub fn map_in_place<F>(&mut self, func: F)
+    where
+        F: FnMut(&mut V),
+    {
+        self.values.to_mut().iter_mut().for_each(func);
+    }
+
+    /// Replace each value by the value of `mapping` labelled by it, `None`
+    /// if the label doesn't exist in `mapping`. Result has the same index.
+    pub fn map_lookup<'n, W>(&self, mapping: &Series<W, V>) -> Series<'n, 'n, Option<W>, I>
+    where
+        I: 'n,
+        V: Eq + Hash,
+        W: Clone,
+    {
+        self.map(|x| if mapping.index.contains(x) {
+            Some(mapping.values[mapping.index.get_loc(x)].clone())
+        } else {
+            None
+        })
+    }
+}
+
 /// /////////////////////////////////////////////////////////////////////////////
 /// Eq
 /// /////////////////////////////////////////////////////////////////////////////
@@ -206,6 +364,20 @@ where
     }
 }
 
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    /// Values as `f64`, values which can't be represented are NaN.
+    fn to_f64_vec(&self) -> Vec<f64> {
+        self.values
+            .iter()
+            .map(|x| x.to_f64().unwrap_or(f64::NAN))
+            .collect()
+    }
+}
+
 impl<'v, 'i, V> FromIterator<V> for Series<'v, 'i, V, usize>
 where
     V: Clone,
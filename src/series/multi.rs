@@ -0,0 +1,53 @@
+use nullvec::prelude::dev::algos::Indexing;
+
+use super::Series;
+use groupby::GroupBy;
+use indexer::MultiLabel;
+use traits::Slicer;
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: MultiLabel,
+{
+    /// Select values whose first index level is `head`, the level is removed.
+    ///
+    /// Result is empty if `head` is not found.
+    pub fn xs_first<'n>(&self, head: &I::Head) -> Series<'n, 'n, V, I::Tail>
+    where
+        V: 'n,
+        I::Tail: 'n,
+    {
+        let locs = self.index.xs_first_locs(head);
+        let new_values = Indexing::reindex(&self.values, &locs);
+        let new_index = self.index.ilocs(&locs).drop_first_level();
+        Series::new(new_values, new_index)
+    }
+
+    /// Remove the first index level.
+    pub fn drop_first_level<'n>(&self) -> Series<'n, 'n, V, I::Tail>
+    where
+        V: 'n,
+        I::Tail: 'n,
+    {
+        Series::new(self.values.clone().into_owned(), self.index.drop_first_level())
+    }
+
+    /// Swap the first and second index levels.
+    pub fn swap_first_two<'n>(&self) -> Series<'n, 'n, V, I::Swapped>
+    where
+        V: 'n,
+        I::Swapped: 'n,
+    {
+        Series::new(self.values.clone().into_owned(), self.index.swap_first_two())
+    }
+
+    /// Group by the first index level.
+    pub fn groupby_first_level<'s>(&'s self) -> GroupBy<'s, Series<'s, 's, V, I>, I::Head>
+    where
+        I::Head: Ord,
+    {
+        let keys = self.index.get_level_head();
+        GroupBy::new(self, &keys)
+    }
+}
@@ -1,8 +1,13 @@
 use std::borrow::{Borrow, Cow};
 use std::hash::Hash;
-use std::ops::{Add, Mul, Sub, Div, Rem, BitAnd, BitOr, BitXor};
+use std::ops::{Add, Mul, Sub, Div, Rem, BitAnd, BitOr, BitXor, Not, Neg};
+use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign};
+
+use num::Signed;
 
 use super::Series;
+use error::Error;
+use traits::{NullValue, NullEquality};
 use nullvec::prelude::dev::algos::Elemwise;
 
 macro_rules! define_numeric_op {
@@ -145,10 +150,334 @@ define_numeric_op!(BitAnd, bitand);
 define_numeric_op!(BitOr, bitor);
 define_numeric_op!(BitXor, bitxor);
 
+macro_rules! define_assign_op {
+    ($t:ident, $m:ident) => {
+
+        // Broadcast, values are updated in place
+        impl<'v, 'i, V, I> $t<V> for Series<'v, 'i, V, I>
+            where V: Clone + $t,
+                  I: Clone + Eq + Hash {
+
+            fn $m(&mut self, _rhs: V) {
+                for x in self.values.to_mut().iter_mut() {
+                    x.$m(_rhs.clone());
+                }
+            }
+        }
+
+        impl<'v, 'i, 'r, V, I> $t<&'r V> for Series<'v, 'i, V, I>
+            where V: Clone + $t,
+                  I: Clone + Eq + Hash {
+
+            fn $m(&mut self, _rhs: &'r V) {
+                for x in self.values.to_mut().iter_mut() {
+                    x.$m(_rhs.clone());
+                }
+            }
+        }
+
+        // Element-wise, index must be the same
+        impl<'lv, 'rv, 'li, 'ri, 'r, V, I> $t<&'r Series<'rv, 'ri, V, I>>
+            for Series<'lv, 'li, V, I>
+            where V: Clone + $t,
+                  I: Clone + Eq + Hash {
+
+            fn $m(&mut self, _rhs: &'r Series<V, I>) {
+                self.assert_binop(_rhs);
+                for (x, y) in self.values.to_mut().iter_mut().zip(_rhs.values.iter()) {
+                    x.$m(y.clone());
+                }
+            }
+        }
+
+        impl<'lv, 'rv, 'li, 'ri, V, I> $t<Series<'rv, 'ri, V, I>> for Series<'lv, 'li, V, I>
+            where V: Clone + $t,
+                  I: Clone + Eq + Hash {
+
+            fn $m(&mut self, _rhs: Series<V, I>) {
+                self.assert_binop(&_rhs);
+                for (x, y) in self.values.to_mut().iter_mut().zip(_rhs.values.into_owned()) {
+                    x.$m(y);
+                }
+            }
+        }
+    }
+}
+
+define_assign_op!(AddAssign, add_assign);
+define_assign_op!(SubAssign, sub_assign);
+define_assign_op!(MulAssign, mul_assign);
+define_assign_op!(DivAssign, div_assign);
+
+impl<'v, 'i, V, I, O> Not for Series<'v, 'i, V, I>
+    where V: Clone + Not<Output=O>,
+          I: Clone + Eq + Hash,
+          O: 'v + Clone {
+
+    type Output = Series<'v, 'i, O, I>;
+    fn not(self) -> Self::Output {
+        let new_values: Vec<O> = self.values.iter().map(|x| !x.clone()).collect();
+        Series::from_cow(Cow::Owned(new_values), self.index)
+    }
+}
+
+impl<'v, 'i, 'l, V, I, O> Not for &'l Series<'v, 'i, V, I>
+    where V: Clone + Not<Output=O>,
+          I: Clone + Eq + Hash,
+          O: 'l + Clone {
+
+    type Output = Series<'l, 'l, O, I>;
+    fn not(self) -> Self::Output {
+        let new_values: Vec<O> = self.values.iter().map(|x| !x.clone()).collect();
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+}
+
+impl<'v, 'i, V, I, O> Neg for Series<'v, 'i, V, I>
+    where V: Clone + Neg<Output=O>,
+          I: Clone + Eq + Hash,
+          O: 'v + Clone {
+
+    type Output = Series<'v, 'i, O, I>;
+    fn neg(self) -> Self::Output {
+        let new_values: Vec<O> = self.values.iter().map(|x| -x.clone()).collect();
+        Series::from_cow(Cow::Owned(new_values), self.index)
+    }
+}
+
+impl<'v, 'i, 'l, V, I, O> Neg for &'l Series<'v, 'i, V, I>
+    where V: Clone + Neg<Output=O>,
+          I: Clone + Eq + Hash,
+          O: 'l + Clone {
+
+    type Output = Series<'l, 'l, O, I>;
+    fn neg(self) -> Self::Output {
+        let new_values: Vec<O> = self.values.iter().map(|x| -x.clone()).collect();
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + Signed,
+          I: Clone + Eq + Hash {
+
+    /// Absolute value of each value.
+    pub fn abs<'n>(&self) -> Series<'n, 'n, V, I>
+        where I: 'n {
+        let new_values: Vec<V> = self.values.iter().map(|x| x.abs()).collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+}
+
+impl<'v, 'i, I> Series<'v, 'i, bool, I>
+    where I: Clone + Eq + Hash {
+
+    /// Whether any value is true, false if empty.
+    pub fn any(&self) -> bool {
+        self.values.iter().any(|x| *x)
+    }
+
+    /// Whether all values are true, true if empty.
+    pub fn all(&self) -> bool {
+        self.values.iter().all(|x| *x)
+    }
+}
+
+macro_rules! define_try_op {
+    ($t:ident, $m:ident, $try_m:ident) => {
+
+        impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+            where V: Clone,
+                  I: Clone + Eq + Hash {
+
+            /// Element-wise operation, returns `Error::IndexMismatch` if index are different
+            pub fn $try_m<'l, O>(&'l self, other: &Series<V, I>)
+                -> Result<Series<'l, 'l, O, I>, Error>
+                where V: $t<Output=O>,
+                      O: 'l + Clone {
+                self.check_binop(other)?;
+                Ok($t::$m(self, other))
+            }
+        }
+    }
+}
+
+define_try_op!(Add, add, try_add);
+define_try_op!(Mul, mul, try_mul);
+define_try_op!(Sub, sub, try_sub);
+define_try_op!(Div, div, try_div);
+define_try_op!(Rem, rem, try_rem);
+
+macro_rules! define_comparison_op {
+    ($m:ident, $series_m:ident, $op:tt, $doc:expr) => {
+
+        impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+            where V: Clone + PartialOrd,
+                  I: Clone + Eq + Hash {
+
+            #[doc = $doc]
+            pub fn $m<'n>(&self, other: &V) -> Series<'n, 'n, bool, I>
+                where I: 'n {
+                let new_values: Vec<bool> = self.values.iter().map(|x| x $op other).collect();
+                Series::new(new_values, self.index.clone().into_owned())
+            }
+
+            /// Element-wise comparison with other `Series`, index must be the same.
+            pub fn $series_m<'n>(&self, other: &Series<V, I>) -> Series<'n, 'n, bool, I>
+                where I: 'n {
+                self.assert_binop(other);
+                let new_values: Vec<bool> = self.values
+                    .iter()
+                    .zip(other.values.iter())
+                    .map(|(x, y)| x $op y)
+                    .collect();
+                Series::new(new_values, self.index.clone().into_owned())
+            }
+        }
+    }
+}
+
+define_comparison_op!(gt, gt_series, >, "Whether each value is greater than `other`.");
+define_comparison_op!(ge, ge_series, >=, "Whether each value is greater than or equal to `other`.");
+define_comparison_op!(lt, lt_series, <, "Whether each value is less than `other`.");
+define_comparison_op!(le, le_series, <=, "Whether each value is less than or equal to `other`.");
+define_comparison_op!(eq_elem, eq_series, ==, "Whether each value is equal to `other`.");
+define_comparison_op!(ne_elem, ne_series, !=, "Whether each value is not equal to `other`.");
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+    where V: Clone + PartialEq + NullValue,
+          I: Clone + Eq + Hash {
+
+    /// Element-wise equality with other `Series`, nulls are equal to each other
+    /// only with `NullEquality::Equal`.
+    pub fn eq_series_with<'n>(&self, other: &Series<V, I>, nulls: NullEquality)
+        -> Series<'n, 'n, bool, I>
+        where I: 'n {
+        self.assert_binop(other);
+        let new_values: Vec<bool> = self.values
+            .iter()
+            .zip(other.values.iter())
+            .map(|(x, y)| match (x.is_null(), y.is_null()) {
+                (true, true) => nulls == NullEquality::Equal,
+                (false, false) => x == y,
+                _ => false,
+            })
+            .collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
+    use std::borrow::Cow;
+
     use super::super::Series;
+    use error::Error;
+    use indexer::Indexer;
+
+    #[test]
+    fn test_series_try_ops() {
+        let l = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]);
+        let r = Series::<i64, i64>::new(vec![2, 3, 4], vec![10, 20, 30]);
+        let exp = Series::<i64, i64>::new(vec![3, 5, 7], vec![10, 20, 30]);
+        assert_eq!(l.try_add(&r), Ok(exp));
+        let exp = Series::<i64, i64>::new(vec![-1, -1, -1], vec![10, 20, 30]);
+        assert_eq!(l.try_sub(&r), Ok(exp));
+
+        let r = Series::<i64, i64>::new(vec![2, 3, 4], vec![10, 20, 40]);
+        assert_eq!(l.try_mul(&r), Err(Error::IndexMismatch));
+    }
+
+    #[test]
+    fn test_series_neg_abs() {
+        let s = Series::<i64, i64>::new(vec![1, -2, 0], vec![10, 20, 30]);
+        let exp = Series::<i64, i64>::new(vec![-1, 2, 0], vec![10, 20, 30]);
+        assert_eq!(-&s, exp);
+        let exp = Series::<i64, i64>::new(vec![1, 2, 0], vec![10, 20, 30]);
+        assert_eq!(s.abs(), exp);
+
+        let s = Series::<f64, i64>::new(vec![1.5, -2.5], vec![10, 20]);
+        let exp = Series::<f64, i64>::new(vec![-1.5, 2.5], vec![10, 20]);
+        assert_eq!(-s.clone(), exp);
+        let exp = Series::<f64, i64>::new(vec![1.5, 2.5], vec![10, 20]);
+        assert_eq!(s.abs(), exp);
+    }
+
+    #[test]
+    fn test_series_assign_ops() {
+        let mut s = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]);
+        s += 3;
+        assert_eq!(s, Series::<i64, i64>::new(vec![4, 5, 6], vec![10, 20, 30]));
+        s *= &2;
+        assert_eq!(s, Series::<i64, i64>::new(vec![8, 10, 12], vec![10, 20, 30]));
+
+        let r = Series::<i64, i64>::new(vec![2, 5, 4], vec![10, 20, 30]);
+        s -= &r;
+        assert_eq!(s, Series::<i64, i64>::new(vec![6, 5, 8], vec![10, 20, 30]));
+        s /= r;
+        assert_eq!(s, Series::<i64, i64>::new(vec![3, 1, 2], vec![10, 20, 30]));
+
+        // borrowed values are copied once, original is unchanged
+        let values = vec![1., 2.];
+        let mut s = Series::from_cow(Cow::Borrowed(&values), Cow::Owned(Indexer::new(vec![1, 2])));
+        s /= 2.;
+        assert_eq!(s.values.to_vec(), vec![0.5, 1.]);
+        assert_eq!(values, vec![1., 2.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_series_assign_ops_index_mismatch() {
+        let mut s = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]);
+        s += Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 40]);
+    }
+
+    #[test]
+    fn test_series_comparison() {
+        let s = Series::<f64, i64>::new(vec![1., 2., 3.], vec![10, 20, 30]);
+        let exp = Series::<bool, i64>::new(vec![false, false, true], vec![10, 20, 30]);
+        assert_eq!(s.gt(&2.), exp);
+        let exp = Series::<bool, i64>::new(vec![false, true, true], vec![10, 20, 30]);
+        assert_eq!(s.ge(&2.), exp);
+        let exp = Series::<bool, i64>::new(vec![true, false, false], vec![10, 20, 30]);
+        assert_eq!(s.lt(&2.), exp);
+        let exp = Series::<bool, i64>::new(vec![true, true, false], vec![10, 20, 30]);
+        assert_eq!(s.le(&2.), exp);
+        let exp = Series::<bool, i64>::new(vec![false, true, false], vec![10, 20, 30]);
+        assert_eq!(s.eq_elem(&2.), exp);
+        let exp = Series::<bool, i64>::new(vec![true, false, true], vec![10, 20, 30]);
+        assert_eq!(s.ne_elem(&2.), exp);
+
+        let r = Series::<f64, i64>::new(vec![3., 2., 1.], vec![10, 20, 30]);
+        let exp = Series::<bool, i64>::new(vec![false, false, true], vec![10, 20, 30]);
+        assert_eq!(s.gt_series(&r), exp);
+        let exp = Series::<bool, i64>::new(vec![true, true, false], vec![10, 20, 30]);
+        assert_eq!(s.le_series(&r), exp);
+        let exp = Series::<bool, i64>::new(vec![false, true, false], vec![10, 20, 30]);
+        assert_eq!(s.eq_series(&r), exp);
+    }
+
+    #[test]
+    fn test_series_eq_nulls() {
+        use std::f64;
+        use traits::NullEquality;
+
+        let s = Series::<f64, i64>::new(vec![1., f64::NAN, f64::NAN], vec![10, 20, 30]);
+        let r = Series::<f64, i64>::new(vec![1., f64::NAN, 3.], vec![10, 20, 30]);
+        let exp = Series::<bool, i64>::new(vec![true, true, false], vec![10, 20, 30]);
+        assert_eq!(s.eq_series_with(&r, NullEquality::Equal), exp);
+        let exp = Series::<bool, i64>::new(vec![true, false, false], vec![10, 20, 30]);
+        assert_eq!(s.eq_series_with(&r, NullEquality::Distinct), exp);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_series_comparison_index_mismatch() {
+        let s = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]);
+        let r = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 40]);
+        s.lt_series(&r);
+    }
 
     #[test]
     fn test_series_ops_i64_broadcast() {
@@ -276,6 +605,39 @@ mod tests {
         assert_eq!(result, exp);
     }
 
+    #[test]
+    fn test_series_ops_bool_not() {
+        let s = Series::<bool, i64>::new(vec![true, false, true], vec![10, 20, 30]);
+        let exp = Series::<bool, i64>::new(vec![false, true, false], vec![10, 20, 30]);
+        assert_eq!(!&s, exp);
+        assert_eq!(!s, exp);
+
+        let s = Series::<i64, i64>::new(vec![0, -1], vec![10, 20]);
+        assert_eq!(!s, Series::<i64, i64>::new(vec![-1, 0], vec![10, 20]));
+    }
+
+    #[test]
+    fn test_series_any_all() {
+        let s = Series::<bool, i64>::new(vec![true, false, true], vec![10, 20, 30]);
+        assert!(s.any());
+        assert!(!s.all());
+
+        let cond = s.clone() | !s;
+        assert!(cond.all());
+
+        let s = Series::<bool, i64>::new(vec![], vec![]);
+        assert!(!s.any());
+        assert!(s.all());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_series_ops_bool_index_mismatch() {
+        let s = Series::<bool, i64>::new(vec![true, false], vec![10, 20]);
+        let r = Series::<bool, i64>::new(vec![true, false], vec![10, 30]);
+        let _ = s & r;
+    }
+
     #[test]
     fn test_series_ops_i64_elemwise() {
         let s = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]);
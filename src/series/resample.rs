@@ -0,0 +1,138 @@
+use std::f64;
+use std::time::Duration;
+
+use num::ToPrimitive;
+
+use super::Series;
+use convert::duration_nanos;
+use traits::Interpolation;
+
+/// Regular grid of a datetime-indexed `Series`, created by `Series::upsample`.
+pub struct Upsampler<'s, 'v: 's, 'i: 's, V: 'v + Clone> {
+    series: &'s Series<'v, 'i, V, i64>,
+    step: i64,
+    max_gap: Option<i64>,
+}
+
+impl<'v, 'i, V> Series<'v, 'i, V, i64>
+where
+    V: Clone + ToPrimitive,
+{
+    /// Regularize values onto timestamps which are multiples of `step`, index
+    /// must be nanoseconds since the UNIX epoch.
+    ///
+    /// Grid covers the range from the first to the last observation, and
+    /// values on it are computed by `Upsampler::interpolate`.
+    pub fn upsample<'s>(&'s self, step: Duration) -> Upsampler<'s, 'v, 'i, V> {
+        let step = duration_nanos(step);
+        assert!(step > 0, "step must be positive");
+        Upsampler {
+            series: self,
+            step,
+            max_gap: None,
+        }
+    }
+}
+
+impl<'s, 'v, 'i, V> Upsampler<'s, 'v, 'i, V>
+where
+    V: Clone + ToPrimitive,
+{
+    /// Grid points between observations more than `gap` apart are null.
+    pub fn max_gap(mut self, gap: Duration) -> Self {
+        self.max_gap = Some(duration_nanos(gap));
+        self
+    }
+
+    /// Values on the grid interpolated by `method`, nulls are NaN.
+    ///
+    /// Null observations are ignored, and observations don't need to be
+    /// sorted. Grid points which have an observation take its value.
+    pub fn interpolate<'n>(&self, method: Interpolation) -> Series<'n, 'n, f64, i64> {
+        let mut points: Vec<(i64, f64)> = self.series
+            .index
+            .values
+            .iter()
+            .zip(self.series.values.iter())
+            .map(|(&t, v)| (t, v.to_f64().unwrap_or(f64::NAN)))
+            .filter(|x| !x.1.is_nan())
+            .collect();
+        points.sort_by_key(|x| x.0);
+        if points.is_empty() {
+            return Series::new(vec![], Vec::<i64>::new());
+        }
+
+        let first = points[0].0;
+        let last = points[points.len() - 1].0;
+        let start = first.div_euclid(self.step) * self.step;
+        let start = if start < first { start + self.step } else { start };
+
+        let mut new_index: Vec<i64> = vec![];
+        let mut new_values: Vec<f64> = vec![];
+        // location of the first observation after the grid point
+        let mut next = 0;
+        let mut t = start;
+        while t <= last {
+            while next < points.len() && points[next].0 <= t {
+                next += 1;
+            }
+            let (pt, pv) = points[next - 1];
+            let value = if pt == t {
+                pv
+            } else {
+                let (nt, nv) = points[next];
+                if self.max_gap.is_some_and(|gap| nt - pt > gap) {
+                    f64::NAN
+                } else {
+                    match method {
+                        Interpolation::Linear => {
+                            pv + (nv - pv) * (t - pt) as f64 / (nt - pt) as f64
+                        }
+                        Interpolation::Previous => pv,
+                        Interpolation::Nearest => if t - pt <= nt - t { pv } else { nv },
+                    }
+                }
+            };
+            new_index.push(t);
+            new_values.push(value);
+            t += self.step;
+        }
+        Series::new(new_values, new_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+    use std::time::Duration;
+
+    use super::super::Series;
+    use traits::Interpolation;
+
+    #[test]
+    fn test_upsample_interpolate() {
+        let sec = 1_000_000_000i64;
+        let s: Series<f64, i64> =
+            Series::new(vec![3., 0., f64::NAN, 9.], vec![4 * sec, 1 * sec, 5 * sec, 10 * sec]);
+        let up = s.upsample(Duration::from_secs(2));
+
+        let res = up.interpolate(Interpolation::Linear);
+        assert_eq!(res.index.values, vec![2 * sec, 4 * sec, 6 * sec, 8 * sec, 10 * sec]);
+        assert_eq!(res.values.to_vec(), vec![1., 3., 5., 7., 9.]);
+
+        let res = up.interpolate(Interpolation::Previous);
+        assert_eq!(res.values.to_vec(), vec![0., 3., 3., 3., 9.]);
+        let res = up.interpolate(Interpolation::Nearest);
+        assert_eq!(res.values.to_vec(), vec![0., 3., 3., 9., 9.]);
+
+        // gap between 4 and 10 seconds is too large
+        let res = s.upsample(Duration::from_secs(2))
+            .max_gap(Duration::from_secs(5))
+            .interpolate(Interpolation::Linear);
+        assert_eq!(res.values[..2], [1., 3.]);
+        assert!(res.values[2].is_nan());
+        assert!(res.values[3].is_nan());
+        assert_eq!(res.values[4], 9.);
+    }
+}
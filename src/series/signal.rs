@@ -0,0 +1,333 @@
+use std::f64;
+use std::hash::Hash;
+
+use num::ToPrimitive;
+
+use super::Series;
+use traits::Detrend;
+
+/// Coefficients of the least squares polynomial of `order` through points,
+/// in increasing order of degree. `None` if points are not enough.
+fn polyfit(xs: &[f64], ys: &[f64], order: usize) -> Option<Vec<f64>> {
+    let n = order + 1;
+    if xs.len() < n {
+        return None;
+    }
+    // normal equations as augmented matrix
+    let mut a: Vec<Vec<f64>> = vec![vec![0.; n + 1]; n];
+    for (&x, &y) in xs.iter().zip(ys) {
+        let powers: Vec<f64> = (0..n).map(|k| x.powi(k as i32)).collect();
+        for r in 0..n {
+            for c in 0..n {
+                a[r][c] += powers[r] * powers[c];
+            }
+            a[r][n] += powers[r] * y;
+        }
+    }
+    // Gaussian elimination with partial pivoting
+    for c in 0..n {
+        let pivot = (c..n)
+            .max_by(|&i, &j| a[i][c].abs().total_cmp(&a[j][c].abs()))
+            .unwrap();
+        if a[pivot][c] == 0. {
+            return None;
+        }
+        a.swap(c, pivot);
+        let pivot_row = a[c].clone();
+        for (r, row) in a.iter_mut().enumerate() {
+            if r != c {
+                let factor = row[c] / pivot_row[c];
+                for (x, p) in row.iter_mut().zip(&pivot_row).skip(c) {
+                    *x -= factor * p;
+                }
+            }
+        }
+    }
+    Some((0..n).map(|r| a[r][n] / a[r][r]).collect())
+}
+
+/// Locations of `n` points selected by Largest-Triangle-Three-Buckets.
+///
+/// The first and last points are kept, and the others are split into
+/// `n - 2` buckets. From each bucket the point forming the largest triangle
+/// with the previously selected point and the average of the next bucket is
+/// selected.
+fn lttb(xs: &[f64], ys: &[f64], n: usize) -> Vec<usize> {
+    let len = xs.len();
+    if n >= len {
+        return (0..len).collect();
+    }
+    let every = (len - 2) as f64 / (n - 2) as f64;
+    let bucket = |i: usize| (i as f64 * every) as usize + 1;
+
+    let mut selected: Vec<usize> = vec![0];
+    let mut a = 0;
+    for i in 0..n - 2 {
+        let (next_start, next_end) = (bucket(i + 1), usize::min(bucket(i + 2), len));
+        let count = (next_end - next_start) as f64;
+        let avg_x = xs[next_start..next_end].iter().sum::<f64>() / count;
+        let avg_y = ys[next_start..next_end].iter().sum::<f64>() / count;
+
+        let area = |j: usize| {
+            ((xs[a] - avg_x) * (ys[j] - ys[a]) - (xs[a] - xs[j]) * (avg_y - ys[a])).abs()
+        };
+        let mut max = bucket(i);
+        for j in bucket(i) + 1..bucket(i + 1) {
+            if area(j) > area(max) {
+                max = j;
+            }
+        }
+        selected.push(max);
+        a = max;
+    }
+    selected.push(len - 1);
+    selected
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    /// Apply `func` to the non-NaN values of the window around each location,
+    /// as (offsets from the location, values). Windows near the ends are
+    /// shifted to keep the size, or shrunk if `shift` is false.
+    fn map_window<'n, F>(&self, window: usize, shift: bool, func: F) -> Series<'n, 'n, f64, I>
+    where
+        I: 'n,
+        F: Fn(&[f64], &[f64]) -> f64,
+    {
+        assert!(window > 0, "window must be positive");
+        let values = self.to_f64_vec();
+        let len = values.len();
+        let half = window / 2;
+        let new_values: Vec<f64> = (0..len)
+            .map(|i| {
+                let (start, end) = if shift && len >= window {
+                    let start = i.saturating_sub(half).min(len - window);
+                    (start, start + window)
+                } else {
+                    (i.saturating_sub(half), (i + window - half).min(len))
+                };
+                let (xs, ys): (Vec<f64>, Vec<f64>) = (start..end)
+                    .filter(|&j| !values[j].is_nan())
+                    .map(|j| (j as f64 - i as f64, values[j]))
+                    .unzip();
+                func(&xs, &ys)
+            })
+            .collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Centered moving average of `window` values, windows are shrunk near
+    /// the ends. NaN values are ignored.
+    pub fn smooth_moving_average<'n>(&self, window: usize) -> Series<'n, 'n, f64, I>
+    where
+        I: 'n,
+    {
+        self.map_window(window, false, |_, ys| if ys.is_empty() {
+            f64::NAN
+        } else {
+            ys.iter().sum::<f64>() / ys.len() as f64
+        })
+    }
+
+    /// Savitzky-Golay filter, which fits a polynomial of `order` to `window`
+    /// values around each location.
+    ///
+    /// Windows near the ends are shifted inside, so the polynomial fitted to
+    /// the first or last window is used there. NaN values are ignored, and
+    /// the result is NaN if the window has too few values.
+    pub fn smooth_savgol<'n>(&self, window: usize, order: usize) -> Series<'n, 'n, f64, I>
+    where
+        I: 'n,
+    {
+        assert!(window % 2 == 1, "window must be odd");
+        assert!(order < window, "order must be less than window");
+        self.map_window(window, true, |xs, ys| polyfit(xs, ys, order).map_or(f64::NAN, |c| c[0]))
+    }
+
+    /// Values with the trend removed, NaN values are kept.
+    pub fn detrend<'n>(&self, method: Detrend) -> Series<'n, 'n, f64, I>
+    where
+        I: 'n,
+    {
+        let values = self.to_f64_vec();
+        let trend: Vec<f64> = match method {
+            Detrend::Linear => {
+                let (xs, ys): (Vec<f64>, Vec<f64>) = values
+                    .iter()
+                    .enumerate()
+                    .filter(|x| !x.1.is_nan())
+                    .map(|(i, &v)| (i as f64, v))
+                    .unzip();
+                let coef = polyfit(&xs, &ys, 1).unwrap_or_else(|| vec![f64::NAN, f64::NAN]);
+                (0..values.len()).map(|i| coef[0] + coef[1] * i as f64).collect()
+            }
+            Detrend::Rolling(window) => self.smooth_moving_average(window).values.into_owned(),
+        };
+        let new_values: Vec<f64> = values.iter().zip(&trend).map(|(v, t)| v - t).collect();
+        Series::new(new_values, self.index.clone().into_owned())
+    }
+
+    /// Labels of local maxima whose value is at least `min_height`.
+    ///
+    /// The first location of a plateau is regarded as the peak. Peaks closer
+    /// than `min_distance` locations to a higher peak are removed, the earlier
+    /// one is kept on ties.
+    pub fn find_peaks(&self, min_height: f64, min_distance: usize) -> Vec<I> {
+        let values = self.to_f64_vec();
+        let len = values.len();
+        let mut peaks: Vec<usize> = vec![];
+        for i in 1..len.saturating_sub(1) {
+            if values[i].is_nan() || values[i] < min_height || values[i] <= values[i - 1] {
+                continue;
+            }
+            // skip plateau to find the next different value
+            let next = (i + 1..len).find(|&j| values[j] != values[i]);
+            if next.is_some_and(|j| values[j] < values[i]) {
+                peaks.push(i);
+            }
+        }
+
+        let mut by_height = peaks.clone();
+        by_height.sort_by(|&a, &b| values[b].total_cmp(&values[a]).then(a.cmp(&b)));
+        let mut kept: Vec<usize> = vec![];
+        for p in by_height {
+            if kept.iter().all(|&k| (k as isize - p as isize).unsigned_abs() >= min_distance) {
+                kept.push(p);
+            }
+        }
+        kept.sort();
+        kept.iter().map(|&i| self.index.values[i].clone()).collect()
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash + ToPrimitive,
+{
+    /// Select `n_points` values which keep the visual shape of the series
+    /// by Largest-Triangle-Three-Buckets, to plot long series.
+    ///
+    /// Index labels are used as x coordinates and must be increasing. The
+    /// first and last values are always selected, NaN values are never
+    /// selected. Values are kept as they are if not more than `n_points`.
+    pub fn downsample_lttb<'n>(&self, n_points: usize) -> Series<'n, 'n, V, I>
+    where
+        V: 'n,
+        I: 'n,
+    {
+        assert!(n_points >= 3, "n_points must be at least 3");
+        let xs: Vec<f64> = self.index
+            .values
+            .iter()
+            .map(|x| x.to_f64().unwrap_or(f64::NAN))
+            .collect();
+        assert!(xs.windows(2).all(|x| x[0] < x[1]), "index must increase monotonically");
+        let ys = self.to_f64_vec();
+        let valid: Vec<usize> = (0..ys.len()).filter(|&i| !ys[i].is_nan()).collect();
+        let valid_xs: Vec<f64> = valid.iter().map(|&i| xs[i]).collect();
+        let valid_ys: Vec<f64> = valid.iter().map(|&i| ys[i]).collect();
+
+        let locs: Vec<usize> = lttb(&valid_xs, &valid_ys, n_points)
+            .into_iter()
+            .map(|i| valid[i])
+            .collect();
+        let values: Vec<V> = locs.iter().map(|&i| self.values[i].clone()).collect();
+        let labels: Vec<I> = locs.iter().map(|&i| self.index.values[i].clone()).collect();
+        Series::new(values, labels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64;
+
+    use super::super::Series;
+    use super::{polyfit, lttb};
+    use traits::Detrend;
+
+    fn assert_close(res: &[f64], exp: &[f64]) {
+        assert_eq!(res.len(), exp.len());
+        for (r, e) in res.iter().zip(exp) {
+            assert!((r - e).abs() < 1e-9, "{:?} != {:?}", res, exp);
+        }
+    }
+
+    #[test]
+    fn test_polyfit() {
+        let xs = vec![0., 1., 2., 3.];
+        let ys: Vec<f64> = xs.iter().map(|x| 1. + 2. * x - x * x).collect();
+        assert_close(&polyfit(&xs, &ys, 2).unwrap(), &[1., 2., -1.]);
+        assert_close(&polyfit(&xs, &[1., 1., 3., 3.], 1).unwrap(), &[0.8, 0.8]);
+        assert!(polyfit(&xs[..2], &ys[..2], 2).is_none());
+    }
+
+    #[test]
+    fn test_smooth() {
+        let s = Series::<f64, usize>::from_vec(vec![1., 3., 2., f64::NAN, 6.]);
+        let res = s.smooth_moving_average(3);
+        assert_close(&res.values, &[2., 2., 2.5, 4., 6.]);
+
+        // quadratic values are kept by quadratic filter
+        let values: Vec<f64> = (0..7).map(|x| (x * x) as f64).collect();
+        let s = Series::<f64, usize>::from_vec(values.clone());
+        assert_close(&s.smooth_savgol(5, 2).values, &values);
+
+        let s = Series::<f64, usize>::from_vec(vec![0., 3., 0., 3., 0.]);
+        let res = s.smooth_savgol(3, 1);
+        assert_close(&res.values, &[1., 1., 2., 1., 1.]);
+    }
+
+    #[test]
+    fn test_detrend() {
+        let s = Series::<f64, usize>::from_vec(vec![1., 4., 5., 8., 9.]);
+        let res = s.detrend(Detrend::Linear);
+        assert_close(&res.values, &[-0.4, 0.6, -0.4, 0.6, -0.4]);
+
+        let s = Series::<i64, usize>::from_vec(vec![1, 2, 6]);
+        let res = s.detrend(Detrend::Rolling(3));
+        assert_close(&res.values, &[-0.5, -1., 2.]);
+    }
+
+    #[test]
+    fn test_find_peaks() {
+        let s: Series<f64, &str> = Series::new(
+            vec![0., 2., 1., 5., 5., 1., 3., 0., 4., 4.],
+            vec!["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"],
+        );
+        assert_eq!(s.find_peaks(f64::NEG_INFINITY, 1), vec!["b", "d", "g"]);
+        assert_eq!(s.find_peaks(2.5, 1), vec!["d", "g"]);
+        assert_eq!(s.find_peaks(f64::NEG_INFINITY, 3), vec!["d", "g"]);
+        assert_eq!(s.find_peaks(f64::NEG_INFINITY, 4), vec!["d"]);
+    }
+
+    #[test]
+    fn test_lttb() {
+        let xs: Vec<f64> = (0..7).map(|x| x as f64).collect();
+        assert_eq!(lttb(&xs, &[0., 1., 0., 5., 0., 1., 0.], 3), vec![0, 3, 6]);
+        let ys = [0., 2., 0., 0., 0., 0., -3., 0., 0., 0.];
+        let xs: Vec<f64> = (0..10).map(|x| x as f64).collect();
+        assert_eq!(lttb(&xs, &ys, 4), vec![0, 1, 6, 9]);
+        assert_eq!(lttb(&xs[..3], &ys[..3], 5), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_downsample_lttb() {
+        let s: Series<i64, i64> = Series::new(
+            vec![0, 1, 0, 5, 0, 1, 0, 2],
+            vec![10, 20, 30, 40, 50, 60, 70, 80],
+        );
+        let res = s.downsample_lttb(4);
+        assert_eq!(res, Series::new(vec![0, 5, 0, 2], vec![10, 40, 50, 80]));
+        assert_eq!(s.downsample_lttb(8), s);
+
+        // NaN values are skipped
+        let s = Series::<f64, usize>::from_vec(vec![f64::NAN, 1., 4., 0., f64::NAN]);
+        let res = s.downsample_lttb(3);
+        assert_eq!(res, Series::new(vec![1., 4., 0.], vec![1, 2, 3]));
+    }
+}
@@ -3,8 +3,9 @@ use std::hash::Hash;
 use super::Series;
 use nullvec::prelude::dev::algos::Indexing;
 use nullvec::prelude::dev::algos::Sorter;
+use algos::parallel::sort_by_kind;
 use indexer::Indexer;
-use traits::Slicer;
+use traits::{Slicer, SortKind};
 
 //**********************************************
 //*Soat
@@ -15,6 +16,7 @@ where
     V: Clone,
     I: Clone + Eq + Hash + Ord,
 {
+    /// Sort by index labels.
     pub fn sort_index(&self) -> Self {
         let (indexer, sorted) = self.index.argsort();
         let new_values = Indexing::reindex(&self.values, &indexer);
@@ -27,10 +29,68 @@ where
     V: Clone + Ord,
     I: Clone + Eq + Hash,
 {
-    pub fn sort_values(&self) -> Self {
-        let (indexer, sorted) = Sorter::argsort(&self.values);
+    /// Sort by values, index is permuted accordingly. The order of equal
+    /// values is kept.
+    pub fn sort_values(&self, ascending: bool) -> Self {
+        let indexer = if ascending {
+            self.argsort()
+        } else {
+            let mut indexer: Vec<usize> = (0..self.values.len()).collect();
+            indexer.sort_by(|&a, &b| self.values[b].cmp(&self.values[a]));
+            indexer
+        };
+        let new_values = Indexing::reindex(&self.values, &indexer);
         let index: Indexer<I> = self.index.reindex(&indexer);
-        Series::new(sorted, index)
+        Series::new(new_values, index)
+    }
+
+    /// Locations which sort values in ascending order.
+    pub fn argsort(&self) -> Vec<usize> {
+        let (indexer, _) = Sorter::argsort(&self.values);
+        indexer
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash + Ord + Sync,
+{
+    /// Sort by index labels using the algorithm of `kind`.
+    pub fn sort_index_kind(&self, kind: SortKind) -> Self {
+        let labels = &self.index.values;
+        let indexer = sort_by_kind((0..labels.len()).collect(), kind, |&a, &b| {
+            labels[a].cmp(&labels[b])
+        });
+        let new_values = Indexing::reindex(&self.values, &indexer);
+        let index: Indexer<I> = self.index.reindex(&indexer);
+        Series::new(new_values, index)
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + Ord + Sync,
+    I: Clone + Eq + Hash,
+{
+    /// Locations which sort values in the order of `ascending` using the
+    /// algorithm of `kind`.
+    pub fn argsort_kind(&self, ascending: bool, kind: SortKind) -> Vec<usize> {
+        let values = &self.values;
+        sort_by_kind((0..values.len()).collect(), kind, |&a, &b| if ascending {
+            values[a].cmp(&values[b])
+        } else {
+            values[b].cmp(&values[a])
+        })
+    }
+
+    /// Sort by values using the algorithm of `kind`, index is permuted
+    /// accordingly.
+    pub fn sort_values_kind(&self, ascending: bool, kind: SortKind) -> Self {
+        let indexer = self.argsort_kind(ascending, kind);
+        let new_values = Indexing::reindex(&self.values, &indexer);
+        let index: Indexer<I> = self.index.reindex(&indexer);
+        Series::new(new_values, index)
     }
 }
 
@@ -38,6 +98,7 @@ where
 mod tests {
 
     use super::super::Series;
+    use traits::SortKind;
 
     #[test]
     fn test_sort_index_int() {
@@ -55,4 +116,40 @@ mod tests {
         let exp = Series::new(vec![3, 2, 4, 1], vec!["a", "b", "c", "d"]);
         assert_eq!(sorted, exp);
     }
+
+    #[test]
+    fn test_sort_values() {
+        let s = Series::new(vec![3, 1, 2, 1], vec!["a", "b", "c", "d"]);
+        assert_eq!(s.argsort(), vec![1, 3, 2, 0]);
+
+        let exp = Series::new(vec![1, 1, 2, 3], vec!["b", "d", "c", "a"]);
+        assert_eq!(s.sort_values(true), exp);
+        let exp = Series::new(vec![3, 2, 1, 1], vec!["a", "c", "b", "d"]);
+        assert_eq!(s.sort_values(false), exp);
+    }
+
+    #[test]
+    fn test_sort_kind() {
+        let s = Series::new(vec![3, 1, 2, 1], vec!["d", "b", "a", "c"]);
+        for kind in [SortKind::Stable, SortKind::Unstable] {
+            assert_eq!(s.sort_index_kind(kind), s.sort_index());
+        }
+        assert_eq!(s.sort_values_kind(false, SortKind::Stable), s.sort_values(false));
+        assert_eq!(s.argsort_kind(true, SortKind::Stable), s.argsort());
+        let res = s.argsort_kind(true, SortKind::Unstable);
+        assert_eq!(&res[2..], &[2, 0]);
+        let res = s.sort_values_kind(false, SortKind::Unstable);
+        assert_eq!(res.values.to_vec(), vec![3, 2, 1, 1]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_sort_kind_parallel() {
+        let s = Series::new(vec![3, 1, 2, 1], vec!["d", "b", "a", "c"]);
+        assert_eq!(s.sort_index_kind(SortKind::Parallel), s.sort_index());
+        let res = s.argsort_kind(true, SortKind::Parallel);
+        assert_eq!(&res[2..], &[2, 0]);
+        let res = s.sort_values_kind(false, SortKind::Parallel);
+        assert_eq!(res.values.to_vec(), vec![3, 2, 1, 1]);
+    }
 }
@@ -0,0 +1,106 @@
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+use num::ToPrimitive;
+
+use super::Series;
+use algos::fft::fft;
+use frame::DataFrame;
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    /// Values as `f64` for spectral analysis, which must not be null.
+    fn spectral_values(&self) -> Vec<f64> {
+        let values = self.to_f64_vec();
+        assert!(values.iter().all(|x| !x.is_nan()), "values must not be null");
+        values
+    }
+
+    /// Discrete Fourier transform of values sampled every `spacing`.
+    ///
+    /// Result has "frequency", "real" and "imag" columns for each bin. As
+    /// `fftfreq` of numpy, bins after the middle have negative frequencies.
+    pub fn fft<'n>(&self, spacing: f64) -> DataFrame<'n, 'n, 'n, usize, &'static str> {
+        let values = self.spectral_values();
+        let n = values.len();
+        let (real, imag): (Vec<f64>, Vec<f64>) = fft(&values).into_iter().unzip();
+        let frequency: Vec<f64> = (0..n)
+            .map(|k| {
+                let k = if k < n.div_ceil(2) { k as f64 } else { k as f64 - n as f64 };
+                k / (n as f64 * spacing)
+            })
+            .collect();
+        DataFrame::from_vec(
+            vec![Array::new(frequency), Array::new(real), Array::new(imag)],
+            (0..n).collect::<Vec<usize>>(),
+            vec!["frequency", "real", "imag"],
+        )
+    }
+
+    /// One-sided periodogram of values sampled every `spacing`.
+    ///
+    /// Result has "frequency" and "power" columns from zero to the Nyquist
+    /// frequency, power is the squared magnitude of the transform divided by
+    /// the number of values.
+    pub fn periodogram<'n>(&self, spacing: f64) -> DataFrame<'n, 'n, 'n, usize, &'static str> {
+        let values = self.spectral_values();
+        let n = values.len();
+        let bins = if n == 0 { 0 } else { n / 2 + 1 };
+        let (frequency, power): (Vec<f64>, Vec<f64>) = fft(&values)
+            .into_iter()
+            .take(bins)
+            .enumerate()
+            .map(|(k, (re, im))| (k as f64 / (n as f64 * spacing), (re * re + im * im) / n as f64))
+            .unzip();
+        DataFrame::from_vec(
+            vec![Array::new(frequency), Array::new(power)],
+            (0..bins).collect::<Vec<usize>>(),
+            vec!["frequency", "power"],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64::consts::PI;
+
+    use nullvec::prelude::Array;
+
+    use super::super::Series;
+    use traits::{ColIndex, Slicer};
+
+    #[test]
+    fn test_series_fft() {
+        let s = Series::<f64, usize>::from_vec(vec![1., 2., 3., 4.]);
+        let res = s.fft(0.5);
+        assert_eq!(res.get(&"frequency"), Array::new(vec![0., 0.5, -1., -0.5]));
+        let real: Vec<f64> = match res.get(&"real") {
+            Array::Float64Array(x) => x.not_null_values(),
+            _ => unreachable!(),
+        };
+        for (r, e) in real.iter().zip(&[10., -2., -2., -2.]) {
+            assert!((r - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_series_periodogram() {
+        // period of 4 samples
+        let values: Vec<f64> = (0..16).map(|x| (x as f64 * PI / 2.).sin()).collect();
+        let s = Series::<f64, usize>::from_vec(values);
+        let res = s.periodogram(1.);
+        assert_eq!(res.index.len(), 9);
+        let power: Vec<f64> = match res.get(&"power") {
+            Array::Float64Array(x) => x.not_null_values(),
+            _ => unreachable!(),
+        };
+        let peak = (0..power.len()).max_by(|&a, &b| power[a].total_cmp(&power[b])).unwrap();
+        assert_eq!(peak, 4);
+        assert_eq!(res.get(&"frequency").iloc(&peak), 0.25.into());
+        assert!((power[peak] - 4.).abs() < 1e-9);
+    }
+}
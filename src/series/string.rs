@@ -0,0 +1,239 @@
+use std::hash::Hash;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+
+use super::Series;
+use traits::RowIndex;
+
+/// String accessor of `Series`, created by `Series::str`.
+pub struct StringMethods<'s, 'v: 's, 'i: 's, V, I>
+where
+    V: 'v + Clone,
+    I: 'i + Clone + Hash,
+{
+    series: &'s Series<'v, 'i, V, I>,
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + AsRef<str>,
+    I: Clone + Eq + Hash,
+{
+    /// Access vectorized string methods of values.
+    pub fn str<'s>(&'s self) -> StringMethods<'s, 'v, 'i, V, I> {
+        StringMethods { series: self }
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash + AsRef<str>,
+{
+    /// Values whose label starts with `prefix`.
+    pub fn loc_startswith(&'i self, prefix: &str) -> Self {
+        let flags: Vec<bool> = self.index
+            .values
+            .iter()
+            .map(|x| x.as_ref().starts_with(prefix))
+            .collect();
+        self.blocs(&flags)
+    }
+
+    /// Values whose label contains `pat`.
+    pub fn loc_contains(&'i self, pat: &str) -> Self {
+        let flags: Vec<bool> = self.index
+            .values
+            .iter()
+            .map(|x| x.as_ref().contains(pat))
+            .collect();
+        self.blocs(&flags)
+    }
+}
+
+/// Compile `pattern`, panic if it's invalid.
+#[cfg(feature = "regex")]
+fn compile(pattern: &str) -> Regex {
+    Regex::new(pattern).unwrap_or_else(|e| panic!("invalid pattern {:?}: {}", pattern, e))
+}
+
+impl<'s, 'v, 'i, V, I> StringMethods<'s, 'v, 'i, V, I>
+where
+    V: Clone + AsRef<str>,
+    I: Clone + Eq + Hash,
+{
+    fn map_str<'n, W, F>(&self, func: F) -> Series<'n, 'n, W, I>
+    where
+        W: Clone,
+        I: 'n,
+        F: Fn(&str) -> W,
+    {
+        let values: Vec<W> = self.series.values.iter().map(|x| func(x.as_ref())).collect();
+        Series::new(values, self.series.index.clone().into_owned())
+    }
+
+    /// Number of characters of each value.
+    pub fn len<'n>(&self) -> Series<'n, 'n, usize, I> {
+        self.map_str(|x| x.chars().count())
+    }
+
+    /// Lowercase of each value.
+    pub fn to_lowercase<'n>(&self) -> Series<'n, 'n, String, I> {
+        self.map_str(str::to_lowercase)
+    }
+
+    /// Uppercase of each value.
+    pub fn to_uppercase<'n>(&self) -> Series<'n, 'n, String, I> {
+        self.map_str(str::to_uppercase)
+    }
+
+    /// Each value without leading and trailing whitespaces.
+    pub fn strip<'n>(&self) -> Series<'n, 'n, String, I> {
+        self.map_str(|x| x.trim().to_string())
+    }
+
+    /// Whether each value contains `pat` literally.
+    pub fn contains<'n>(&self, pat: &str) -> Series<'n, 'n, bool, I> {
+        self.map_str(|x| x.contains(pat))
+    }
+
+    /// Whether each value starts with `pat`.
+    pub fn starts_with<'n>(&self, pat: &str) -> Series<'n, 'n, bool, I> {
+        self.map_str(|x| x.starts_with(pat))
+    }
+
+    /// Whether each value ends with `pat`.
+    pub fn ends_with<'n>(&self, pat: &str) -> Series<'n, 'n, bool, I> {
+        self.map_str(|x| x.ends_with(pat))
+    }
+
+    /// Replace all occurrences of `from` in each value with `to`.
+    pub fn replace<'n>(&self, from: &str, to: &str) -> Series<'n, 'n, String, I> {
+        self.map_str(|x| x.replace(from, to))
+    }
+
+    /// Split each value by `sep`, result can be accessed by `Series::list`.
+    pub fn split<'n>(&self, sep: &str) -> Series<'n, 'n, Vec<String>, I> {
+        self.map_str(|x| x.split(sep).map(|s| s.to_string()).collect())
+    }
+
+    /// Characters of each value from `start` until `stop` (exclusive), which
+    /// are clamped to the length.
+    pub fn slice<'n>(&self, start: usize, stop: usize) -> Series<'n, 'n, String, I> {
+        self.map_str(|x| x.chars().skip(start).take(stop.saturating_sub(start)).collect())
+    }
+
+    /// Whether each value matches regular expression `pattern` at its
+    /// beginning.
+    ///
+    /// See the `regex` crate for the supported syntax, panics if `pattern` is
+    /// invalid.
+    #[cfg(feature = "regex")]
+    pub fn matches<'n>(&self, pattern: &str) -> Series<'n, 'n, bool, I> {
+        // validate as written, wrapping may balance parentheses like "a)|(b"
+        compile(pattern);
+        let pattern = compile(&format!("^(?:{})", pattern));
+        self.map_str(|x| pattern.is_match(x))
+    }
+
+    /// Whether each value contains a match of regular expression `pattern`.
+    #[cfg(feature = "regex")]
+    pub fn contains_pattern<'n>(&self, pattern: &str) -> Series<'n, 'n, bool, I> {
+        let pattern = compile(pattern);
+        self.map_str(|x| pattern.is_match(x))
+    }
+
+    /// The first capturing group of the leftmost match of regular expression
+    /// `pattern` in each value, or the whole match if `pattern` has no group.
+    /// `None` if not matched.
+    #[cfg(feature = "regex")]
+    pub fn extract<'n>(&self, pattern: &str) -> Series<'n, 'n, Option<String>, I> {
+        let pattern = compile(pattern);
+        let group = if pattern.captures_len() > 1 { 1 } else { 0 };
+        self.map_str(|x| {
+            pattern
+                .captures(x)
+                .and_then(|c| c.get(group))
+                .map(|m| m.as_str().to_string())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Series;
+
+    #[test]
+    fn test_series_str() {
+        let values: Vec<String> = vec!["Apple Pie", "banana", " Cherry "]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect();
+        let s = Series::<String, i64>::new(values, vec![1, 2, 3]);
+
+        assert_eq!(s.str().len(), Series::new(vec![9, 6, 8], vec![1, 2, 3]));
+        assert_eq!(s.str().to_lowercase().values[0], "apple pie");
+        assert_eq!(s.str().to_uppercase().values[1], "BANANA");
+        assert_eq!(s.str().strip().values[2], "Cherry");
+        assert_eq!(s.str().contains("an").values.to_vec(), vec![false, true, false]);
+        assert_eq!(s.str().starts_with("b").values.to_vec(), vec![false, true, false]);
+        assert_eq!(s.str().ends_with(" ").values.to_vec(), vec![false, false, true]);
+        assert_eq!(s.str().replace("a", "o").values[1], "bonono");
+        assert_eq!(s.str().slice(1, 4).values.to_vec(), vec!["ppl", "ana", "Che"]);
+        assert_eq!(s.str().slice(7, 20).values.to_vec(), vec!["ie", "", " "]);
+
+        let res = s.str().split(" ");
+        assert_eq!(res.values[0], vec!["Apple".to_string(), "Pie".to_string()]);
+        assert_eq!(res.list().len().values.to_vec(), vec![2, 1, 3]);
+        assert_eq!(res.index, s.index);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_series_str_pattern() {
+        let s = Series::<&str, usize>::from_vec(vec!["id-12", "x id-3", "none"]);
+        assert_eq!(s.str().matches("id-\\d+").values.to_vec(), vec![true, false, false]);
+        assert_eq!(s.str().contains_pattern("id-\\d+").values.to_vec(), vec![true, true, false]);
+
+        let res = s.str().extract("id-(\\d+)");
+        assert_eq!(res.values.to_vec(), vec![Some("12".to_string()), Some("3".to_string()), None]);
+        let res = s.str().extract("[a-z]+");
+        let exp = vec![Some("id".to_string()), Some("x".to_string()), Some("none".to_string())];
+        assert_eq!(res.values.to_vec(), exp);
+    }
+
+    #[test]
+    fn test_series_loc_startswith() {
+        let s: Series<i64, &str> =
+            Series::new(vec![1, 2, 3, 4], vec!["east/a", "west/b", "east/c", "north/east"]);
+        assert_eq!(s.loc_startswith("east/"), Series::new(vec![1, 3], vec!["east/a", "east/c"]));
+        let res = s.loc_contains("east");
+        assert_eq!(res.index.values, vec!["east/a", "east/c", "north/east"]);
+        assert_eq!(s.loc_startswith("south").values.len(), 0);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_series_str_pattern_long() {
+        let value = format!("{}x", "1".repeat(20_000));
+        let s = Series::<String, usize>::from_vec(vec![value, "1".repeat(20_000)]);
+        assert_eq!(s.str().contains_pattern("\\d+x").values.to_vec(), vec![true, false]);
+        assert_eq!(s.str().matches("\\d+x").values.to_vec(), vec![true, false]);
+        let res = s.str().extract("(\\d{3})x");
+        assert_eq!(res.values.to_vec(), vec![Some("111".to_string()), None]);
+
+        // exponential for backtracking engines
+        let s = Series::<String, usize>::from_vec(vec!["a".repeat(34)]);
+        assert_eq!(s.str().contains_pattern("(a|aa)+b").values.to_vec(), vec![false]);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    #[should_panic]
+    fn test_series_str_invalid_pattern() {
+        let s = Series::<&str, usize>::from_vec(vec!["a"]);
+        s.str().matches("(a");
+    }
+}
@@ -0,0 +1,63 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use super::Series;
+
+/// `Series` whose values and index are borrowed for lifetime `'a`, created by
+/// `Series::view`.
+///
+/// Any `Series` can be a view as its values and index are `Cow`, use
+/// `Series::to_owned` to get a `Series` which doesn't borrow.
+pub type SeriesView<'a, V, I> = Series<'a, 'a, V, I>;
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    /// Borrow values and index without copying.
+    pub fn view<'a>(&'a self) -> SeriesView<'a, V, I> {
+        Series::from_cow(Cow::Borrowed(&*self.values), Cow::Borrowed(&*self.index))
+    }
+
+    /// Whether values or index are borrowed.
+    pub fn is_view(&self) -> bool {
+        matches!(self.values, Cow::Borrowed(_)) || matches!(self.index, Cow::Borrowed(_))
+    }
+
+    /// Copy borrowed values and index, the result is independent from the
+    /// lifetimes of the original.
+    pub fn to_owned<'n>(&self) -> Series<'n, 'n, V, I>
+    where
+        V: 'n,
+        I: 'n,
+    {
+        self.clone().into_owned()
+    }
+
+    /// Same as `to_owned`, but owned values and index are moved.
+    pub fn into_owned<'n>(self) -> Series<'n, 'n, V, I>
+    where
+        V: 'n,
+        I: 'n,
+    {
+        Series::from_cow(
+            Cow::Owned(self.values.into_owned()),
+            Cow::Owned(self.index.into_owned()),
+        )
+    }
+
+    /// Every `n`-th value starting from `offset`, keeping their index
+    /// labels.
+    pub fn every_nth<'n>(&self, n: usize, offset: usize) -> Series<'n, 'n, V, I>
+    where
+        V: 'n,
+        I: 'n,
+    {
+        assert!(n > 0, "n must be positive");
+        let locs = (offset..self.values.len()).step_by(n);
+        let values: Vec<V> = locs.clone().map(|i| self.values[i].clone()).collect();
+        let labels: Vec<I> = locs.map(|i| self.index.values[i].clone()).collect();
+        Series::new(values, labels)
+    }
+}
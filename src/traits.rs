@@ -1,5 +1,9 @@
 //! Common Traits
 
+use std::fmt;
+
+use error::Error;
+
 /// Indexing methods for non-labeled Array / Indexer
 pub use nullvec::prelude::Slicer;
 
@@ -16,6 +20,202 @@ pub trait IndexerIndex: Slicer {
     fn init_state(&self);
 }
 
+/// Values which can represent missing value, such as NaN of floats
+pub trait NullValue {
+    fn is_null(&self) -> bool;
+}
+
+macro_rules! define_never_null {
+    ($($t:ty),*) => {
+        $(
+            impl NullValue for $t {
+                fn is_null(&self) -> bool {
+                    false
+                }
+            }
+        )*
+    }
+}
+
+define_never_null!(i64, i32, i16, i8, isize, u64, u32, u16, u8, usize, bool, String);
+
+impl NullValue for &str {
+    fn is_null(&self) -> bool {
+        false
+    }
+}
+
+impl NullValue for f64 {
+    fn is_null(&self) -> bool {
+        self.is_nan()
+    }
+}
+
+impl NullValue for f32 {
+    fn is_null(&self) -> bool {
+        self.is_nan()
+    }
+}
+
+impl<T> NullValue for Option<T> {
+    fn is_null(&self) -> bool {
+        self.is_none()
+    }
+}
+
+/// Algorithm to sum floats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Summation {
+    /// Add values in order
+    Naive,
+    /// Kahan-Babuska compensated summation, most accurate
+    Kahan,
+    /// Sum halves recursively, error grows logarithmically to the length
+    Pairwise,
+}
+
+/// Order to combine partial results of parallel aggregations
+#[cfg(feature = "rayon")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reduction {
+    /// Partial results of fixed-size blocks are combined in the order of
+    /// blocks, result is the same across runs and thread counts
+    Deterministic,
+    /// Partial results are combined as threads finish, result may differ in
+    /// the last bits between runs
+    Unordered,
+}
+
+/// Method to fill values between observations
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Linear in time between the previous and next observations
+    Linear,
+    /// Value of the previous observation
+    Previous,
+    /// Value of the closer observation, the previous one on ties
+    Nearest,
+}
+
+/// Trend removed by `Series::detrend`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Detrend {
+    /// Least squares line fitted to all values
+    Linear,
+    /// Centered rolling mean of the window size
+    Rolling(usize),
+}
+
+/// Dependence measure between columns used by `DataFrame::pairwise`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// Pearson correlation coefficient
+    Pearson,
+    /// Spearman rank correlation coefficient
+    Spearman,
+    /// Kendall rank correlation coefficient (tau-b)
+    Kendall,
+    /// Mutual information in nats, estimated by histogram
+    MutualInformation,
+}
+
+/// Target dtype of `astype`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DType {
+    Bool,
+    I64,
+    I32,
+    U64,
+    Usize,
+    F64,
+    F32,
+    Str,
+}
+
+impl DType {
+    /// Name of the dtype, as returned by `Array::dtype`.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            DType::Bool => "bool",
+            DType::I64 => "i64",
+            DType::I32 => "i32",
+            DType::U64 => "u64",
+            DType::Usize => "usize",
+            DType::F64 => "f64",
+            DType::F32 => "f32",
+            DType::Str => "str",
+        }
+    }
+}
+
+/// Checked conversion of values to other dtype
+pub trait AsType: Sized {
+    /// Convert values to `dtype`, or return `Error::Cast` for the first value
+    /// which can't be converted without loss. Such values become null instead
+    /// if `coerce`.
+    fn try_astype(&self, dtype: DType, coerce: bool) -> Result<Self, Error>;
+
+    /// Convert values to `dtype`, panic where `try_astype` returns an error.
+    fn astype(&self, dtype: DType, coerce: bool) -> Self {
+        self.try_astype(dtype, coerce).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// Handling of integer overflow in checked operations, use `widen` to
+/// promote values to `i128` before operations instead
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// Wrap around, as release builds of std
+    Wrap,
+    /// Clamp to the bounds of the type
+    Saturate,
+    /// Return `Error::Overflow`
+    Error,
+}
+
+/// Integers whose arithmetic follows `Overflow` policy
+pub trait CheckedInt: Copy {
+    /// Add by `overflow` policy, `None` if overflowed under `Overflow::Error`
+    fn add_by(self, other: Self, overflow: Overflow) -> Option<Self>;
+    /// Subtract by `overflow` policy, `None` if overflowed under `Overflow::Error`
+    fn sub_by(self, other: Self, overflow: Overflow) -> Option<Self>;
+    /// Multiply by `overflow` policy, `None` if overflowed under `Overflow::Error`
+    fn mul_by(self, other: Self, overflow: Overflow) -> Option<Self>;
+    /// Convert to `i128`, which can hold any result of 64-bit integer
+    /// addition, subtraction and multiplication
+    fn widen(self) -> i128;
+}
+
+macro_rules! define_checked_op {
+    ($m:ident, $wrapping:ident, $saturating:ident, $checked:ident) => {
+        fn $m(self, other: Self, overflow: Overflow) -> Option<Self> {
+            match overflow {
+                Overflow::Wrap => Some(self.$wrapping(other)),
+                Overflow::Saturate => Some(self.$saturating(other)),
+                Overflow::Error => self.$checked(other),
+            }
+        }
+    }
+}
+
+macro_rules! define_checked_int {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedInt for $t {
+                define_checked_op!(add_by, wrapping_add, saturating_add, checked_add);
+                define_checked_op!(sub_by, wrapping_sub, saturating_sub, checked_sub);
+                define_checked_op!(mul_by, wrapping_mul, saturating_mul, checked_mul);
+
+                fn widen(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    }
+}
+
+define_checked_int!(i64, i32, i16, i8, isize, u64, u32, u16, u8, usize);
+
 /// Indexing methods for Index(Row)
 pub trait RowIndex<'s>: Sized {
     // 's: lifetime of myself
@@ -23,7 +223,7 @@ pub trait RowIndex<'s>: Sized {
     type Key;
     type Row;
 
-    fn len(&'s self) -> usize;
+    fn len(&self) -> usize;
 
     fn head(&'s self, n: usize) -> Self {
         let indexer: Vec<usize> = (0..n).collect();
@@ -94,9 +294,128 @@ pub trait Concatenation<'s>: Sized {
     fn concat<'o>(&'s self, other: &'o Self) -> Self;
 }
 
+/// Type of join
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum How {
+    /// Keep labels in both
+    Inner,
+    /// Keep all labels in left
+    Left,
+    /// Keep all labels in right
+    Right,
+    /// Keep all labels in either
+    Outer,
+}
+
+/// Which rows to drop in `dropna`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropHow {
+    /// Drop rows having any null
+    Any,
+    /// Drop rows whose values are all null
+    All,
+}
+
+/// Algorithm of sort
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortKind {
+    /// Equal values keep their order
+    Stable,
+    /// Equal values may be reordered, faster on large inputs
+    Unstable,
+    /// Sorted in parallel by rayon, equal values may be reordered
+    #[cfg(feature = "rayon")]
+    Parallel,
+}
+
+/// Position of nulls in sorted rows, regardless of the sort order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NullOrder {
+    /// Nulls come before all values
+    First,
+    /// Nulls come after all values
+    Last,
+}
+
+/// Direction to search labels in asof join
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Last label less than or equal to the key
+    Backward,
+    /// First label greater than or equal to the key
+    Forward,
+    /// Closest label in either direction, backward is preferred on ties
+    Nearest,
+}
+
+/// Whether `Null` equals `Null` in comparisons, merges and deduplication
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NullEquality {
+    /// `Null` equals `Null`, as pandas
+    Equal,
+    /// `Null` equals nothing including itself, as SQL
+    Distinct,
+}
+
+/// Algorithm of join
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinMethod {
+    /// Sort-merge join if both index are sorted, otherwise hash join
+    Auto,
+    /// Hash join, keeps the order of labels
+    Hash,
+    /// Sort-merge join, labels are sorted
+    SortMerge,
+}
+
 /// Join by index
 pub trait Join: Sized {
     fn join_inner(&self, other: &Self) -> Self;
+
+    /// Keep all rows of self, values missing in other are filled with nulls
+    fn join_left(&self, other: &Self) -> Self;
+
+    /// Keep all rows of other, values missing in self are filled with nulls
+    fn join_right(&self, other: &Self) -> Self;
+
+    /// Keep rows of both, values missing in either are filled with nulls
+    fn join_outer(&self, other: &Self) -> Self;
+
+    fn join(&self, other: &Self, how: How) -> Self {
+        match how {
+            How::Inner => self.join_inner(other),
+            How::Left => self.join_left(other),
+            How::Right => self.join_right(other),
+            How::Outer => self.join_outer(other),
+        }
+    }
+}
+
+/// Aggregation function, used in `GroupBy::agg`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Agg {
+    Sum,
+    Count,
+    Mean,
+    Var,
+    Std,
+    Min,
+    Max,
+}
+
+impl fmt::Display for Agg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Agg::Sum => "sum",
+            Agg::Count => "count",
+            Agg::Mean => "mean",
+            Agg::Var => "var",
+            Agg::Std => "std",
+            Agg::Min => "min",
+            Agg::Max => "max",
+        };
+        write!(f, "{}", name)
+    }
 }
 
 pub trait Apply<'s, R> {
@@ -137,6 +456,15 @@ pub trait ComparisonAggregation<'s> {
     fn max(&'s self) -> Self::Kept;
 }
 
+pub trait SelectionAggregation<'s> {
+    // result of selected values, missing if out of bounds
+    type Selected;
+
+    fn first(&'s self) -> Self::Selected;
+    fn last(&'s self) -> Self::Selected;
+    fn nth(&'s self, n: usize) -> Self::Selected;
+}
+
 pub trait Description<'s>
     : BasicAggregation<'s> + NumericAggregation<'s> + ComparisonAggregation<'s> {
     type Described;
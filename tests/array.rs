@@ -103,13 +103,32 @@ fn test_clone() {
 
 #[test]
 fn test_astype() {
-    // let iarr = array![1i64, 2, 3];
-    // assert_eq!(iarr.astype::<f64>(), array![1.0f64, 2., 3.]);
-    // assert_eq!(iarr.astype::<i64>(), array![1i64, 2, 3]);
-
-    // let farr = array![1.1f64, 2.1, 3.1];
-    // assert_eq!(farr.astype::<f64>(), array![1.1f64, 2.1, 3.1]);
-    // assert_eq!(farr.astype::<i64>(), array![1i64, 2, 3]);
+    let arr = array![1i64, 0, 3];
+    assert_eq!(arr.astype(DType::F64, false), array![1., 0., 3.]);
+    assert_eq!(arr.astype(DType::Str, false), array!["1", "0", "3"]);
+    let err = arr.try_astype(DType::Bool, false).unwrap_err();
+    assert_eq!(err.to_string(), "3 at location 2 can't be cast to bool");
+    let exp: Array = NullVec::with_mask(vec![true, false, false], Some(vec![false, false, true]))
+        .into();
+    assert_eq!(arr.astype(DType::Bool, true), exp);
+
+    let arr = array![1.5, 2.];
+    assert!(arr.try_astype(DType::I64, false).is_err());
+    let exp: Array = NullVec::with_mask(vec![0i64, 2], Some(vec![true, false])).into();
+    assert_eq!(arr.astype(DType::I64, true), exp);
+
+    let arr = array!["true", "False", "1"];
+    let exp: Array = NullVec::with_mask(vec![true, false, false], Some(vec![false, false, true]))
+        .into();
+    assert_eq!(arr.astype(DType::Bool, true), exp);
+    assert_eq!(array!["1", "2.0", "-3"].astype(DType::I32, false), array![1i32, 2, -3]);
+    assert!(array![-1i64].try_astype(DType::U64, false).is_err());
+
+    // decimal strings are parsed as f32, while f64 values must be exact
+    let arr = array!["0.1", " 2.5"];
+    assert_eq!(arr.try_astype(DType::F32, false).unwrap(), array![0.1f32, 2.5]);
+    assert!(array![0.1].try_astype(DType::F32, false).is_err());
+    assert_eq!(array![0.5].astype(DType::F32, false), array![0.5f32]);
 }
 
 
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
 
 #[macro_use]
 extern crate brassfibre;
@@ -118,6 +119,18 @@ fn test_frame_slice_ilocs_panic() {
     df.ilocs(&vec![0, 5, 2]);
 }
 
+#[test]
+fn test_frame_slice_blocs() {
+    let values = vec![array![1i64, 2, 3, 4], array![6.0f64, 7., 8., 9.]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C", "D"], vec!["X", "Y"]);
+
+    let col = Series::new(vec![1i64, 7, 3, 9], vec!["A", "B", "C", "D"]);
+    let res = df.blocs(&col.gt(&2).values);
+    let exp_values = vec![array![2i64, 3, 4], array![7.0f64, 8., 9.]];
+    let exp = DataFrame::from_vec(exp_values, vec!["B", "C", "D"], vec!["X", "Y"]);
+    assert_eq!(res, exp);
+}
+
 #[test]
 fn test_frame_columns_slice() {
     let values = vec![
@@ -199,3 +212,310 @@ fn test_frame_properties() {
     let exp: Vec<bool> = vec![false, true, false, true];
     assert_eq!(df.is_numeric(), exp);
 }
+
+#[test]
+fn test_frame_try_from_vec() {
+    let values = vec![array![1, 2, 3], array![4., 5., 6.]];
+    let df = DataFrame::try_from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]).unwrap();
+    assert_eq!(df.len(), 3);
+
+    let values = vec![array![1, 2, 3], array![4., 5.]];
+    let res = DataFrame::try_from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+    assert_eq!(
+        res.err(),
+        Some(Error::LengthMismatch {
+            expected: 3,
+            actual: 2,
+        })
+    );
+
+    let values = vec![array![1, 2, 3]];
+    let res = DataFrame::try_from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_frame_try_slice() {
+    let values = vec![array![1, 2, 3], array![4., 5., 6.]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+
+    let exp = DataFrame::from_vec(
+        vec![array![3, 1], array![6., 4.]],
+        vec!["C", "A"],
+        vec!["X", "Y"],
+    );
+    assert_eq!(df.try_locs(&["C", "A"]).unwrap(), exp);
+    assert_eq!(df.try_ilocs(&[2, 0]).unwrap(), exp);
+    assert_eq!(
+        df.try_locs(&["C", "D"]).err(),
+        Some(Error::KeyNotFound("\"D\"".to_string()))
+    );
+    assert_eq!(
+        df.try_ilocs(&[3]).err(),
+        Some(Error::OutOfBounds {
+            location: 3,
+            len: 3,
+        })
+    );
+
+    let exp = DataFrame::from_vec(vec![array![4., 5., 6.]], vec!["A", "B", "C"], vec!["Y"]);
+    assert_eq!(df.try_gets(&["Y"]).unwrap(), exp);
+    assert!(df.try_gets(&["Z"]).is_err());
+}
+
+#[test]
+fn test_frame_try_insert() {
+    let values = vec![array![1, 2, 3]];
+    let mut df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X"]);
+    assert!(df.try_insert(array![1, 2], "Y").is_err());
+    assert_eq!(
+        df.try_insert(array![1, 2, 3], "X"),
+        Err(Error::DuplicateLabel("\"X\"".to_string()))
+    );
+    assert_eq!(df.try_insert(array![4, 5, 6], "Y"), Ok(()));
+    assert_eq!(df.columns.values, vec!["X", "Y"]);
+    assert_eq!(df.values.len(), 2);
+}
+
+#[test]
+fn test_frame_index_astype() {
+    let values = vec![array![1, 2, 3], array![1.1, 2.1, 3.1]];
+    let df = DataFrame::from_vec(values.clone(), vec!["10", "20", "30"], vec!["X", "Y"]);
+
+    let res = df.index_astype::<i64>();
+    let exp = DataFrame::from_vec(values, vec![10i64, 20, 30], vec!["X", "Y"]);
+    assert_eq!(res, exp);
+    assert_eq!(res.index.get_loc(&20), 1);
+
+    let res = df.try_index_astype::<bool>();
+    assert_eq!(res.err(), Some(Error::ParseLabel("\"10\"".to_string())));
+}
+
+#[test]
+fn test_frame_index_label() {
+    let values = vec![array![1, 2, 3]];
+    let df = DataFrame::from_vec(values, vec!["10", "x", "20"], vec!["X"]);
+
+    let res = df.index_astype::<IndexLabel>();
+    assert_eq!(
+        res.index.values,
+        vec![IndexLabel::Int(10), IndexLabel::from("x"), IndexLabel::Int(20)]
+    );
+    assert_eq!(res.index.get_loc(&IndexLabel::from("x")), 1);
+}
+
+#[test]
+fn test_frame_default_labels() {
+    let df = DataFrame::from_arrays(vec![array![1, 2, 3], array![1.1, 2.1, 3.1]]);
+    let exp = DataFrame::from_vec(
+        vec![array![1, 2, 3], array![1.1, 2.1, 3.1]],
+        vec![0usize, 1, 2],
+        vec![0usize, 1],
+    );
+    assert_eq!(df, exp);
+
+    let df = DataFrame::from_matrix(vec![vec![1., 2.], vec![3., 4.], vec![5., 6.]]);
+    assert_eq!(df.index.values, vec![0, 1, 2]);
+    assert_eq!(df.columns.values, vec![0, 1]);
+    assert_eq!(*df.values[0], array![1., 3., 5.]);
+    assert_eq!(*df.values[1], array![2., 4., 6.]);
+}
+
+#[test]
+fn test_frame_view() {
+    let values = vec![array![1, 2, 3], array![1.1, 2.1, 3.1]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+    assert!(!df.is_view());
+
+    let v: DataFrameView<&str, &str> = df.view();
+    assert!(v.is_view());
+    assert_eq!(v, df);
+
+    let o = v.to_owned();
+    assert!(!o.is_view());
+    assert_eq!(o, df);
+    assert!(!v.into_owned().is_view());
+}
+
+#[test]
+fn test_frame_split_at_chunks() {
+    let values = vec![array![1, 2, 3, 4, 5], array![1.1, 2.1, 3.1, 4.1, 5.1]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C", "D", "E"], vec!["X", "Y"]);
+
+    let (head, tail) = df.split_at(2);
+    let exp = DataFrame::from_vec(
+        vec![array![1, 2], array![1.1, 2.1]],
+        vec!["A", "B"],
+        vec!["X", "Y"],
+    );
+    assert_eq!(head, exp);
+    assert_eq!(tail.index.values, vec!["C", "D", "E"]);
+    assert_eq!(*tail.values[0], array![3, 4, 5]);
+
+    let chunks: Vec<DataFrameView<&str, &str>> = df.chunks(2).collect();
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[0], exp);
+    assert_eq!(chunks[2].index.values, vec!["E"]);
+    assert_eq!(*chunks[2].values[1], array![5.1]);
+    assert_eq!(chunks.iter().map(|x| x.len()).sum::<usize>(), 5);
+
+    let (head, tail) = df.split_at(5);
+    assert_eq!(head, df);
+    assert_eq!(tail.len(), 0);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_frame_par_apply() {
+    let values = vec![array![1, 2, 3, 4, 5], array![1.5, 2.5, 3.5, 4.5, 5.5]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C", "D", "E"], vec!["X", "Y"]);
+
+    let res = df.par_apply_rows(|row| match (row[0].as_f64(), row[1].as_f64()) {
+        (Nullable::Value(x), Nullable::Value(y)) => x + y,
+        _ => f64::NAN,
+    });
+    let exp: Series<f64, &str> =
+        Series::new(vec![2.5, 4.5, 6.5, 8.5, 10.5], vec!["A", "B", "C", "D", "E"]);
+    assert_eq!(res, exp);
+
+    // chunk-wise cumulative count
+    let res = df.par_apply_chunks(2, |chunk| (0..chunk.len()).collect());
+    let exp: Series<usize, &str> = Series::new(vec![0, 1, 0, 1, 0], vec!["A", "B", "C", "D", "E"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_apply() {
+    let values = vec![array![1, 2, 3], array![4., 5., 6.]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+
+    let res = df.apply_rows(|row| match (row[0].as_f64(), row[1].as_f64()) {
+        (Nullable::Value(x), Nullable::Value(y)) => y / x,
+        _ => f64::NAN,
+    });
+    let exp: Series<f64, &str> = Series::new(vec![4., 2.5, 2.], vec!["A", "B", "C"]);
+    assert_eq!(res, exp);
+
+    let res = df.apply_cols(|col| col.dtype());
+    let exp: Series<String, &str> =
+        Series::new(vec!["i32".to_string(), "f64".to_string()], vec!["X", "Y"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_applymap() {
+    let values = vec![array![1, 2, 3], array!["a", "b", "c"], array![0.5, 1.5, 2.5]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y", "Z"]);
+
+    let res = df.applymap(|x| x * 2.);
+    let values = vec![array![2., 4., 6.], array!["a", "b", "c"], array![1., 3., 5.]];
+    let exp = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+
+    let res = df.applymap_scalar(|x| match x {
+        Scalar::String(s) => Scalar::String(s.to_uppercase()),
+        other => Scalar::String(other.to_string()),
+    });
+    let values = vec![array!["1", "2", "3"], array!["A", "B", "C"], array!["0.5", "1.5", "2.5"]];
+    let exp = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_map_column() {
+    let values = vec![array![3, 1, 2, 3], array![0.5, 1.5, 2.5, 3.5]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C", "D"], vec!["code", "amount"]);
+
+    let mut mapping = HashMap::new();
+    mapping.insert(1i64, "apple".to_string());
+    mapping.insert(3i64, "melon".to_string());
+    let res = df.map_column(&"code", &mapping);
+    let values = vec![
+        Array::from(vec![
+            Scalar::String("melon".to_string()),
+            Scalar::String("apple".to_string()),
+            Scalar::Null,
+            Scalar::String("melon".to_string()),
+        ]),
+        array![0.5, 1.5, 2.5, 3.5],
+    ];
+    let exp = DataFrame::from_vec(values, vec!["A", "B", "C", "D"], vec!["code", "amount"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_get_column() {
+    let values = vec![array![1, 2, 3], array![4., 5., 6.], array![7, 8, 9]];
+    let columns = vec!["revenue_total".to_string(), "Cost".to_string(), "cost".to_string()];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], columns);
+
+    assert_eq!(df.get(&"Cost".to_string()), array![4., 5., 6.]);
+    assert_eq!(df.iget(&0), array![1, 2, 3]);
+
+    // exact match is preferred
+    assert_eq!(df.get_ci("cost"), array![7, 8, 9]);
+    assert_eq!(df.get_ci("REVENUE_TOTAL"), array![1, 2, 3]);
+
+    let err = df.try_get_ci("Revenue").unwrap_err();
+    assert_eq!(
+        err,
+        Error::ColumnNotFound {
+            label: "\"Revenue\"".to_string(),
+            suggestion: Some("\"revenue_total\"".to_string()),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "column not found: \"Revenue\", did you mean \"revenue_total\"?"
+    );
+
+    let err = df.try_get(&"cots".to_string()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "column not found: \"cots\", did you mean \"Cost\"?"
+    );
+    let err = df.try_gets(&["xyz".to_string()]).unwrap_err();
+    assert_eq!(err.to_string(), "column not found: \"xyz\"");
+}
+
+#[test]
+fn test_frame_astype() {
+    let df = DataFrame::from_vec(
+        vec![array![1i64, 2, 3], array!["1.5", "x", "3"], array![true, false, true]],
+        vec![10, 20, 30],
+        vec!["X", "Y", "Z"],
+    );
+    let res = df.astype(&[("X", DType::F64), ("Z", DType::I64)], false);
+    let exp = DataFrame::from_vec(
+        vec![array![1., 2., 3.], array!["1.5", "x", "3"], array![1i64, 0, 1]],
+        vec![10, 20, 30],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(res, exp);
+
+    assert!(df.try_astype(&[("Y", DType::F64)], false).is_err());
+    assert!(df.try_astype(&[("W", DType::F64)], true).is_err());
+    let res = df.astype(&[("Y", DType::F64)], true);
+    let exp: Array = NullVec::with_mask(vec![1.5, 0., 3.], Some(vec![false, true, false])).into();
+    assert_eq!(res.get(&"Y"), exp);
+}
+
+#[test]
+fn test_frame_every_nth() {
+    let values = vec![array![1, 2, 3, 4, 5], array![1.1, 2.1, 3.1, 4.1, 5.1]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C", "D", "E"], vec!["X", "Y"]);
+
+    let res = df.every_nth(2, 0);
+    let exp = DataFrame::from_vec(
+        vec![array![1, 3, 5], array![1.1, 3.1, 5.1]],
+        vec!["A", "C", "E"],
+        vec!["X", "Y"],
+    );
+    assert_eq!(res, exp);
+    assert!(res.is_view());
+
+    let res = df.every_nth(3, 1);
+    assert_eq!(res.index.values, vec!["B", "E"]);
+    assert_eq!(df.every_nth(1, 0), df);
+    assert_eq!(df.every_nth(2, 5).len(), 0);
+}
@@ -125,3 +125,77 @@ fn test_frame_describe() {
     );
     assert_eq!(df.describe(), exp);
 }
+
+#[test]
+fn test_frame_summary_by() {
+    let values: Vec<Array> = vec![
+        array![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "d".to_string(),
+            "e".to_string(),
+        ],
+        array![1, 3, 2, 5, 8],
+        array![1.5, 2.5, 3., 1., 0.5],
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40, 50], vec!["A", "B", "C"]);
+
+    let res = df.summary_by(&["y", "x", "y", "x", "y"]);
+    let exp_values: Vec<Array> = vec![
+        array![2usize, 3],
+        array![8, 11],
+        array![4., 11. / 3.],
+        array![3, 1],
+        array![5, 8],
+        array![2usize, 3],
+        array![3.5, 5.],
+        array![1.75, 5. / 3.],
+        array![1., 0.5],
+        array![2.5, 3.],
+    ];
+    let exp_columns: Vec<String> = vec![
+        "B_count", "B_sum", "B_mean", "B_min", "B_max",
+        "C_count", "C_sum", "C_mean", "C_min", "C_max",
+    ].into_iter()
+        .map(|x| x.to_string())
+        .collect();
+    let exp = DataFrame::from_vec(exp_values, vec!["x", "y"], exp_columns);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_aggregate_with_skipna() {
+    let values: Vec<Array> = vec![
+        NullVec::with_mask(vec![1i64, 0, 3], Some(vec![false, true, false])).into(),
+        array![1.5, 2.5, f64::NAN],
+        array!["a", "b", "c"],
+        NullVec::with_mask(vec![0i64, 0, 0], Some(vec![true, true, true])).into(),
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30], vec!["A", "B", "C", "D"]);
+
+    let res = df.aggregate_with(Agg::Sum, true, 0);
+    let exp = Series::new(
+        vec![Scalar::i64(4), Scalar::f64(4.), Scalar::i64(0)],
+        vec!["A", "B", "D"],
+    );
+    assert_eq!(res, exp);
+
+    let res = df.aggregate_with(Agg::Sum, false, 0);
+    let exp = Series::new(vec![Scalar::Null, Scalar::Null, Scalar::Null], vec!["A", "B", "D"]);
+    assert_eq!(res, exp);
+
+    let res = df.aggregate_with(Agg::Mean, true, 1);
+    let exp = Series::new(
+        vec![Scalar::f64(2.), Scalar::f64(2.), Scalar::Null],
+        vec!["A", "B", "D"],
+    );
+    assert_eq!(res, exp);
+
+    let res = df.aggregate_with(Agg::Count, false, 5);
+    let exp = Series::new(
+        vec![Scalar::usize(2), Scalar::usize(2), Scalar::usize(0)],
+        vec!["A", "B", "D"],
+    );
+    assert_eq!(res, exp);
+}
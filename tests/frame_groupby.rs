@@ -26,3 +26,218 @@ fn test_frame_get_group() {
     assert_eq!(df1.index, exp.index);
     assert_eq!(df1.columns, exp.columns);
 }
+
+#[test]
+fn test_frame_groupby_agg() {
+    let values = vec![
+        array![1, 2, 3, 4, 5],
+        array![6., 7., 8., 9., 10.],
+        array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(), "e".to_string()],
+    ];
+    let df = DataFrame::from_vec(values, vec![0, 1, 2, 3, 4], vec!["X", "Y", "Z"]);
+
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+    // non-numeric columns are excluded
+    let exp = DataFrame::from_vec(vec![array![8, 7], array![23., 17.]], vec![1, 2], vec!["X", "Y"]);
+    assert_eq!(dg.sum(), exp);
+    let exp = DataFrame::from_vec(
+        vec![array![3usize, 2], array![3usize, 2]],
+        vec![1, 2],
+        vec!["X", "Y"],
+    );
+    assert_eq!(dg.count(), exp);
+    let exp = DataFrame::from_vec(
+        vec![array![8. / 3., 3.5], array![23. / 3., 8.5]],
+        vec![1, 2],
+        vec!["X", "Y"],
+    );
+    assert_eq!(dg.mean(), exp);
+    let exp = DataFrame::from_vec(vec![array![1, 2], array![6., 7.]], vec![1, 2], vec!["X", "Y"]);
+    assert_eq!(dg.min(), exp);
+}
+
+#[test]
+fn test_frame_groupby_cols() {
+    let values = vec![
+        array!["x".to_string(), "y".to_string(), "x".to_string(), "x".to_string()],
+        array![2018, 2018, 2019, 2018],
+        array![1.5, 2.5, 3.5, 4.5],
+    ];
+    let df = DataFrame::from_vec(values, vec![0, 1, 2, 3], vec!["city", "year", "sales"]);
+
+    let dg = df.groupby_cols(&["city", "year"]);
+    let exp_index = vec![
+        vec![ScalarKey::Str("x".to_string()), ScalarKey::Int(2018)],
+        vec![ScalarKey::Str("x".to_string()), ScalarKey::Int(2019)],
+        vec![ScalarKey::Str("y".to_string()), ScalarKey::Int(2018)],
+    ];
+    assert_eq!(dg.groups(), exp_index);
+
+    let res = dg.sum();
+    assert!(res == DataFrame::from_vec(vec![array![6., 3.5, 2.5]], exp_index, vec!["sales"]));
+
+    let group = dg.get_group(&vec![ScalarKey::Str("x".to_string()), ScalarKey::Int(2018)]);
+    let exp = DataFrame::from_vec(vec![array![1.5, 4.5]], vec![0, 3], vec!["sales"]);
+    assert_eq!(group, exp);
+
+    let dg = df.groupby_cols(&["city"]);
+    let res = dg.max();
+    assert_eq!(res.columns.values, vec!["year", "sales"]);
+    assert_eq!(res.index.values[1][0].to_string(), "y");
+    assert_eq!(*res.values[0], array![2019, 2018]);
+}
+
+#[test]
+fn test_frame_groupby_multi() {
+    let values = vec![array![1, 2, 3, 4], array![1.5, 2.5, 3.5, 4.5]];
+    let df = DataFrame::from_vec(values, vec![0, 1, 2, 3], vec!["X", "Y"]);
+
+    let dg = df.groupby_multi((&["a", "b", "a", "a"], &[1, 1, 2, 1]));
+    let res = dg.sum();
+    let exp = DataFrame::from_vec(
+        vec![array![5, 3, 2], array![6., 3.5, 2.5]],
+        vec![("a", 1), ("a", 2), ("b", 1)],
+        vec!["X", "Y"],
+    );
+    assert!(res == exp);
+    let group = dg.get_group(&("a", 1));
+    assert_eq!(group.index.values, vec![0, 3]);
+}
+
+#[test]
+fn test_frame_groupby_agg_multiple() {
+    let values = vec![
+        array![1, 2, 3, 4, 5],
+        array![6., 7., 8., 9., 10.],
+        array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(), "e".to_string()],
+    ];
+    let df = DataFrame::from_vec(values, vec![0, 1, 2, 3, 4], vec!["X", "Y", "Z"]);
+
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+    let res = dg.agg(&[Agg::Sum, Agg::Mean, Agg::Count]);
+    let exp = DataFrame::from_vec(
+        vec![
+            array![8, 7],
+            array![8. / 3., 3.5],
+            array![3usize, 2],
+            array![23., 17.],
+            array![23. / 3., 8.5],
+            array![3usize, 2],
+        ],
+        vec![1, 2],
+        vec![
+            ("X", Agg::Sum),
+            ("X", Agg::Mean),
+            ("X", Agg::Count),
+            ("Y", Agg::Sum),
+            ("Y", Agg::Mean),
+            ("Y", Agg::Count),
+        ],
+    );
+    assert!(res == exp);
+    // select aggregations of a column
+    let x = res.xs_first_columns(&"X");
+    assert_eq!(x.columns.values, vec![Agg::Sum, Agg::Mean, Agg::Count]);
+}
+
+#[test]
+fn test_frame_groupby_first_last_nth() {
+    let values = vec![
+        array![1, 2, 3, 4, 5],
+        array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(), "e".to_string()],
+    ];
+    let df = DataFrame::from_vec(values, vec![0, 1, 2, 3, 4], vec!["X", "Y"]);
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+
+    let exp = DataFrame::from_vec(
+        vec![array![1, 2], array!["a".to_string(), "b".to_string()]],
+        vec![1, 2],
+        vec!["X", "Y"],
+    );
+    assert_eq!(dg.first(), exp);
+
+    let exp = DataFrame::from_vec(
+        vec![array![4, 5], array!["d".to_string(), "e".to_string()]],
+        vec![1, 2],
+        vec!["X", "Y"],
+    );
+    assert_eq!(dg.last(), exp);
+
+    // group 2 has only 2 rows
+    let exp = DataFrame::from_vec(
+        vec![array![4], array!["d".to_string()]],
+        vec![1],
+        vec!["X", "Y"],
+    );
+    assert_eq!(dg.nth(2), exp);
+}
+
+#[test]
+fn test_frame_groupby_size_cumcount() {
+    let values = vec![array![1, 2, 3, 4, 5], array![1.1, 2.1, 3.1, 4.1, 5.1]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C", "D", "E"], vec!["X", "Y"]);
+    let dg = df.groupby(&[2, 1, 2, 2, 1]);
+
+    let exp: Series<usize, i32> = Series::new(vec![2, 3], vec![1, 2]);
+    assert_eq!(dg.size(), exp);
+
+    let exp: Series<usize, &str> = Series::new(vec![0, 0, 1, 2, 1], vec!["A", "B", "C", "D", "E"]);
+    assert_eq!(dg.cumcount(), exp);
+}
+
+#[test]
+fn test_frame_groupby_value_counts() {
+    let x: Vec<String> = vec!["a", "b", "a", "a", "b"].iter().map(|x| x.to_string()).collect();
+    let y = vec![Scalar::i64(1), Scalar::i64(2), Scalar::Null, Scalar::i64(2), Scalar::i64(2)];
+    let values = vec![Array::new(x), Array::from(y)];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C", "D", "E"], vec!["X", "Y"]);
+    let dg = df.groupby(&[2, 1, 2, 2, 1]);
+
+    let res = dg.value_counts(&"X");
+    assert_eq!(res.values.to_vec(), vec![2, 3]);
+    let exp: Vec<(i32, ScalarKey)> = vec![
+        (1, ScalarKey::Str("b".to_string())),
+        (2, ScalarKey::Str("a".to_string())),
+    ];
+    assert_eq!(res.index.values, exp);
+
+    // nulls are not counted, ties are sorted by value
+    let res = dg.value_counts(&"Y");
+    assert_eq!(res.values.to_vec(), vec![2, 1, 1]);
+    let exp: Vec<(i32, ScalarKey)> =
+        vec![(1, ScalarKey::Int(2)), (2, ScalarKey::Int(1)), (2, ScalarKey::Int(2))];
+    assert_eq!(res.index.values, exp);
+}
+
+#[test]
+fn test_frame_groupby_iter() {
+    let values = vec![array![1, 2, 3], array![1.1, 2.1, 3.1]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+    let dg = df.groupby(&[2, 1, 2]);
+
+    let res: Vec<(i32, usize)> = dg.iter().map(|(k, g)| (k, g.len())).collect();
+    assert_eq!(res, vec![(1, 1), (2, 2)]);
+
+    let (_, g) = dg.iter().last().unwrap();
+    let exp = DataFrame::from_vec(
+        vec![array![1, 3], array![1.1, 3.1]],
+        vec!["A", "C"],
+        vec!["X", "Y"],
+    );
+    assert_eq!(g, exp);
+}
+
+#[test]
+fn test_frame_groupby_minmax_float() {
+    let nan = std::f64::NAN;
+    let values = vec![array![1.5, nan, -2., 0.5], array![1, 2, 3, 4]];
+    let df = DataFrame::from_vec(values, vec![0, 1, 2, 3], vec!["X", "Y"]);
+    let dg = df.groupby(&[1, 1, 1, 2]);
+
+    let res = dg.min();
+    assert_eq!(*res.values[0], array![-2., 0.5]);
+    assert_eq!(*res.values[1], array![1, 4]);
+    let res = dg.max();
+    assert_eq!(*res.values[0], array![1.5, 0.5]);
+    assert_eq!(*res.values[1], array![3, 4]);
+}
@@ -45,12 +45,65 @@ fn test_dataframe_append_different_dtype() {
     let values1 = vec![array![1, 2, 3], array![4.1, 5.1, 6.1], array![1, 2, 3]];
     let df1 = DataFrame::from_vec(values1, vec!["A", "B", "C"], vec!["X", "Y", "Z"]);
 
-    let values2 = vec![array![4, 5], array![7.1, 8.1], array![4.1, 5.1]];
+    let values2 = vec![array![4, 5], array![7.1, 8.1], array!["a", "b"]];
     let df2 = DataFrame::from_vec(values2, vec!["D", "E"], vec!["X", "Y", "Z"]);
 
     df1.append(&df2);
 }
 
+#[test]
+fn test_dataframe_append_promote_dtype() {
+    let values1 = vec![array![1, 2], array![1i64, 2], array![1u8, 2]];
+    let df1 = DataFrame::from_vec(values1, vec!["A", "B"], vec!["X", "Y", "Z"]);
+
+    let nulls: Array = NullVec::with_mask(vec![0., 0.], Some(vec![true, true])).into();
+    let values2 = vec![array![3.5], array![3], nulls.ilocs(&[0])];
+    let df2 = DataFrame::from_vec(values2, vec!["C"], vec!["X", "Y", "Z"]);
+
+    let res = df1.append(&df2);
+    let z: Array = NullVec::with_mask(vec![1u8, 2, 0], Some(vec![false, false, true])).into();
+    let exp = DataFrame::from_vec(
+        vec![array![1., 2., 3.5], array![1i64, 2, 3], z],
+        vec!["A", "B", "C"],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(res, exp);
+    assert_eq!(df1.try_append(&df2).unwrap(), exp);
+
+    let err = Error::DtypeMismatch {
+        column: "\"X\"".to_string(),
+        left: "i32".to_string(),
+        right: "f64".to_string(),
+    };
+    assert_eq!(df1.try_append_strict(&df2).err(), Some(err));
+    assert_eq!(df1.try_append_strict(&df1).unwrap(), df1.append(&df1));
+
+    let df3 = DataFrame::from_vec(
+        vec![array![3], array!["a"], array![3u8]],
+        vec!["C"],
+        vec!["X", "Y", "Z"],
+    );
+    let err = Error::DtypeMismatch {
+        column: "\"Y\"".to_string(),
+        left: "i64".to_string(),
+        right: "str".to_string(),
+    };
+    assert_eq!(df1.try_append(&df3).err(), Some(err));
+
+    // u64 values may not fit in i64
+    let df4 = DataFrame::from_vec(
+        vec![array![3], array![u64::MAX], array![3u8]],
+        vec!["C"],
+        vec!["X", "Y", "Z"],
+    );
+    let err = Error::DtypeMismatch {
+        column: "\"Y\"".to_string(),
+        left: "i64".to_string(),
+        right: "u64".to_string(),
+    };
+    assert_eq!(df1.try_append(&df4).err(), Some(err));
+}
+
 #[test]
 fn test_dataframe_concat() {
 
@@ -116,3 +169,535 @@ fn test_block_join() {
 
     assert_eq!(res, exp);
 }
+
+#[test]
+fn test_dataframe_try_reshape() {
+    let df1 = DataFrame::from_vec(vec![array![1, 2]], vec!["A", "B"], vec!["X"]);
+    let df2 = DataFrame::from_vec(vec![array![3, 4]], vec!["A", "B"], vec!["Y"]);
+    let df3 = DataFrame::from_vec(vec![array![5]], vec!["C"], vec!["X"]);
+
+    let exp = DataFrame::from_vec(vec![array![1, 2, 5]], vec!["A", "B", "C"], vec!["X"]);
+    assert_eq!(df1.try_append(&df3).unwrap(), exp);
+    assert_eq!(df1.try_append(&df2).err(), Some(Error::ColumnsMismatch));
+
+    let exp = DataFrame::from_vec(
+        vec![array![1, 2], array![3, 4]],
+        vec!["A", "B"],
+        vec!["X", "Y"],
+    );
+    assert_eq!(df1.try_concat(&df2).unwrap(), exp);
+    assert_eq!(df1.try_concat(&df3).err(), Some(Error::IndexMismatch));
+    assert_eq!(
+        df1.try_concat(&df1).err(),
+        Some(Error::DuplicateLabel("\"X\"".to_string()))
+    );
+
+    assert_eq!(df1.try_join_inner(&df2).unwrap(), exp);
+    assert!(df1.try_join_inner(&df3).is_err());
+}
+
+#[test]
+fn test_block_join_left_right_outer() {
+    let df1 = DataFrame::from_vec(
+        vec![array![1, 2, 3], array![1.5, 2.5, 3.5]],
+        vec!["A", "B", "C"],
+        vec!["X", "Y"],
+    );
+    let df2 = DataFrame::from_vec(
+        vec![array!["d".to_string(), "b".to_string()]],
+        vec!["D", "B"],
+        vec!["Z"],
+    );
+
+    let res = df1.join_left(&df2);
+    let exp = DataFrame::from_vec(
+        vec![
+            array![1, 2, 3],
+            array![1.5, 2.5, 3.5],
+            Array::StringArray(NullVec::with_mask(
+                vec!["".to_string(), "b".to_string(), "".to_string()],
+                Some(vec![true, false, true]),
+            )),
+        ],
+        vec!["A", "B", "C"],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(res, exp);
+    assert_eq!(df1.join(&df2, How::Left), exp);
+
+    let res = df1.join_right(&df2);
+    let exp = DataFrame::from_vec(
+        vec![
+            Array::Int32Array(NullVec::with_mask(vec![0, 2], Some(vec![true, false]))),
+            Array::Float64Array(vec![Nullable::Null, Nullable::Value(2.5)].into()),
+            array!["d".to_string(), "b".to_string()],
+        ],
+        vec!["D", "B"],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(res, exp);
+
+    let res = df1.join_outer(&df2);
+    assert_eq!(res.len(), 4);
+    assert_eq!(res.index.values, vec!["A", "B", "C", "D"]);
+    let exp = Array::StringArray(NullVec::with_mask(
+        vec!["".to_string(), "b".to_string(), "".to_string(), "d".to_string()],
+        Some(vec![true, false, true, false]),
+    ));
+    assert_eq!(*res.values[2], exp);
+    let exp = Array::Int32Array(NullVec::with_mask(
+        vec![1, 2, 3, 0],
+        Some(vec![false, false, false, true]),
+    ));
+    assert_eq!(*res.values[0], exp);
+
+    assert_eq!(df1.join(&df2, How::Inner), df1.join_inner(&df2));
+    assert!(df1.try_join(&df1, How::Outer).is_err());
+}
+
+#[test]
+fn test_dataframe_merge() {
+    let left = DataFrame::from_vec(
+        vec![
+            array!["a".to_string(), "b".to_string(), "b".to_string(), "c".to_string()],
+            array![1, 1, 2, 1],
+            array![1.5, 2.5, 3.5, 4.5],
+        ],
+        vec![0, 1, 2, 3],
+        vec!["K1".to_string(), "K2".to_string(), "V".to_string()],
+    );
+    let right = DataFrame::from_vec(
+        vec![
+            array![1i64, 1, 1],
+            array!["b".to_string(), "a".to_string(), "d".to_string()],
+            array![10, 20, 30],
+        ],
+        vec![0, 1, 2],
+        vec!["K2".to_string(), "K1".to_string(), "V".to_string()],
+    );
+
+    let res = left.merge(&right, &["K1", "K2"], How::Inner);
+    let exp = DataFrame::from_vec(
+        vec![
+            array!["a".to_string(), "b".to_string()],
+            // i32 and i64 keys are promoted to i64
+            array![1i64, 1],
+            array![1.5, 2.5],
+            array![20, 10],
+        ],
+        vec![0, 1],
+        vec!["K1".to_string(), "K2".to_string(), "V_x".to_string(), "V_y".to_string()],
+    );
+    assert_eq!(res, exp);
+
+    let res = left.merge_with_suffixes(&right, &["K1", "K2"], How::Outer, ("_l", "_r"));
+    assert_eq!(res.len(), 5);
+    assert_eq!(
+        res.columns.values,
+        vec!["K1".to_string(), "K2".to_string(), "V_l".to_string(), "V_r".to_string()]
+    );
+    // keys of rows only in right are filled
+    let exp = array![
+        "a".to_string(),
+        "b".to_string(),
+        "b".to_string(),
+        "c".to_string(),
+        "d".to_string()
+    ];
+    assert_eq!(*res.values[0], exp);
+    assert_eq!(*res.values[1], array![1i64, 1, 2, 1, 1]);
+    let exp = Array::Int32Array(NullVec::with_mask(
+        vec![20, 10, 0, 0, 30],
+        Some(vec![false, false, true, true, false]),
+    ));
+    assert_eq!(*res.values[3], exp);
+
+    // duplicated keys produce all combinations
+    let res = left.merge(&right, &["K2"], How::Left);
+    assert_eq!(res.len(), 10);
+    assert_eq!(
+        res.columns.values,
+        vec!["K1_x".to_string(), "K2".to_string(), "V_x".to_string(), "K1_y".to_string(),
+             "V_y".to_string()]
+    );
+
+    assert!(left.try_merge(&right, &["X"], How::Inner).is_err());
+}
+
+#[test]
+fn test_dataframe_merge_key_dtypes() {
+    let left = DataFrame::from_vec(
+        vec![array![1i64, 2], array![1, 2]],
+        vec![0, 1],
+        vec!["K".to_string(), "X".to_string()],
+    );
+    let right = DataFrame::from_vec(
+        vec![array![2., 2.5], array![10, 20]],
+        vec![0, 1],
+        vec!["K".to_string(), "Y".to_string()],
+    );
+    // keys are promoted to the common dtype
+    let res = left.try_merge(&right, &["K"], How::Outer).unwrap();
+    assert_eq!(*res.values[0], array![1., 2., 2.5]);
+    let exp = Array::Int32Array(NullVec::with_mask(
+        vec![1, 2, 0],
+        Some(vec![false, false, true]),
+    ));
+    assert_eq!(*res.values[1], exp);
+
+    let right = DataFrame::from_vec(
+        vec![array!["1".to_string(), "3".to_string()], array![10, 20]],
+        vec![0, 1],
+        vec!["K".to_string(), "Y".to_string()],
+    );
+    let res = left.try_merge(&right, &["K"], How::Outer);
+    assert_eq!(
+        res.unwrap_err(),
+        Error::DtypeMismatch {
+            column: "\"K\"".to_string(),
+            left: "i64".to_string(),
+            right: "str".to_string(),
+        }
+    );
+
+    let right = DataFrame::from_vec(
+        vec![array![u64::MAX, 1], array![10, 20]],
+        vec![0, 1],
+        vec!["K".to_string(), "Y".to_string()],
+    );
+    let res = left.try_merge(&right, &["K"], How::Outer);
+    assert_eq!(
+        res.unwrap_err(),
+        Error::DtypeMismatch {
+            column: "\"K\"".to_string(),
+            left: "i64".to_string(),
+            right: "u64".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_dataframe_merge_null_keys() {
+    let key: Array = NullVec::with_mask(vec![1i64, 0, 2], Some(vec![false, true, false])).into();
+    let left = DataFrame::from_vec(
+        vec![key.clone(), array![1, 2, 3]],
+        vec![0, 1, 2],
+        vec!["K".to_string(), "X".to_string()],
+    );
+    let right = DataFrame::from_vec(
+        vec![key, array![10, 20, 30]],
+        vec![0, 1, 2],
+        vec!["K".to_string(), "Y".to_string()],
+    );
+
+    let res = left.merge_with_nulls(&right, &["K"], How::Inner, NullEquality::Equal);
+    assert_eq!(*res.values[2], array![10, 20, 30]);
+    let res = left.merge_with_nulls(&right, &["K"], How::Inner, NullEquality::Distinct);
+    assert_eq!(*res.values[2], array![10, 30]);
+
+    let res = left.merge_with_nulls(&right, &["K"], How::Outer, NullEquality::Distinct);
+    assert_eq!(res.len(), 4);
+    let exp = Array::Int32Array(NullVec::with_mask(
+        vec![1, 2, 3, 0],
+        Some(vec![false, false, false, true]),
+    ));
+    assert_eq!(*res.values[1], exp);
+}
+
+#[test]
+fn test_dataframe_join_with() {
+    let df1 = DataFrame::from_vec(vec![array![1, 2, 3]], vec![1, 3, 5], vec!["X"]);
+    let df2 = DataFrame::from_vec(vec![array![4.5, 5.5, 6.5]], vec![2, 3, 5], vec!["Y"]);
+
+    for how in &[How::Inner, How::Left, How::Right] {
+        let res = df1.join_with(&df2, *how, JoinMethod::SortMerge);
+        assert_eq!(res, df1.join(&df2, *how));
+        assert_eq!(df1.join_with(&df2, *how, JoinMethod::Auto), res);
+    }
+
+    // outer join by sort-merge is ordered by labels
+    let res = df1.join_with(&df2, How::Outer, JoinMethod::Auto);
+    assert_eq!(res.index.values, vec![1, 2, 3, 5]);
+    let exp = Array::Int32Array(NullVec::with_mask(
+        vec![1, 0, 2, 3],
+        Some(vec![false, true, false, false]),
+    ));
+    assert_eq!(*res.values[0], exp);
+    let res = df1.join_with(&df2, How::Outer, JoinMethod::Hash);
+    assert_eq!(res.index.values, vec![1, 3, 5, 2]);
+
+    // unsorted index is sorted by explicit sort-merge join
+    let df3 = DataFrame::from_vec(vec![array![4.5, 5.5, 6.5]], vec![5, 3, 2], vec!["Y"]);
+    let res = df1.join_with(&df3, How::Inner, JoinMethod::SortMerge);
+    assert_eq!(res.index.values, vec![3, 5]);
+    assert_eq!(*res.values[1], array![5.5, 4.5]);
+    let res = df1.join_with(&df3, How::Inner, JoinMethod::Auto);
+    assert_eq!(res, df1.join_inner(&df3));
+}
+
+#[test]
+fn test_dataframe_join_asof() {
+    // quotes and trades, labelled by timestamps
+    let trades = DataFrame::from_vec(
+        vec![array![100, 200, 300]],
+        vec![10i64, 25, 40],
+        vec!["qty"],
+    );
+    let quotes = DataFrame::from_vec(
+        vec![array![1.5, 2.5, 3.5]],
+        vec![8i64, 20, 41],
+        vec!["price"],
+    );
+
+    let res = trades.join_asof(&quotes, None, Direction::Backward);
+    let exp = DataFrame::from_vec(
+        vec![array![100, 200, 300], array![1.5, 2.5, 2.5]],
+        vec![10i64, 25, 40],
+        vec!["qty", "price"],
+    );
+    assert_eq!(res, exp);
+
+    let res = trades.join_asof(&quotes, Some(3), Direction::Backward);
+    let exp = Array::Float64Array(
+        vec![Nullable::Value(1.5), Nullable::Null, Nullable::Null].into(),
+    );
+    assert_eq!(*res.values[1], exp);
+
+    let res = trades.join_asof(&quotes, None, Direction::Forward);
+    assert_eq!(*res.values[1], array![2.5, 3.5, 3.5]);
+
+    let res = trades.join_asof(&quotes, Some(2), Direction::Nearest);
+    let exp = Array::Float64Array(
+        vec![Nullable::Value(1.5), Nullable::Null, Nullable::Value(3.5)].into(),
+    );
+    assert_eq!(*res.values[1], exp);
+}
+
+#[test]
+fn test_dataframe_complete() {
+    let df = DataFrame::from_vec(
+        vec![
+            array!["a".to_string(), "b".to_string(), "a".to_string(), "b".to_string()],
+            array![2018, 2018, 2020, 2021],
+            array![1.5, 2.5, 3.5, 4.5],
+        ],
+        vec![0, 1, 2, 3],
+        vec!["city".to_string(), "year".to_string(), "sales".to_string()],
+    );
+
+    let years = array![2018, 2019, 2020];
+    let res = df.complete(&["city"], "year", &years, &Scalar::Null);
+    let exp = DataFrame::from_vec(
+        vec![
+            array![
+                "a".to_string(),
+                "a".to_string(),
+                "a".to_string(),
+                "b".to_string(),
+                "b".to_string(),
+                "b".to_string()
+            ],
+            array![2018, 2019, 2020, 2018, 2019, 2020],
+            Array::Float64Array(
+                vec![
+                    Nullable::Value(1.5),
+                    Nullable::Null,
+                    Nullable::Value(3.5),
+                    Nullable::Value(2.5),
+                    Nullable::Null,
+                    Nullable::Null,
+                ].into(),
+            ),
+        ],
+        vec![0, 1, 2, 3, 4, 5],
+        vec!["city".to_string(), "year".to_string(), "sales".to_string()],
+    );
+    assert_eq!(res, exp);
+
+    let res = df.complete(&["city"], "year", &years, &Scalar::f64(0.));
+    assert_eq!(*res.values[2], array![1.5, 0., 3.5, 2.5, 0., 0.]);
+}
+
+#[test]
+fn test_dataframe_explode_multi() {
+    let df = DataFrame::from_vec(vec![array![1, 2, 3]], vec!["A", "B", "C"], vec!["id"]);
+
+    let tags = vec![
+        array!["x".to_string(), "y".to_string()],
+        Array::StringArray(Vec::<String>::new().into()),
+        array!["z".to_string()],
+    ];
+    let weights = vec![
+        array![0.1, 0.2],
+        Array::Float64Array(Vec::<f64>::new().into()),
+        array![0.3],
+    ];
+    let res = df.explode_multi(&[("tag", &tags), ("weight", &weights)]);
+    let exp = DataFrame::from_vec(
+        vec![
+            array![1, 1, 2, 3],
+            Array::StringArray(
+                vec![
+                    Nullable::Value("x".to_string()),
+                    Nullable::Value("y".to_string()),
+                    Nullable::Null,
+                    Nullable::Value("z".to_string()),
+                ].into(),
+            ),
+            Array::Float64Array(
+                vec![
+                    Nullable::Value(0.1),
+                    Nullable::Value(0.2),
+                    Nullable::Null,
+                    Nullable::Value(0.3),
+                ].into(),
+            ),
+        ],
+        vec!["A", "A", "B", "C"],
+        vec!["id", "tag", "weight"],
+    );
+    assert_eq!(res, exp);
+
+    // existing column is replaced
+    let ids = vec![array![10, 11], array![20, 21], array![30]];
+    let res = df.explode_multi(&[("id", &ids)]);
+    assert_eq!(res.columns.len(), 1);
+    assert_eq!(*res.values[0], array![10, 11, 20, 21, 30]);
+}
+
+#[test]
+#[should_panic]
+fn test_dataframe_explode_multi_different_lengths() {
+    let df = DataFrame::from_vec(vec![array![1]], vec!["A"], vec!["id"]);
+    let a = vec![array![1, 2]];
+    let b = vec![array![1]];
+    df.explode_multi(&[("a", &a), ("b", &b)]);
+}
+
+#[test]
+fn test_dataframe_reindex_on() {
+    let orders = DataFrame::from_vec(
+        vec![
+            array![10, 20, 30, 40],
+            array!["p2".to_string(), "p1".to_string(), "p9".to_string(), "p2".to_string()],
+        ],
+        vec![1, 2, 3, 4],
+        vec!["qty", "product"],
+    );
+    let products = DataFrame::from_vec(
+        vec![array!["pen".to_string(), "ink".to_string()], array![1.5, 0.5]],
+        vec!["p1", "p2"],
+        vec!["name", "price"],
+    );
+
+    let res = orders.reindex_on(&"product", &products);
+    let exp = DataFrame::from_vec(
+        vec![
+            Array::from(vec![
+                Scalar::String("ink".to_string()),
+                Scalar::String("pen".to_string()),
+                Scalar::Null,
+                Scalar::String("ink".to_string()),
+            ]),
+            NullVec::with_mask(vec![0.5, 1.5, 0., 0.5], Some(vec![false, false, true, false]))
+                .into(),
+        ],
+        vec![1, 2, 3, 4],
+        vec!["name", "price"],
+    );
+    assert_eq!(res, exp);
+
+    // integer keys match labels of any integer type
+    let lookup = DataFrame::from_vec(vec![array![0.1, 0.2]], vec![10usize, 20], vec!["rate"]);
+    let res = orders.reindex_on(&"qty", &lookup);
+    assert_eq!(res.index, orders.index);
+    assert_eq!(res.get(&"rate").iloc(&1), Scalar::f64(0.2));
+    assert_eq!(res.get(&"rate").iloc(&2), Scalar::Null);
+}
+
+#[test]
+#[should_panic]
+fn test_dataframe_reindex_on_duplicated() {
+    let df = DataFrame::from_vec(vec![array![1, 2]], vec![0, 1], vec!["key"]);
+    let other = DataFrame::from_vec(vec![array![1.5, 2.5]], vec![1, 1], vec!["value"]);
+    df.reindex_on(&"key", &other);
+}
+
+#[test]
+fn test_dataframe_pivot_table() {
+    let df = DataFrame::from_vec(
+        vec![
+            array!["a".to_string(), "a".to_string(), "b".to_string(), "a".to_string()],
+            array![2019, 2018, 2018, 2018],
+            array![1.5, 2.5, 3.5, 4.5],
+        ],
+        vec![0, 1, 2, 3],
+        vec!["city", "year", "sales"],
+    );
+
+    let res = df.pivot_table(&"city", &"year", &"sales", Agg::Sum);
+    let exp = DataFrame::from_vec(
+        vec![
+            array![7., 3.5],
+            Array::Float64Array(vec![Nullable::Value(1.5), Nullable::Null].into()),
+        ],
+        vec![ScalarKey::Str("a".to_string()), ScalarKey::Str("b".to_string())],
+        vec![ScalarKey::Int(2018), ScalarKey::Int(2019)],
+    );
+    assert!(res == exp);
+
+    let res = df.pivot_table(&"year", &"city", &"sales", Agg::Count);
+    assert_eq!(res.index.values, vec![ScalarKey::Int(2018), ScalarKey::Int(2019)]);
+    assert_eq!(
+        *res.values[0],
+        Array::UsizeArray(vec![Nullable::Value(2), Nullable::Value(1)].into())
+    );
+    assert_eq!(
+        *res.values[1],
+        Array::UsizeArray(vec![Nullable::Value(1), Nullable::Null].into())
+    );
+}
+
+#[test]
+fn test_dataframe_pivot_table_margins() {
+    let df = DataFrame::from_vec(
+        vec![
+            array!["a".to_string(), "a".to_string(), "b".to_string(), "a".to_string()],
+            array![2019, 2018, 2018, 2018],
+            array![1.5, 2.5, 3.5, 4.5],
+        ],
+        vec![0, 1, 2, 3],
+        vec!["city", "year", "sales"],
+    );
+
+    let res = df.pivot_table_with(&"city", &"year", &"sales", Agg::Mean, true);
+    let all = ScalarKey::Str("All".to_string());
+    let exp = DataFrame::from_vec(
+        vec![
+            array![3.5, 3.5, 3.5],
+            Array::Float64Array(
+                vec![Nullable::Value(1.5), Nullable::Null, Nullable::Value(1.5)].into(),
+            ),
+            array![8.5 / 3., 3.5, 3.],
+        ],
+        vec![ScalarKey::Str("a".to_string()), ScalarKey::Str("b".to_string()), all.clone()],
+        vec![ScalarKey::Int(2018), ScalarKey::Int(2019), all.clone()],
+    );
+    assert!(res == exp);
+    assert!(df.pivot_table_with(&"city", &"year", &"sales", Agg::Sum, false) ==
+        df.pivot_table(&"city", &"year", &"sales", Agg::Sum));
+
+    let res = df.crosstab(&"city", &"year", false);
+    let exp = DataFrame::from_vec(
+        vec![array![2usize, 1], array![1usize, 0]],
+        vec![ScalarKey::Str("a".to_string()), ScalarKey::Str("b".to_string())],
+        vec![ScalarKey::Int(2018), ScalarKey::Int(2019)],
+    );
+    assert!(res == exp);
+
+    let res = df.crosstab(&"city", &"year", true);
+    assert_eq!(res.index.values[2], all);
+    assert_eq!(*res.values[0], array![2usize, 1, 3]);
+    assert_eq!(*res.values[1], array![1usize, 0, 1]);
+    assert_eq!(*res.values[2], array![3usize, 1, 4]);
+}
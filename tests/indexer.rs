@@ -8,7 +8,7 @@ fn test_index_creation_from_len() {
     assert_eq!(idx.len(), 3);
 
     let idx: Indexer<usize> = Indexer::<usize>::from_len(0);
-    assert_eq!(idx.values, vec![]);
+    assert_eq!(idx.values, Vec::<usize>::new());
     assert_eq!(idx.len(), 0);
 }
 
@@ -177,3 +177,53 @@ fn test_index_from_iter() {
     let exp: Indexer<i64> = Indexer::new(vec![3, 4, 5]);
     assert_eq!(index, exp);
 }
+
+#[test]
+fn test_index_try_get_loc() {
+    let idx: Indexer<&str> = Indexer::new(vec!["A", "B", "C"]);
+    assert_eq!(idx.try_get_loc(&"B"), Ok(1));
+    assert_eq!(idx.try_get_locs(&["C", "A"]), Ok(vec![2, 0]));
+    assert_eq!(
+        idx.try_get_loc(&"X"),
+        Err(Error::KeyNotFound("\"X\"".to_string()))
+    );
+    assert_eq!(
+        idx.try_get_locs(&["A", "X"]),
+        Err(Error::KeyNotFound("\"X\"".to_string()))
+    );
+
+    let idx: Indexer<i64> = Indexer::new(vec![1, 2, 1]);
+    assert_eq!(idx.try_get_loc(&1), Err(Error::DuplicateLabel("1".to_string())));
+}
+
+#[test]
+fn test_index_try_push() {
+    let mut idx: Indexer<i64> = Indexer::new(vec![1, 2, 3]);
+    assert_eq!(idx.try_push(4), Ok(()));
+    assert_eq!(idx.values, vec![1, 2, 3, 4]);
+    assert_eq!(idx.try_push(2), Err(Error::DuplicateLabel("2".to_string())));
+    assert_eq!(idx.values, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_index_introspection() {
+    let idx: Indexer<i64> = Indexer::new(vec![1, 3, 3]);
+    assert!(!idx.is_unique());
+    assert!(idx.is_monotonic_increasing());
+    assert_eq!(idx.max(), Some(&3));
+
+    let idx: Indexer<&str> = Indexer::new(vec!["b", "a"]);
+    assert!(idx.is_unique());
+    assert!(idx.is_monotonic_decreasing());
+    assert_eq!(idx.min(), Some(&"a"));
+    // lookup works after the check
+    assert_eq!(idx.get_loc(&"a"), 1);
+}
+
+#[test]
+fn test_index_factorize() {
+    let idx: Indexer<i64> = Indexer::new(vec![3, 1, 3, 2, 1]);
+    let (codes, uniques) = idx.factorize();
+    assert_eq!(codes, vec![0, 1, 0, 2, 1]);
+    assert_eq!(uniques, Indexer::new(vec![3, 1, 2]));
+}
@@ -0,0 +1,141 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde_derive;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Point {
+    name: String,
+    x: i64,
+}
+
+struct PointCodec;
+
+impl RowCodec for PointCodec {
+    type Row = Point;
+    type Error = String;
+
+    fn encode(&self, row: &Point) -> Result<Vec<(String, Scalar)>, String> {
+        Ok(vec![
+            ("name".to_string(), Scalar::String(row.name.clone())),
+            ("x".to_string(), Scalar::i64(row.x)),
+        ])
+    }
+
+    fn decode(&self, columns: &[String], values: Vec<Scalar>) -> Result<Point, String> {
+        let mut name = None;
+        let mut x = None;
+        for (column, value) in columns.iter().zip(values) {
+            match (column.as_ref(), value) {
+                ("name", Scalar::String(v)) => name = Some(v),
+                ("x", Scalar::i64(v)) => x = Some(v),
+                (c, _) => return Err(format!("unexpected column {}", c)),
+            }
+        }
+        match (name, x) {
+            (Some(name), Some(x)) => Ok(Point { name, x }),
+            _ => Err("missing field".to_string()),
+        }
+    }
+}
+
+#[test]
+fn test_from_rows() {
+    let rows = vec![
+        Point {
+            name: "a".to_string(),
+            x: 1,
+        },
+        Point {
+            name: "b".to_string(),
+            x: 2,
+        },
+    ];
+    let res = DataFrame::<usize, String>::from_rows(&PointCodec, rows.clone()).unwrap();
+    let exp = DataFrame::from_vec(
+        vec![array!["a".to_string(), "b".to_string()], array![1i64, 2]],
+        vec![0, 1],
+        vec!["name".to_string(), "x".to_string()],
+    );
+    assert_eq!(res, exp);
+
+    let res = res.to_rows(&PointCodec).unwrap();
+    assert_eq!(res, rows);
+}
+
+#[test]
+fn test_to_rows_error() {
+    let df = DataFrame::from_vec(vec![array![1i64, 2]], vec![0, 1], vec!["y".to_string()]);
+    assert!(df.to_rows(&PointCodec).is_err());
+}
+
+#[cfg(feature = "serde")]
+mod serde_codec {
+
+    use brassfibre::prelude::*;
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+    enum Side {
+        Buy,
+        Sell,
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+    struct Trade {
+        symbol: String,
+        side: Side,
+        quantity: u32,
+        price: f64,
+        note: Option<String>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Nested {
+        trade: Trade,
+    }
+
+    fn trades() -> Vec<Trade> {
+        vec![
+            Trade {
+                symbol: "A".to_string(),
+                side: Side::Buy,
+                quantity: 10,
+                price: 1.5,
+                note: None,
+            },
+            Trade {
+                symbol: "B".to_string(),
+                side: Side::Sell,
+                quantity: 5,
+                price: 2.,
+                note: Some("x".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_serde_codec_round_trip() {
+        let codec: SerdeCodec<Trade> = SerdeCodec::new();
+        let df = DataFrame::<usize, String>::from_rows(&codec, trades()).unwrap();
+
+        let exp_columns: Vec<String> = vec!["symbol", "side", "quantity", "price", "note"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect();
+        assert_eq!(df.columns.values, exp_columns);
+        assert_eq!(df.dtypes(), vec!["str", "str", "i64", "f64", "str"]);
+
+        let res: Vec<Trade> = df.to_rows(&codec).unwrap();
+        assert_eq!(res, trades());
+    }
+
+    #[test]
+    fn test_serde_codec_nested() {
+        let codec: SerdeCodec<Nested> = SerdeCodec::new();
+        let rows = vec![Nested { trade: trades()[0].clone() }];
+        assert!(DataFrame::<usize, String>::from_rows(&codec, rows).is_err());
+    }
+}
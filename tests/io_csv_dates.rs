@@ -0,0 +1,106 @@
+#![cfg(feature = "chrono")]
+
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+extern crate chrono;
+extern crate csv;
+
+use chrono::FixedOffset;
+
+#[test]
+fn test_read_csv_dates() {
+    let data = "A,B,C
+02/01/2018 09:00,2018-01-02,1
+03/01/2018 10:30,,2
+31/02/2018 00:00,2018-01-04,3
+04/01/2018 12:00,x,4";
+
+    let rdr = csv::Reader::from_reader(data.as_bytes());
+    let options = DateOptions::new()
+        .column("A", "%d/%m/%Y %H:%M")
+        .column("B", "%Y-%m-%d")
+        .timezone(FixedOffset::east_opt(9 * 3600).unwrap());
+    let (res, summary) = DataFrame::<usize, String>::read_csv_dates(rdr, &options).unwrap();
+
+    assert_eq!(res.dtypes(), vec!["i64", "i64", "i64"]);
+    let hour: i64 = 3_600_000_000_000;
+    // 2018-01-02 00:00 UTC
+    let base: i64 = 1_514_851_200_000_000_000;
+    let exp_values = vec![
+        Array::Int64Array(NullVec::with_mask(
+            vec![base, base + 24 * hour + 90 * hour / 60, 0, base + 2 * 24 * hour + 3 * hour],
+            Some(vec![false, false, true, false]),
+        )),
+        Array::Int64Array(NullVec::with_mask(
+            vec![base - 9 * hour, 0, base + 2 * 24 * hour - 9 * hour, 0],
+            Some(vec![false, true, false, true]),
+        )),
+        array![1i64, 2, 3, 4],
+    ];
+    let exp = DataFrame::from_vec(
+        exp_values,
+        vec![0, 1, 2, 3],
+        vec!["A".to_string(), "B".to_string(), "C".to_string()],
+    );
+    assert_eq!(res, exp);
+
+    assert_eq!(summary.failures.len(), 2);
+    assert_eq!(summary.count("A"), 1);
+    assert_eq!(
+        summary.failures[1],
+        DateParseFailure {
+            column: "B".to_string(),
+            row: 3,
+            value: "x".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_read_csv_dates_with_offset() {
+    let data = "A\n2018-01-02T09:00:00+09:00\n2018-01-02T00:00:00+00:00";
+    let rdr = csv::Reader::from_reader(data.as_bytes());
+    let options = DateOptions::new().column("A", "%Y-%m-%dT%H:%M:%S%:z");
+    let (res, summary) = DataFrame::<usize, String>::read_csv_dates(rdr, &options).unwrap();
+    assert!(summary.is_empty());
+    let exp = array![1_514_851_200_000_000_000i64, 1_514_851_200_000_000_000];
+    assert_eq!(*res.values[0], exp);
+}
+
+#[test]
+fn test_parse_dates() {
+    let df: DataFrame<usize, String> = DataFrame::from_vec(
+        vec![
+            Array::from(vec![
+                Scalar::String("2018-01-02".to_string()),
+                Scalar::Null,
+                Scalar::String("2018-02-30".to_string()),
+            ]),
+            array![1i64, 2, 3],
+        ],
+        vec![0, 1, 2],
+        vec!["A".to_string(), "B".to_string()],
+    );
+    // non-string columns are kept
+    let options = DateOptions::new().column("A", "%Y-%m-%d").column("B", "%Y");
+    let (res, summary) = df.parse_dates(&options);
+
+    let exp = Array::Int64Array(NullVec::with_mask(
+        vec![1_514_851_200_000_000_000, 0, 0],
+        Some(vec![false, true, true]),
+    ));
+    assert_eq!(*res.values[0], exp);
+    assert_eq!(*res.values[1], array![1i64, 2, 3]);
+    assert_eq!(
+        summary.failures,
+        vec![
+            DateParseFailure {
+                column: "A".to_string(),
+                row: 2,
+                value: "2018-02-30".to_string(),
+            },
+        ]
+    );
+}
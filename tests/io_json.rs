@@ -0,0 +1,119 @@
+#![cfg(feature = "json")]
+
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_read_ndjson() {
+    let data = r#"{"A": "x", "B": true, "C": 7, "D": 1.1}
+{"A": "y", "B": false, "C": 3, "D": 2.2}
+
+{"A": "z", "B": true, "C": 1, "D": 4.5}
+"#;
+    let res = DataFrame::<usize, String>::read_ndjson(data.as_bytes()).unwrap();
+
+    let exp_values = vec![
+        array!["x".to_string(), "y".to_string(), "z".to_string()],
+        array![true, false, true],
+        array![7i64, 3, 1],
+        array![1.1, 2.2, 4.5],
+    ];
+    let exp = DataFrame::from_vec(
+        exp_values,
+        vec![0, 1, 2],
+        vec![
+            "A".to_string(),
+            "B".to_string(),
+            "C".to_string(),
+            "D".to_string(),
+        ],
+    );
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_ndjson_missing_keys() {
+    let data = r#"{"A": 1, "B": 1.5}
+{"A": 2, "C": "x"}
+{"B": 3, "A": null}"#;
+    let res = DataFrame::<usize, String>::read_ndjson(data.as_bytes()).unwrap();
+
+    let exp_values = vec![
+        Array::Int64Array(NullVec::with_mask(vec![1, 2, 0], Some(vec![false, false, true]))),
+        Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 3.], Some(vec![false, true, false]))),
+        Array::StringArray(NullVec::with_mask(
+            vec!["".to_string(), "x".to_string(), "".to_string()],
+            Some(vec![true, false, true]),
+        )),
+    ];
+    let exp = DataFrame::from_vec(
+        exp_values,
+        vec![0, 1, 2],
+        vec!["A".to_string(), "B".to_string(), "C".to_string()],
+    );
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_read_ndjson_batches() {
+    let data = r#"{"A": 1}
+{"A": 2}
+{"A": 3}
+{"A": 4}
+{"A": 5}"#;
+    let batches: Vec<DataFrame<usize, String>> =
+        DataFrame::<usize, String>::read_ndjson_batches(data.as_bytes(), 2)
+            .map(|x| x.unwrap())
+            .collect();
+    assert_eq!(batches.len(), 3);
+
+    let exp = DataFrame::from_vec(vec![array![3i64, 4]], vec![2, 3], vec!["A".to_string()]);
+    assert_eq!(batches[1], exp);
+    let exp = DataFrame::from_vec(vec![array![5i64]], vec![4], vec!["A".to_string()]);
+    assert_eq!(batches[2], exp);
+}
+
+#[test]
+fn test_read_ndjson_error() {
+    let data = r#"{"A": 1}
+[1, 2]"#;
+    let res = DataFrame::<usize, String>::read_ndjson(data.as_bytes());
+    assert!(res.is_err());
+
+    let mut batches = DataFrame::<usize, String>::read_ndjson_batches(data.as_bytes(), 1);
+    assert!(batches.next().unwrap().is_ok());
+    assert!(batches.next().unwrap().is_err());
+}
+
+#[test]
+fn test_write_ndjson() {
+    let values = vec![
+        array!["x".to_string(), "y".to_string()],
+        array![true, false],
+        array![7i64, 3],
+        Array::Float64Array(NullVec::with_mask(vec![1.1, 0.], Some(vec![false, true]))),
+    ];
+    let df = DataFrame::from_vec(
+        values,
+        vec![0, 1],
+        vec![
+            "B".to_string(),
+            "A".to_string(),
+            "C".to_string(),
+            "D".to_string(),
+        ],
+    );
+
+    let mut buf: Vec<u8> = vec![];
+    df.write_ndjson(&mut buf).unwrap();
+    let res = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        res,
+        "{\"B\":\"x\",\"A\":true,\"C\":7,\"D\":1.1}\n{\"B\":\"y\",\"A\":false,\"C\":3,\"D\":null}\n"
+    );
+
+    // test round-trip
+    let res = DataFrame::<usize, String>::read_ndjson(res.as_bytes()).unwrap();
+    assert_eq!(res, df);
+}
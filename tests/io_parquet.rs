@@ -0,0 +1,214 @@
+#![cfg(feature = "parquet")]
+
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+extern crate parquet;
+
+use std::env;
+use std::fs::{self, File};
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    let mut path = env::temp_dir();
+    path.push(format!("brassfibre_{}.parquet", name));
+    path
+}
+
+fn sample_frame<'a>() -> DataFrame<'a, 'a, 'a, usize, String> {
+    let values = vec![
+        array!["x".to_string(), "y".to_string(), "z".to_string()],
+        array![true, false, true],
+        Array::Int64Array(NullVec::with_mask(vec![7, 0, 1], Some(vec![false, true, false]))),
+        array![1.1, 2.2, 4.5],
+        array![1u8, 2, 3],
+    ];
+    DataFrame::from_vec(
+        values,
+        vec![0, 1, 2],
+        vec![
+            "A".to_string(),
+            "B".to_string(),
+            "C".to_string(),
+            "D".to_string(),
+            "E".to_string(),
+        ],
+    )
+}
+
+#[test]
+fn test_parquet_round_trip() {
+    let df = sample_frame();
+    let path = temp_path("round_trip");
+    df.to_parquet(File::create(&path).unwrap()).unwrap();
+
+    let res = DataFrame::<usize, String>::read_parquet(File::open(&path).unwrap(), None).unwrap();
+    assert_eq!(res.dtypes(), df.dtypes());
+    assert_eq!(res, df);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_read_parquet_columns() {
+    let df = sample_frame();
+    let path = temp_path("columns");
+    df.to_parquet(File::create(&path).unwrap()).unwrap();
+
+    let res = DataFrame::<usize, String>::read_parquet(File::open(&path).unwrap(),
+                                                       Some(&["D", "A"]))
+        .unwrap();
+    let exp = DataFrame::from_vec(
+        vec![
+            array![1.1, 2.2, 4.5],
+            array!["x".to_string(), "y".to_string(), "z".to_string()],
+        ],
+        vec![0, 1, 2],
+        vec!["D".to_string(), "A".to_string()],
+    );
+    assert_eq!(res, exp);
+
+    let res = DataFrame::<usize, String>::read_parquet(File::open(&path).unwrap(), Some(&["X"]));
+    assert!(res.is_err());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_read_parquet_chunks() {
+    let df = DataFrame::from_vec(
+        vec![array![1i64, 2, 3, 4, 5], array![1.5, 2.5, 3.5, 4.5, 5.5]],
+        vec![0, 1, 2, 3, 4],
+        vec!["A".to_string(), "B".to_string()],
+    );
+    let path = temp_path("chunks");
+    df.to_parquet_chunked(File::create(&path).unwrap(), 2).unwrap();
+
+    let chunks: Vec<DataFrame<usize, String>> =
+        DataFrame::<usize, String>::read_parquet_chunks(File::open(&path).unwrap(), Some(&["B"]))
+            .unwrap()
+            .map(|x| x.unwrap())
+            .collect();
+    assert_eq!(chunks.len(), 3);
+    let exp = DataFrame::from_vec(vec![array![3.5, 4.5]], vec![2, 3], vec!["B".to_string()]);
+    assert_eq!(chunks[1], exp);
+    let exp = DataFrame::from_vec(vec![array![5.5]], vec![4], vec!["B".to_string()]);
+    assert_eq!(chunks[2], exp);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_parquet_stats() {
+    let path = temp_path("stats");
+    sample_frame().to_parquet(File::create(&path).unwrap()).unwrap();
+
+    let stats = DataFrame::<usize, String>::read_parquet_stats(File::open(&path).unwrap()).unwrap();
+    assert_eq!(
+        stats["A"],
+        ColumnStats {
+            min: Scalar::String("x".to_string()),
+            max: Scalar::String("z".to_string()),
+            null_count: 0,
+            count: 3,
+        }
+    );
+    assert_eq!(stats["B"].count, 3);
+    assert_eq!(stats["C"].count, 2);
+    assert_eq!(stats["C"].min, Scalar::i64(1));
+    assert_eq!(stats["C"].max, Scalar::i64(7));
+    assert_eq!(stats["C"].null_count, 1);
+    assert_eq!(stats["D"].max, Scalar::f64(4.5));
+    assert_eq!(stats["E"].min, Scalar::u64(1));
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_parquet_filter() {
+    let path = temp_path("filter");
+    sample_frame().to_parquet(File::create(&path).unwrap()).unwrap();
+
+    let pred = Predicate::Gt("D".to_string(), Scalar::f64(2.));
+    let res = DataFrame::<usize, String>::read_parquet_filter(File::open(&path).unwrap(), &pred)
+        .unwrap();
+    assert_eq!(res.len(), 2);
+    assert_eq!(*res.values[0], array!["y".to_string(), "z".to_string()]);
+
+    // excluded by stats, dtypes are kept
+    let pred = Predicate::And(vec![
+        Predicate::Le("C".to_string(), Scalar::i64(0)),
+        Predicate::NotNull("A".to_string()),
+    ]);
+    let res = DataFrame::<usize, String>::read_parquet_filter(File::open(&path).unwrap(), &pred)
+        .unwrap();
+    assert_eq!(res.len(), 0);
+    assert_eq!(res.dtypes(), vec!["str", "bool", "i64", "f64", "u8"]);
+
+    let pred = Predicate::IsNull("C".to_string());
+    let res = DataFrame::<usize, String>::read_parquet_filter(File::open(&path).unwrap(), &pred)
+        .unwrap();
+    assert_eq!(*res.values[0], array!["y".to_string()]);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_parquet_filter_f32() {
+    let df = DataFrame::from_vec(
+        vec![array![0.1f32, 0.05]],
+        vec![0, 1],
+        vec!["x".to_string()],
+    );
+    let path = temp_path("filter_f32");
+    df.to_parquet(File::create(&path).unwrap()).unwrap();
+
+    let stats = DataFrame::<usize, String>::read_parquet_stats(File::open(&path).unwrap()).unwrap();
+    assert_eq!(stats["x"].max, Scalar::f32(0.1));
+    let pred = Predicate::Ge("x".to_string(), Scalar::f32(0.1));
+    let res = DataFrame::<usize, String>::read_parquet_filter(File::open(&path).unwrap(), &pred)
+        .unwrap();
+    assert_eq!(*res.values[0], array![0.1f32]);
+    let pred = Predicate::Eq("x".to_string(), Scalar::f32(0.05));
+    let res = DataFrame::<usize, String>::read_parquet_filter(File::open(&path).unwrap(), &pred)
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_read_parquet_nested() {
+    use std::sync::Arc;
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::writer::SerializedFileWriter;
+    use parquet::schema::parser::parse_message_type;
+
+    let schema = "message schema {
+        optional group g { optional int32 a; optional int32 b; }
+        optional int32 c;
+    }";
+    let schema = Arc::new(parse_message_type(schema).unwrap());
+    let path = temp_path("nested");
+    let writer = SerializedFileWriter::new(File::create(&path).unwrap(), schema,
+                                           Arc::new(WriterProperties::builder().build()))
+        .unwrap();
+    writer.close().unwrap();
+
+    let res = DataFrame::<usize, String>::read_parquet(File::open(&path).unwrap(), None);
+    assert!(res.is_err());
+    let res = DataFrame::<usize, String>::read_parquet(File::open(&path).unwrap(), Some(&["c"]));
+    assert!(res.is_err());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_parquet_isize_usize() {
+    let df = DataFrame::from_vec(
+        vec![array![-1isize, 2], array![1usize, 2]],
+        vec![0, 1],
+        vec!["A".to_string(), "B".to_string()],
+    );
+    let path = temp_path("isize");
+    df.to_parquet(File::create(&path).unwrap()).unwrap();
+    let res = DataFrame::<usize, String>::read_parquet(File::open(&path).unwrap(), None).unwrap();
+    assert_eq!(res.dtypes(), vec!["i64", "u64"]);
+    assert_eq!(*res.values[0], array![-1i64, 2]);
+    assert_eq!(*res.values[1], array![1u64, 2]);
+    fs::remove_file(&path).unwrap();
+}
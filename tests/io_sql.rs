@@ -0,0 +1,149 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[cfg(feature = "rusqlite")]
+extern crate rusqlite;
+
+struct DummySource;
+
+impl SqlSource for DummySource {
+    type Error = String;
+
+    fn query_scalars(&mut self, query: &str) -> Result<SqlResult, String> {
+        if query != "SELECT * FROM t" {
+            return Err("syntax error".to_string());
+        }
+        Ok(SqlResult {
+            columns: vec!["a".to_string(), "b".to_string()],
+            dtypes: vec![Some("i32"), None],
+            rows: vec![
+                vec![Scalar::i64(1), Scalar::Null],
+                vec![Scalar::Null, Scalar::f64(1.5)],
+            ],
+        })
+    }
+}
+
+/// Source whose values don't match the declared dtypes.
+struct MixedSource;
+
+impl SqlSource for MixedSource {
+    type Error = String;
+
+    fn query_scalars(&mut self, _: &str) -> Result<SqlResult, String> {
+        Ok(SqlResult {
+            columns: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            dtypes: vec![Some("i64"), Some("bool"), Some("f64")],
+            rows: vec![
+                vec![Scalar::i64(1), Scalar::bool(true), Scalar::i64(1)],
+                vec![Scalar::String("x".to_string()), Scalar::String("y".to_string()),
+                     Scalar::f64(1.5)],
+                vec![Scalar::f64(2.5), Scalar::Null, Scalar::Null],
+            ],
+        })
+    }
+}
+
+#[test]
+fn test_from_sql() {
+    let res = DataFrame::<usize, String>::from_sql("SELECT * FROM t", &mut DummySource).unwrap();
+    let exp = DataFrame::from_vec(
+        vec![
+            Array::Int32Array(NullVec::with_mask(vec![1, 0], Some(vec![false, true]))),
+            Array::Float64Array(NullVec::with_mask(vec![0., 1.5], Some(vec![true, false]))),
+        ],
+        vec![0, 1],
+        vec!["a".to_string(), "b".to_string()],
+    );
+    assert_eq!(res, exp);
+
+    let res = DataFrame::<usize, String>::from_sql("SELECT", &mut DummySource);
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_from_sql_mixed_dtypes() {
+    let res = DataFrame::<usize, String>::from_sql("SELECT * FROM t", &mut MixedSource).unwrap();
+    assert_eq!(res.dtypes(), vec!["str", "str", "f64"]);
+    let exp = Array::StringArray(NullVec::new(vec!["1".to_string(), "x".to_string(),
+                                                  "2.5".to_string()]));
+    assert_eq!(res.get(&"a".to_string()), exp);
+    let exp = Array::Float64Array(NullVec::with_mask(vec![1., 1.5, 0.],
+                                                     Some(vec![false, false, true])));
+    assert_eq!(res.get(&"c".to_string()), exp);
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_from_sql_sqlite() {
+    let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(
+        "CREATE TABLE t (id INTEGER, name TEXT, score REAL, flag BOOLEAN);
+         INSERT INTO t VALUES (1, 'x', 1.5, 1);
+         INSERT INTO t VALUES (2, NULL, NULL, 0);
+         INSERT INTO t VALUES (3, 'z', 2.5, NULL);",
+    ).unwrap();
+
+    let res = DataFrame::<usize, String>::from_sql("SELECT * FROM t ORDER BY id", &mut conn)
+        .unwrap();
+    let exp = DataFrame::from_vec(
+        vec![
+            array![1i64, 2, 3],
+            Array::StringArray(NullVec::with_mask(
+                vec!["x".to_string(), "".to_string(), "z".to_string()],
+                Some(vec![false, true, false]),
+            )),
+            Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 2.5], Some(vec![false, true, false]))),
+            Array::BoolArray(NullVec::with_mask(vec![true, false, false], Some(vec![false, false, true]))),
+        ],
+        vec![0, 1, 2],
+        vec![
+            "id".to_string(),
+            "name".to_string(),
+            "score".to_string(),
+            "flag".to_string(),
+        ],
+    );
+    assert_eq!(res, exp);
+
+    // expression columns have no declared type
+    let res = DataFrame::<usize, String>::from_sql("SELECT id * 2 AS x FROM t WHERE id > 5",
+                                                   &mut conn)
+        .unwrap();
+    assert_eq!(res.len(), 0);
+    assert_eq!(res.dtypes(), vec!["f64"]);
+
+    assert!(DataFrame::<usize, String>::from_sql("SELECT * FROM missing", &mut conn).is_err());
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_from_sql_sqlite_mixed_storage() {
+    // declared types are affinities, values of other storage classes are kept
+    let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+    conn.execute_batch(
+        "CREATE TABLE t (id INTEGER, n INTEGER, flag BOOLEAN, score REAL);
+         INSERT INTO t VALUES (1, 1, 1, 1.5);
+         INSERT INTO t VALUES (2, '', 'yes', 2.0);
+         INSERT INTO t VALUES (3, 2.5, NULL, 'n/a');",
+    ).unwrap();
+
+    let res = DataFrame::<usize, String>::from_sql("SELECT id, n FROM t ORDER BY id", &mut conn)
+        .unwrap();
+    assert_eq!(res.dtypes(), vec!["i64", "str"]);
+    let exp = Array::StringArray(NullVec::new(vec!["1".to_string(), "".to_string(),
+                                                  "2.5".to_string()]));
+    assert_eq!(res.get(&"n".to_string()), exp);
+
+    let res = DataFrame::<usize, String>::from_sql("SELECT flag, score FROM t ORDER BY id",
+                                                   &mut conn)
+        .unwrap();
+    assert_eq!(res.dtypes(), vec!["str", "str"]);
+
+    // a REAL value in an INTEGER column is not truncated
+    let res = DataFrame::<usize, String>::from_sql("SELECT n FROM t WHERE id <> 2 ORDER BY id",
+                                                   &mut conn)
+        .unwrap();
+    assert_eq!(res.get(&"n".to_string()), array![1., 2.5]);
+}
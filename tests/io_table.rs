@@ -0,0 +1,118 @@
+#![cfg(feature = "parquet")]
+
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let mut path = env::temp_dir();
+    path.push(format!("brassfibre_table_{}", name));
+    if path.exists() {
+        fs::remove_dir_all(&path).unwrap();
+    }
+    path
+}
+
+#[test]
+fn test_table_append_read() {
+    let path = temp_dir("append_read");
+    let table = Table::open(&path).unwrap();
+    assert_eq!(table.version().unwrap(), 0);
+
+    let df1 = DataFrame::from_vec(
+        vec![array![1i64, 2], array!["a".to_string(), "b".to_string()]],
+        vec![10, 20],
+        vec!["X", "Y"],
+    );
+    assert_eq!(table.append(&df1).unwrap(), 1);
+
+    let df2 = DataFrame::from_vec(
+        vec![array![3i64], array!["c".to_string()]],
+        vec![30],
+        vec!["X", "Y"],
+    );
+    assert_eq!(table.append(&df2).unwrap(), 2);
+
+    let res = table.read().unwrap();
+    let exp = DataFrame::from_vec(
+        vec![
+            array![1i64, 2, 3],
+            array!["a".to_string(), "b".to_string(), "c".to_string()],
+        ],
+        vec![0, 1, 2],
+        vec!["X".to_string(), "Y".to_string()],
+    );
+    assert_eq!(res, exp);
+
+    // older version
+    let res = table.read_version(1).unwrap();
+    let exp = DataFrame::from_vec(
+        vec![array![1i64, 2], array!["a".to_string(), "b".to_string()]],
+        vec![0, 1],
+        vec!["X".to_string(), "Y".to_string()],
+    );
+    assert_eq!(res, exp);
+    assert!(table.read_version(3).is_err());
+
+    // reopen
+    let table = Table::open(&path).unwrap();
+    assert_eq!(table.version().unwrap(), 2);
+    fs::remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn test_table_schema_evolution() {
+    let path = temp_dir("schema_evolution");
+    let table = Table::open(&path).unwrap();
+
+    let df1 = DataFrame::from_vec(vec![array![1i64, 2]], vec![0, 1], vec!["X"]);
+    table.append(&df1).unwrap();
+    let df2 = DataFrame::from_vec(vec![array![1.5], array![3i64]], vec![0], vec!["Z", "X"]);
+    table.append(&df2).unwrap();
+
+    let res = table.read().unwrap();
+    let exp = DataFrame::from_vec(
+        vec![
+            array![1i64, 2, 3],
+            Array::Float64Array(NullVec::with_mask(vec![0., 0., 1.5], Some(vec![true, true, false]))),
+        ],
+        vec![0, 1, 2],
+        vec!["X".to_string(), "Z".to_string()],
+    );
+    assert_eq!(res, exp);
+
+    // incompatible dtype
+    let df3 = DataFrame::from_vec(vec![array!["x".to_string()]], vec![0], vec!["X"]);
+    table.append(&df3).unwrap();
+    assert!(table.read().is_err());
+    fs::remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn test_table_read_filter() {
+    let path = temp_dir("read_filter");
+    let table = Table::open(&path).unwrap();
+
+    let df1 = DataFrame::from_vec(vec![array![1i64, 2, 3]], vec![0, 1, 2], vec!["X"]);
+    table.append(&df1).unwrap();
+    let df2 = DataFrame::from_vec(vec![array![10i64, 20]], vec![0, 1], vec!["X"]);
+    table.append(&df2).unwrap();
+
+    let pred = Predicate::Ge("X".to_string(), Scalar::i64(3));
+    let res = table.read_filter(&pred).unwrap();
+    let exp = DataFrame::from_vec(vec![array![3i64, 10, 20]], vec![0, 1, 2], vec!["X".to_string()]);
+    assert_eq!(res, exp);
+
+    let pred = Predicate::Or(vec![
+        Predicate::Eq("X".to_string(), Scalar::i64(5)),
+        Predicate::Lt("X".to_string(), Scalar::i64(0)),
+    ]);
+    let res = table.read_filter(&pred).unwrap();
+    assert_eq!(res.len(), 0);
+    assert_eq!(res.dtypes(), vec!["i64"]);
+    fs::remove_dir_all(&path).unwrap();
+}
@@ -0,0 +1,86 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_series_multiindex() {
+    let index: MultiIndexer<(&str, i64)> =
+        Indexer::from_arrays(vec!["a", "a", "b", "b"], vec![1, 2, 1, 2]);
+    let s: Series<f64, (&str, i64)> = Series::new(vec![1., 2., 3., 4.], index);
+
+    assert_eq!(s.loc(&("b", 1)), 3.);
+
+    let exp: Series<f64, i64> = Series::new(vec![3., 4.], vec![1, 2]);
+    assert_eq!(s.xs_first(&"b"), exp);
+    assert_eq!(s.xs_first(&"c").len(), 0);
+
+    let swapped = s.swap_first_two();
+    let exp: Series<f64, &str> = Series::new(vec![2., 4.], vec!["a", "b"]);
+    assert_eq!(swapped.xs_first(&2), exp);
+
+    let exp: Series<f64, i64> = Series::new(vec![1., 2., 3., 4.], vec![1, 2, 1, 2]);
+    assert!(s.drop_first_level().values == exp.values);
+}
+
+#[test]
+fn test_series_multiindex_groupby_level() {
+    let index = MultiIndexer::from_product(&["a", "b"], &[1, 2, 3]);
+    let s: Series<i64, (&str, i64)> = Series::new(vec![1, 2, 3, 4, 5, 6], index);
+
+    let sg = s.groupby_first_level();
+    let res = sg.sum();
+    let exp: Series<i64, &str> = Series::new(vec![6, 15], vec!["a", "b"]);
+    assert_eq!(res, exp);
+
+    let swapped = s.swap_first_two();
+    let sg = swapped.groupby_first_level();
+    let res = sg.sum();
+    let exp: Series<i64, i64> = Series::new(vec![5, 7, 9], vec![1, 2, 3]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_multiindex() {
+    let index = MultiIndexer::from_arrays(vec![1, 1, 2], vec!["x", "y", "x"]);
+    let df = DataFrame::from_vec(
+        vec![array![1, 2, 3], array![1.5, 2.5, 3.5]],
+        index,
+        vec!["A", "B"],
+    );
+
+    let res = df.xs_first(&1);
+    let exp = DataFrame::from_vec(
+        vec![array![1, 2], array![1.5, 2.5]],
+        vec!["x", "y"],
+        vec!["A", "B"],
+    );
+    assert_eq!(res, exp);
+
+    let swapped = df.swap_first_two();
+    let res = swapped.xs_first(&"x");
+    let exp = DataFrame::from_vec(vec![array![1, 3], array![1.5, 3.5]], vec![1, 2], vec!["A", "B"]);
+    assert_eq!(res, exp);
+
+    let dg = df.groupby_first_level();
+    assert_eq!(dg.groups(), vec![1, 2]);
+    let exp = DataFrame::from_vec(
+        vec![array![3], array![3.5]],
+        MultiIndexer::from_arrays(vec![2], vec!["x"]),
+        vec!["A", "B"],
+    );
+    // tuple index doesn't implement Display
+    assert!(dg.get_group(&2) == exp);
+}
+
+#[test]
+fn test_frame_multiindex_columns() {
+    let columns = MultiIndexer::from_product(&["A", "B"], &["min", "max"]);
+    let df = DataFrame::from_vec(
+        vec![array![1, 2], array![3, 4], array![5, 6], array![7, 8]],
+        vec![10, 20],
+        columns,
+    );
+    let res = df.xs_first_columns(&"B");
+    let exp = DataFrame::from_vec(vec![array![5, 6], array![7, 8]], vec![10, 20], vec!["min", "max"]);
+    assert_eq!(res, exp);
+}
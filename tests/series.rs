@@ -252,3 +252,184 @@ fn test_series_from_iter() {
     let exp: Series<i64, usize> = Series::new(vec![3, 4, 5], vec![0, 1, 2]);
     assert_eq!(s, exp);
 }
+
+#[test]
+fn test_series_try_new() {
+    let s = Series::<f64, i64>::try_new(vec![1., 2.], vec![5, 6]).unwrap();
+    assert_eq!(s.len(), 2);
+
+    let res = Series::<f64, i64>::try_new(vec![1., 2.], vec![5, 6, 7]);
+    assert_eq!(
+        res.err(),
+        Some(Error::LengthMismatch {
+            expected: 3,
+            actual: 2,
+        })
+    );
+}
+
+#[test]
+fn test_series_try_slice() {
+    let s = Series::<f64, i64>::new(vec![1., 2., 3.], vec![5, 6, 7]);
+    assert_eq!(s.try_loc(&6), Ok(2.));
+    assert_eq!(s.try_loc(&8), Err(Error::KeyNotFound("8".to_string())));
+    assert_eq!(s.try_iloc(&2), Ok(3.));
+    assert_eq!(
+        s.try_iloc(&3),
+        Err(Error::OutOfBounds {
+            location: 3,
+            len: 3,
+        })
+    );
+
+    let exp = Series::<f64, i64>::new(vec![3., 1.], vec![7, 5]);
+    assert_eq!(s.try_locs(&[7, 5]).unwrap(), exp);
+    assert!(s.try_locs(&[7, 8]).is_err());
+    assert_eq!(s.try_ilocs(&[2, 0]).unwrap(), exp);
+    assert!(s.try_ilocs(&[2, 3]).is_err());
+}
+
+#[test]
+fn test_series_asof() {
+    let s: Series<Option<i64>, i64> = Series::new(vec![Some(1), None, Some(3)], vec![1, 3, 5]);
+    assert_eq!(s.asof(&4), Some(Some(1)));
+    assert_eq!(s.asofs(&[0, 2, 6]), vec![None, Some(Some(1)), Some(Some(3))]);
+}
+
+#[test]
+fn test_series_index_astype() {
+    let s: Series<i64, String> =
+        Series::new(vec![1, 2, 3], vec!["1".to_string(), "2".to_string(), "3".to_string()]);
+    let res = s.index_astype::<u8>();
+    assert_eq!(res.index.values, vec![1, 2, 3]);
+    assert_eq!(res.values, s.values);
+}
+
+#[test]
+fn test_series_view() {
+    fn total(s: &SeriesView<i64, &str>) -> i64 {
+        s.values.iter().sum()
+    }
+
+    let s: Series<i64, &str> = Series::new(vec![1, 2, 3], vec!["a", "b", "c"]);
+    assert!(!s.is_view());
+
+    let v = s.view();
+    assert!(v.is_view());
+    assert_eq!(v, s);
+    assert_eq!(total(&v), 6);
+    assert_eq!(v.values, Cow::Borrowed(&vec![1, 2, 3]));
+
+    let o = v.to_owned();
+    assert!(!o.is_view());
+    assert_eq!(o, s);
+    assert!(!v.into_owned().is_view());
+}
+
+#[test]
+fn test_series_every_nth() {
+    let s: Series<i64, &str> = Series::new(vec![1, 2, 3, 4, 5], vec!["a", "b", "c", "d", "e"]);
+    assert_eq!(s.every_nth(2, 0), Series::new(vec![1, 3, 5], vec!["a", "c", "e"]));
+    assert_eq!(s.every_nth(3, 1), Series::new(vec![2, 5], vec!["b", "e"]));
+    assert_eq!(s.every_nth(1, 0), s);
+    assert_eq!(s.every_nth(2, 7).len(), 0);
+}
+
+#[test]
+fn test_series_when_then() {
+    let s: Series<i64, &str> = Series::new(vec![5, 15, 25, 35], vec!["a", "b", "c", "d"]);
+    let small: Vec<bool> = s.values.iter().map(|x| *x < 10).collect();
+    let medium: Vec<bool> = s.values.iter().map(|x| *x < 30).collect();
+
+    let values = when(&small)
+        .then("small")
+        .when(&medium)
+        .then("medium")
+        .otherwise("large");
+    let res = Series::new(values, s.index.into_owned());
+    let exp: Series<&str, &str> =
+        Series::new(vec!["small", "medium", "medium", "large"], vec!["a", "b", "c", "d"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_cut_qcut() {
+    let s = Series::<f64, usize>::from_vec(vec![1., 7., 5., 4., 6., 3.]);
+
+    let (res, edges) = s.cut(&[0., 3., 6., 9.]);
+    let low = Some(Interval::new(0., 3.));
+    let mid = Some(Interval::new(3., 6.));
+    let high = Some(Interval::new(6., 9.));
+    assert_eq!(res.values.into_owned(), vec![low, high, mid, mid, mid, low]);
+    assert_eq!(edges, vec![0., 3., 6., 9.]);
+
+    let (res, edges) = s.qcut(2);
+    assert_eq!(edges, vec![1., 4.5, 7.]);
+    let low = Some(Interval::new(1., 4.5));
+    let high = Some(Interval::new(4.5, 7.));
+    assert_eq!(res.values.into_owned(), vec![low, high, high, low, high, low]);
+
+    // bins can be group keys
+    let (bins, _) = s.cut(&[0., 5., 10.]);
+    let sg = s.groupby(&bins.values);
+    let exp: Series<f64, Option<Interval>> = Series::new(
+        vec![13., 13.],
+        vec![Some(Interval::new(0., 5.)), Some(Interval::new(5., 10.))],
+    );
+    assert!(sg.sum() == exp);
+}
+
+#[test]
+fn test_series_map() {
+    let s: Series<i64, &str> = Series::new(vec![1, 2, 3], vec!["a", "b", "c"]);
+    let res = s.map(|x| format!("v{}", x));
+    let values = vec!["v1".to_string(), "v2".to_string(), "v3".to_string()];
+    let exp: Series<String, &str> = Series::new(values, vec!["a", "b", "c"]);
+    assert_eq!(res, exp);
+
+    let mut s = s;
+    s.map_in_place(|x| *x *= 10);
+    assert_eq!(s, Series::new(vec![10, 20, 30], vec!["a", "b", "c"]));
+}
+
+#[test]
+fn test_series_map_lookup() {
+    let s: Series<&str, usize> = Series::new(vec!["x", "z", "y", "x"], vec![0, 1, 2, 3]);
+    let mapping: Series<f64, &str> = Series::new(vec![1.5, 2.5], vec!["x", "y"]);
+    let res = s.map_lookup(&mapping);
+    let exp = Series::new(vec![Some(1.5), None, Some(2.5), Some(1.5)], vec![0, 1, 2, 3]);
+    assert!(res == exp);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Point {
+    x: f64,
+    label: String,
+}
+
+#[test]
+fn test_series_owned_values() {
+    let values: Vec<String> = vec!["b", "a", "c", "a"].into_iter().map(|x| x.to_string()).collect();
+    let s: Series<String, i64> = Series::new(values, vec![10, 20, 30, 40]);
+
+    assert_eq!(s.loc(&20), "a".to_string());
+    assert_eq!(s.iloc(&2), "c".to_string());
+    let res = s.locs(&[30, 10]);
+    assert_eq!(res.values.to_vec(), vec!["c".to_string(), "b".to_string()]);
+    let res = s.blocs(&[true, false, false, true]);
+    assert_eq!(res.index.values, vec![10, 40]);
+
+    let res = s.sort_values(true);
+    assert_eq!(res.index.values, vec![20, 40, 10, 30]);
+    let res = s.value_counts();
+    assert_eq!(res.loc(&"a".to_string()), 2);
+
+    let res = s.append(&s);
+    assert_eq!(res.len(), 8);
+    assert_eq!(res.iloc(&4), "b".to_string());
+
+    let res = s.map(|x| Point { x: x.len() as f64, label: x.to_uppercase() });
+    assert_eq!(res.loc(&30), Point { x: 1., label: "C".to_string() });
+    let res = res.ilocs(&[0, 1]);
+    assert_eq!(res.values[1].label, "A");
+}
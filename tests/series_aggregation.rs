@@ -103,3 +103,77 @@ fn test_series_value_counts_str() {
     let exp: Series<usize, &str> = Series::new(vec![3, 2, 1], vec!["a", "bb", "c"]);
     assert_eq!(d, exp);
 }
+
+#[test]
+fn test_series_value_counts_with() {
+    let values: Vec<&str> = vec!["c", "a", "bb", "bb", "a", "a", "c", "d"];
+    let s = Series::<&str, usize>::from_vec(values);
+
+    let exp: Series<f64, &str> = Series::new(vec![3., 2., 2., 1.], vec!["a", "bb", "c", "d"]);
+    assert_eq!(s.value_counts_with(false, false), exp);
+    let exp: Series<f64, &str> = Series::new(vec![3., 5., 7., 8.], vec!["a", "bb", "c", "d"]);
+    assert_eq!(s.value_counts_with(false, true), exp);
+    let exp: Series<f64, &str> = Series::new(vec![37.5, 25., 25., 12.5], vec!["a", "bb", "c", "d"]);
+    assert_eq!(s.value_counts_with(true, false), exp);
+    let exp: Series<f64, &str> =
+        Series::new(vec![37.5, 62.5, 87.5, 100.], vec!["a", "bb", "c", "d"]);
+    assert_eq!(s.value_counts_with(true, true), exp);
+}
+
+#[test]
+fn test_series_factorize() {
+    let values: Vec<&str> = vec!["bb", "a", "bb", "c", "a"];
+    let s = Series::<&str, usize>::from_vec(values);
+
+    let (codes, uniques) = s.factorize();
+    assert_eq!(codes, vec![0, 1, 0, 2, 1]);
+    assert_eq!(uniques, vec!["bb", "a", "c"]);
+}
+
+#[test]
+fn test_series_compensated_summation() {
+    let values: Vec<f64> = vec![1., 1e100, 1., -1e100];
+    let s = Series::<f64, usize>::from_vec(values);
+    assert_eq!(s.sum(), 0.);
+    assert_eq!(s.sum_by(Summation::Naive), 0.);
+    assert_eq!(s.sum_by(Summation::Kahan), 2.);
+    assert_eq!(s.mean_by(Summation::Kahan), 0.5);
+
+    let s = Series::<i64, usize>::from_vec(vec![1, 2, 3, 4, 5]);
+    assert_eq!(s.sum_by(Summation::Pairwise), 15.);
+    assert_eq!(s.var_by(Summation::Kahan), 2.);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_series_par_sum() {
+    let values: Vec<f64> = (0..200_000).map(|x| (x as f64).sqrt()).collect();
+    let s = Series::<f64, usize>::from_vec(values);
+    let res = s.par_sum_by(Summation::Naive, Reduction::Deterministic);
+    assert_eq!(s.par_sum_by(Summation::Naive, Reduction::Deterministic), res);
+    assert!((res - s.sum_by(Summation::Kahan)).abs() < 1e-3);
+    let res = s.par_sum_by(Summation::Kahan, Reduction::Unordered);
+    assert!((res - s.sum_by(Summation::Kahan)).abs() < 1e-3);
+
+    let s = Series::<i64, usize>::from_vec(vec![1, 2, 3, 4]);
+    assert_eq!(s.par_mean_by(Summation::Kahan, Reduction::Deterministic), 2.5);
+}
+
+#[test]
+fn test_series_aggregate_with_skipna() {
+    let nan = f64::NAN;
+    let s = Series::<f64, usize>::from_vec(vec![1., nan, 3., nan]);
+    assert!(s.sum().is_nan());
+    assert_eq!(s.aggregate_with(Agg::Sum, true, 0), 4.);
+    assert_eq!(s.aggregate_with(Agg::Mean, true, 0), 2.);
+    assert_eq!(s.aggregate_with(Agg::Min, true, 0), 1.);
+    assert_eq!(s.aggregate_with(Agg::Count, false, 0), 2.);
+    assert!(s.aggregate_with(Agg::Sum, false, 0).is_nan());
+    assert_eq!(s.aggregate_with(Agg::Max, true, 2), 3.);
+    assert!(s.aggregate_with(Agg::Max, true, 3).is_nan());
+
+    let s = Series::<f64, usize>::from_vec(vec![nan, nan]);
+    assert_eq!(s.aggregate_with(Agg::Sum, true, 0), 0.);
+    assert!(s.aggregate_with(Agg::Sum, true, 1).is_nan());
+    assert!(s.aggregate_with(Agg::Mean, true, 0).is_nan());
+}
@@ -0,0 +1,209 @@
+#![cfg(feature = "chrono")]
+
+#[macro_use]
+extern crate brassfibre;
+extern crate chrono;
+use brassfibre::prelude::*;
+#[cfg(feature = "chrono-tz")]
+use brassfibre::chrono_tz::America::New_York;
+
+// 2018-03-31, 2018-04-01, 2018-04-02, 2018-12-31 in nanoseconds
+const NANOS: [i64; 4] = [
+    1_522_454_400_000_000_000,
+    1_522_540_800_000_000_000,
+    1_522_627_200_000_000_000,
+    1_546_214_400_000_000_000,
+];
+
+#[test]
+fn test_series_dt_periods() {
+    let s: Series<i64, &str> = Series::new(NANOS.to_vec(), vec!["a", "b", "c", "d"]);
+
+    let res = s.dt().iso_week();
+    let exp: Vec<Period> = vec![
+        Period::Week { year: 2018, week: 13 },
+        Period::Week { year: 2018, week: 13 },
+        Period::Week { year: 2018, week: 14 },
+        Period::Week { year: 2019, week: 1 },
+    ];
+    assert_eq!(res, Series::new(exp, vec!["a", "b", "c", "d"]));
+
+    let res = s.dt().quarter();
+    let labels: Vec<String> = res.values.iter().map(|x| x.to_string()).collect();
+    assert_eq!(labels, vec!["2018Q1", "2018Q2", "2018Q2", "2018Q4"]);
+
+    let res = s.dt().fiscal_period(4);
+    let labels: Vec<String> = res.values.iter().map(|x| x.to_string()).collect();
+    assert_eq!(labels, vec!["FY2018Q4", "FY2019Q1", "FY2019Q1", "FY2019Q3"]);
+}
+
+#[test]
+fn test_series_groupby_period() {
+    let s = Series::<i64, usize>::from_vec(NANOS.to_vec());
+    let values = Series::<i64, usize>::from_vec(vec![1, 2, 3, 4]);
+
+    let periods = s.dt().quarter();
+    let sg = values.groupby(&periods.values);
+    let res = sg.sum();
+    let exp: Series<i64, Period> = Series::new(
+        vec![1, 5, 4],
+        vec![
+            Period::Quarter { year: 2018, quarter: 1 },
+            Period::Quarter { year: 2018, quarter: 2 },
+            Period::Quarter { year: 2018, quarter: 4 },
+        ],
+    );
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_dt_to_period() {
+    let s = Series::<i64, usize>::from_vec(NANOS.to_vec());
+
+    let res = s.dt().to_period(Freq::Month);
+    assert_eq!(res, s.dt().month());
+    let labels: Vec<String> = res.values.iter().map(|x| x.to_string()).collect();
+    assert_eq!(labels, vec!["2018-03", "2018-04", "2018-04", "2018-12"]);
+
+    // monthly panel shifted by a period
+    let shifted: Vec<Period> = res.values.iter().map(|x| *x + 1).collect();
+    let labels: Vec<String> = shifted.iter().map(|x| x.to_string()).collect();
+    assert_eq!(labels, vec!["2018-04", "2018-05", "2018-05", "2019-01"]);
+    assert_eq!(res.values[3] - res.values[0], 9);
+    assert_eq!(Period::from_datetime(&NANOS[3], Freq::Quarter) - 4, Period::Quarter {
+        year: 2017,
+        quarter: 4,
+    });
+
+    // usable as index labels
+    let values = Series::<i64, Period>::new(vec![1, 2, 3, 4], vec![
+        Period::Month { year: 2018, month: 1 },
+        Period::Month { year: 2018, month: 2 },
+        Period::Month { year: 2018, month: 3 },
+        Period::Month { year: 2018, month: 4 },
+    ]);
+    assert_eq!(values.loc(&Period::Month { year: 2018, month: 3 }), 3);
+}
+
+#[test]
+fn test_series_dt_to_datetime() {
+    let s: Series<i64, &str> = Series::new(NANOS[..2].to_vec(), vec!["a", "b"]);
+
+    let res = s.dt().to_datetime();
+    let exp = chrono::NaiveDate::from_ymd_opt(2018, 3, 31).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(res.values[0], exp);
+    assert_eq!(res.index, s.index);
+    // NaiveDateTime values have the same accessor
+    assert_eq!(res.dt().to_nanos(), s);
+    assert_eq!(res.dt().month(), s.dt().month());
+
+    let res = s.dt().strftime("%Y/%m/%d %H:%M");
+    assert_eq!(res.values.to_vec(), vec!["2018/03/31 00:00", "2018/04/01 00:00"]);
+    let formatted = format!("{}", s.dt().to_datetime());
+    assert!(formatted.contains("2018-04-01T00:00:00"));
+}
+
+#[test]
+fn test_date_range_loc_partial() {
+    let start = chrono::NaiveDate::from_ymd_opt(2018, 3, 30).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let end = chrono::NaiveDate::from_ymd_opt(2018, 4, 2).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let index = date_range(&start, &end, std::time::Duration::from_secs(86_400));
+    assert_eq!(index.len(), 4);
+    assert_eq!(index.values[1], NANOS[0]);
+
+    let s: Series<i64, i64> = Series::new(vec![1, 2, 3, 4], index);
+    assert_eq!(s.loc(&NANOS[1]), 3);
+    let res = s.loc_partial("2018-03");
+    assert_eq!(res, Series::new(vec![1, 2], vec![NANOS[0] - 86_400_000_000_000, NANOS[0]]));
+    assert_eq!(s.loc_partial("2018-04-02").values.to_vec(), vec![4]);
+    assert_eq!(s.loc_partial("2019").len(), 0);
+
+    let df = DataFrame::from_vec(
+        vec![array![1, 2, 3, 4], array![1.5, 2.5, 3.5, 4.5]],
+        s.index.values.clone(),
+        vec!["X", "Y"],
+    );
+    let res = df.loc_partial("2018-04");
+    assert_eq!(res.index.values, NANOS[1..3].to_vec());
+    assert_eq!(*res.values[1], array![3.5, 4.5]);
+}
+
+#[test]
+fn test_series_dt_tz() {
+    // 2018-03-31 20:00 and 2018-04-01 20:00 UTC
+    let hour: i64 = 3_600_000_000_000;
+    let s = Series::<i64, usize>::from_vec(vec![NANOS[0] + 20 * hour, NANOS[1] + 20 * hour]);
+    let jst = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+
+    let local = s.dt().tz_convert(&jst);
+    assert_eq!(local.dt().to_nanos(), s);
+    let res = local.dt().strftime("%Y-%m-%d %H:%M %z");
+    assert_eq!(res.values.to_vec(), vec!["2018-04-01 05:00 +0900", "2018-04-02 05:00 +0900"]);
+    let labels: Vec<String> = local.dt().month().values.iter().map(|x| x.to_string()).collect();
+    assert_eq!(labels, vec!["2018-04", "2018-04"]);
+    let labels: Vec<String> = s.dt().month().values.iter().map(|x| x.to_string()).collect();
+    assert_eq!(labels, vec!["2018-03", "2018-04"]);
+
+    // wall clock time regarded as JST
+    let res = s.dt().tz_localize(&jst);
+    assert_eq!(res.dt().strftime("%H:%M %z").values[0], "20:00 +0900");
+    assert_eq!(res.dt().to_nanos().values[0], NANOS[0] + 11 * hour);
+    // localize keeps wall clock time of aware values
+    let res = local.dt().tz_localize(&chrono::FixedOffset::east_opt(0).unwrap());
+    assert_eq!(res.dt().to_nanos().values[0], NANOS[1] + 5 * hour);
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_series_dt_tz_dst() {
+    // 2018-03-10 12:00 and 2018-03-12 12:00 UTC, around the start of DST
+    let hour: i64 = 3_600_000_000_000;
+    let day = 24 * hour;
+    let s = Series::<i64, usize>::from_vec(vec![
+        NANOS[0] - 21 * day + 12 * hour,
+        NANOS[0] - 19 * day + 12 * hour,
+    ]);
+    let local = s.dt().tz_convert(&New_York);
+    let res = local.dt().strftime("%Y-%m-%d %H:%M %z");
+    assert_eq!(res.values.to_vec(), vec!["2018-03-10 07:00 -0500", "2018-03-12 08:00 -0400"]);
+    assert_eq!(local.dt().to_nanos(), s);
+
+    let res = s.dt().tz_localize(&New_York);
+    assert_eq!(res.dt().strftime("%H:%M %z").values.to_vec(), vec!["12:00 -0500", "12:00 -0400"]);
+    let exp = vec![s.values[0] + 5 * hour, s.values[1] + 4 * hour];
+    assert_eq!(res.dt().to_nanos().values.to_vec(), exp);
+
+    // 2018-11-04 01:30 is repeated at the end of DST
+    let nov4 = NANOS[3] - 57 * day;
+    let s = Series::<i64, usize>::from_vec(vec![nov4 + 3 * hour / 2]);
+    let res = s.dt().tz_localize(&New_York);
+    assert_eq!(res.dt().strftime("%Y-%m-%d %H:%M %z").values[0], "2018-11-04 01:30 -0400");
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+#[should_panic]
+fn test_series_dt_tz_localize_skipped() {
+    // 2018-03-11 02:30 is skipped at the start of DST
+    let hour: i64 = 3_600_000_000_000;
+    let s = Series::<i64, usize>::from_vec(vec![NANOS[0] - 20 * 24 * hour + 5 * hour / 2]);
+    s.dt().tz_localize(&New_York);
+}
+
+#[test]
+fn test_series_dt_duration_since() {
+    let start = Series::<i64, usize>::from_vec(NANOS[..3].to_vec());
+    let end = Series::<i64, usize>::from_vec(NANOS[1..].to_vec());
+
+    let res = end.dt().duration_since(&start);
+    let days: Vec<chrono::Duration> =
+        vec![1, 1, 273].into_iter().map(chrono::Duration::days).collect();
+    assert_eq!(res.values.to_vec(), days);
+    assert_eq!(res.total_seconds().values[0], 86_400.);
+    assert_eq!(res.max(), Some(chrono::Duration::days(273)));
+    assert_eq!(res.mean(), Some(chrono::Duration::days(275) / 3));
+
+    // other can be any datetime-like values
+    let res = start.dt().duration_since(&end.dt().to_datetime());
+    assert_eq!(res.total_millis().values[0], -86_400_000);
+}
@@ -1,6 +1,9 @@
+#[macro_use]
 extern crate brassfibre;
 use brassfibre::prelude::*;
 
+use std::borrow::Cow;
+
 // Some tests are under series::groupby which directly uses SeriesGroupBy (private internal)
 
 #[test]
@@ -21,6 +24,14 @@ fn test_series_get_group() {
     assert_eq!(s2, exp);
 }
 
+#[test]
+#[should_panic]
+fn test_series_groupby_from_cow_length_mismatch() {
+    let s = Series::<f64, usize>::from_vec(vec![1., 2., 3.]);
+    let data: Cow<Series<f64, usize>> = Cow::Owned(s);
+    GroupBy::from_cow(data, &[1, 1]);
+}
+
 #[test]
 fn test_series_agg_sum_integer_grouper() {
     let values: Vec<i64> = vec![1, 2, 3, 4, 5];
@@ -58,3 +69,149 @@ fn test_series_agg_mean_integer_grouper() {
     let exp: Series<f64, i64> = Series::new(vec![2.0, 4.5], vec![1, 2]);
     assert_eq!(sum, exp);
 }
+
+#[test]
+fn test_series_groupby_multi() {
+    let s = Series::<i64, usize>::from_vec(vec![1, 2, 3, 4, 5]);
+    let cities = vec!["x", "y", "x", "x", "y"];
+    let years = vec![2018, 2018, 2019, 2018, 2018];
+
+    let sg = s.groupby_multi((&cities, &years));
+    assert_eq!(sg.groups(), vec![("x", 2018), ("x", 2019), ("y", 2018)]);
+    let res = sg.sum();
+    let exp: Series<i64, (&str, i32)> = Series::new(
+        vec![5, 3, 7],
+        MultiIndexer::from_arrays(vec!["x", "x", "y"], vec![2018, 2019, 2018]),
+    );
+    assert!(res == exp);
+    assert!(res.xs_first(&"x") == Series::new(vec![5, 3], vec![2018, 2019]));
+
+    let sg = s.groupby_multi((&[1, 1, 2, 2, 2], &cities[..], vec![true; 5]));
+    assert_eq!(sg.groups().len(), 4);
+    assert_eq!(sg.count().values.into_owned(), vec![1, 1, 2, 1]);
+}
+
+#[test]
+fn test_series_groupby_agg_multiple() {
+    let s = Series::<i64, usize>::from_vec(vec![1, 2, 3, 4, 5]);
+    let sg = s.groupby(&["a", "b", "a", "b", "b"]);
+    let res = sg.agg(&[Agg::Sum, Agg::Mean, Agg::Count, Agg::Max]);
+    let exp = DataFrame::from_vec(
+        vec![array![4i64, 11], array![2., 11. / 3.], array![2usize, 3], array![3i64, 5]],
+        vec!["a", "b"],
+        vec![Agg::Sum, Agg::Mean, Agg::Count, Agg::Max],
+    );
+    assert_eq!(res, exp);
+    assert_eq!(Agg::Mean.to_string(), "mean");
+}
+
+#[test]
+fn test_series_groupby_transform() {
+    let s: Series<f64, i64> = Series::new(vec![1., 2., 3., 4., 6.], vec![10, 20, 30, 40, 50]);
+    let sg = s.groupby(&["a", "b", "a", "b", "b"]);
+
+    // demean each group
+    let res = sg.transform(|x| {
+        let mean = x.mean();
+        x.values.iter().map(|v| v - mean).collect()
+    });
+    let exp: Series<f64, i64> = Series::new(vec![-1., -2., 1., 0., 2.], vec![10, 20, 30, 40, 50]);
+    assert_eq!(res, exp);
+
+    // broadcast group size
+    let res = sg.transform(|x| vec![x.len(); x.len()]);
+    let exp: Series<usize, i64> = Series::new(vec![2, 3, 2, 3, 3], vec![10, 20, 30, 40, 50]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_groupby_transform_chain() {
+    let s: Series<i64, i64> = Series::new(vec![3, 1, 2, 5, 4], vec![10, 20, 30, 40, 50]);
+    let sg = s.groupby(&["a", "b", "a", "a", "b"]);
+
+    // sort within each group, then cumulative sum
+    let res = sg.pipe(|g| {
+        g.transform_groups(|x| x.sort_values(true).values.into_owned())
+            .transform(|x| {
+                x.values
+                    .iter()
+                    .scan(0, |acc, v| {
+                        *acc += *v;
+                        Some(*acc)
+                    })
+                    .collect()
+            })
+    });
+    let exp: Series<i64, i64> = Series::new(vec![2, 1, 5, 10, 5], vec![10, 20, 30, 40, 50]);
+    assert_eq!(res, exp);
+
+    assert_eq!(sg.pipe(|g| g.groups().len()), 2);
+}
+
+#[test]
+fn test_series_groupby_first_last_nth() {
+    let s: Series<i64, i64> = Series::new(vec![1, 2, 3, 4, 5], vec![10, 20, 30, 40, 50]);
+    let sg = s.groupby(&["a", "b", "a", "b", "b"]);
+
+    let exp: Series<i64, &str> = Series::new(vec![1, 2], vec!["a", "b"]);
+    assert_eq!(sg.first(), exp);
+    let exp: Series<i64, &str> = Series::new(vec![3, 5], vec!["a", "b"]);
+    assert_eq!(sg.last(), exp);
+    let exp: Series<i64, &str> = Series::new(vec![5], vec!["b"]);
+    assert_eq!(sg.nth(2), exp);
+
+    assert_eq!(s.first(), Some(1));
+    assert_eq!(s.last(), Some(5));
+    assert_eq!(s.nth(5), None);
+}
+
+#[test]
+fn test_series_groupby_size_cumcount() {
+    let s: Series<i64, i64> = Series::new(vec![1, 2, 3, 4, 5], vec![10, 20, 30, 40, 50]);
+    let sg = s.groupby(&["a", "b", "a", "b", "b"]);
+
+    let exp: Series<usize, &str> = Series::new(vec![2, 3], vec!["a", "b"]);
+    assert_eq!(sg.size(), exp);
+
+    let exp: Series<usize, i64> = Series::new(vec![0, 0, 1, 1, 2], vec![10, 20, 30, 40, 50]);
+    assert_eq!(sg.cumcount(), exp);
+}
+
+#[test]
+fn test_series_groupby_iter() {
+    let s: Series<i64, i64> = Series::new(vec![1, 2, 3, 4, 5], vec![10, 20, 30, 40, 50]);
+    let sg = s.groupby(&["b", "a", "b", "a", "b"]);
+
+    let mut keys: Vec<&str> = vec![];
+    for (key, group) in &sg {
+        keys.push(key);
+        if key == "a" {
+            assert_eq!(group, Series::new(vec![2, 4], vec![20, 40]));
+        } else {
+            assert_eq!(group, Series::new(vec![1, 3, 5], vec![10, 30, 50]));
+        }
+    }
+    assert_eq!(keys, vec!["a", "b"]);
+
+    let sums: Vec<(&str, i64)> = sg.iter().map(|(k, g)| (k, g.sum())).collect();
+    assert_eq!(sums, vec![("a", 6), ("b", 9)]);
+}
+
+#[test]
+fn test_series_groupby_minmax_float() {
+    let nan = std::f64::NAN;
+    let s = Series::<f64, usize>::from_vec(vec![1.5, nan, -2., nan, nan, 0.5]);
+    let sg = s.groupby(&[1, 1, 1, 2, 2, 3]);
+
+    let res = sg.min();
+    assert_eq!(res.index.values, vec![1, 2, 3]);
+    assert_eq!(res.values[0], -2.);
+    // all NaN group
+    assert!(res.values[1].is_nan());
+    assert_eq!(res.values[2], 0.5);
+
+    let res = sg.max();
+    assert_eq!(res.values[0], 1.5);
+    assert!(res.values[1].is_nan());
+    assert_eq!(res.values[2], 0.5);
+}
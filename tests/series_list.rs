@@ -0,0 +1,22 @@
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_series_list_methods() {
+    let s: Series<Vec<i64>, &str> =
+        Series::new(vec![vec![1, 2, 3], vec![], vec![4]], vec!["a", "b", "c"]);
+
+    let exp: Series<usize, &str> = Series::new(vec![3, 0, 1], vec!["a", "b", "c"]);
+    assert_eq!(s.list().len(), exp);
+
+    let exp: Series<i64, &str> = Series::new(vec![6, 0, 4], vec!["a", "b", "c"]);
+    assert_eq!(s.list().sum(), exp);
+
+    let res = s.list().mean();
+    assert_eq!(res.values[0], 2.);
+    assert!(res.values[1].is_nan());
+    assert_eq!(res.values[2], 4.);
+
+    let exp: Series<bool, &str> = Series::new(vec![false, false, true], vec!["a", "b", "c"]);
+    assert_eq!(s.list().contains(&4), exp);
+}